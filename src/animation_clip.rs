@@ -0,0 +1,447 @@
+//! Module containing conversion of [`AnimationClip`] into span tweens.
+//!
+//! # Animation clip
+//!
+//! **Plugins**:
+//! - [`AnimationClipTweenPlugin`]
+//!
+//! **Components**:
+//! - [`BakeAnimationClip`]
+//!
+//! **Systems**:
+//! - [`bake_animation_clip_system`]
+//!
+//! **Events**:
+//! - [`AnimationClipBaked`]
+//!
+//! **Combinators**:
+//! - [`animation_clip`]
+//! - [`bevy_gltf::gltf_animation`] with the `bevy_gltf` feature
+//!
+//! ## Importing
+//!
+//! Each translation, rotation, and scale curve of an [`AnimationClip`] is
+//! sampled into a [`KeyframeTrack`] that's spawned as a tween targeting the
+//! joint with [`TargetJoint`]. The animation is then a regular span tween
+//! hierarchy that can be blended, retimed, and mixed with tweens written in
+//! code.
+//!
+//! Curves are sampled through their concrete type so only the curve types
+//! created by the glTF loader and [`ConstantCurve`] are supported. Other
+//! curves, such as morph weights or custom [`AnimatableCurve`]s, are skipped
+//! with a warning.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{animation_clip::animation_clip, prelude::*};
+//!
+//! fn play_walk(
+//!     commands: &mut Commands,
+//!     scene_root: Entity,
+//!     walk: &AnimationClip,
+//! ) {
+//!     commands
+//!         .entity(scene_root)
+//!         .animation()
+//!         .repeat(Repeat::infinitely())
+//!         .insert(animation_clip(walk, 30.));
+//! }
+//! ```
+//!
+//! ## Baking
+//!
+//! The other way around, insert [`BakeAnimationClip`] to a [`TimeRunner`]
+//! to sample the [`Transform`] of its targets at a fixed rate into a new
+//! [`AnimationClip`] that can be played by Bevy's animation graph or saved
+//! as an asset.
+//!
+//! ```no_run
+//! use bevy::{animation::AnimationTargetId, prelude::*};
+//! use bevy_tween::animation_clip::{AnimationClipBaked, BakeAnimationClip};
+//!
+//! fn bake(commands: &mut Commands, time_runner: Entity, head: Entity) {
+//!     commands
+//!         .entity(time_runner)
+//!         .insert(BakeAnimationClip::new(30.).with_target(
+//!             head,
+//!             AnimationTargetId::from_names(
+//!                 [Name::new("Body"), Name::new("Head")].iter(),
+//!             ),
+//!         ))
+//!         .observe(|trigger: Trigger<AnimationClipBaked>| {
+//!             info!("baked {:?}", trigger.clip);
+//!         });
+//! }
+//! ```
+//!
+//! [`TimeRunner`]: bevy_time_runner::TimeRunner
+//! [`AnimatableCurve`]: bevy::animation::animation_curves::AnimatableCurve
+
+use std::time::Duration;
+
+#[cfg(feature = "bevy_gltf")]
+pub mod bevy_gltf;
+
+use bevy::{
+    animation::{
+        animation_curves::{RotationCurve, ScaleCurve, TranslationCurve},
+        gltf_curves::{
+            CubicKeyframeCurve, CubicRotationCurve, SteppedKeyframeCurve,
+        },
+        AnimationClip, AnimationTargetId, VariableCurve,
+    },
+    math::{
+        curve::{ConstantCurve, Curve, UnevenSampleAutoCurve},
+        StableInterpolate,
+    },
+    prelude::*,
+};
+use bevy_time_runner::{TimeDirection, TimeRunner, TimeSpan};
+
+use crate::{
+    combinator::AnimationCommands,
+    interpolate::{self, KeyframeTrack},
+    interpolation::EaseKind,
+    playback::{ManualTick, TimeRunnerExt},
+    tween::{self, TargetComponent, TargetJoint},
+    BevyTweenRegisterSystems, TweenSystemSet,
+};
+
+/// Plugin for the tweens spawned by [`animation_clip`] and for
+/// [`BakeAnimationClip`]
+pub struct AnimationClipTweenPlugin;
+
+impl Plugin for AnimationClipTweenPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        app.add_tween_systems((
+            tween::component_tween_system::<
+                KeyframeTrack<interpolate::Translation, Vec3>,
+            >(),
+            tween::component_tween_system::<
+                KeyframeTrack<interpolate::Rotation, Quat>,
+            >(),
+            tween::component_tween_system::<
+                KeyframeTrack<interpolate::Scale, Vec3>,
+            >(),
+        ));
+
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            bake_animation_clip_system
+                .after(TweenSystemSet::ApplyAdditiveTween),
+        )
+        .register_type::<BakeAnimationClip>();
+    }
+}
+
+/// A sampled curve of an [`AnimationClip`]
+#[derive(Debug, Clone)]
+pub enum AnimationClipTrack {
+    #[allow(missing_docs)]
+    Translation(KeyframeTrack<interpolate::Translation, Vec3>),
+    #[allow(missing_docs)]
+    Rotation(KeyframeTrack<interpolate::Rotation, Quat>),
+    #[allow(missing_docs)]
+    Scale(KeyframeTrack<interpolate::Scale, Vec3>),
+}
+
+impl AnimationClipTrack {
+    /// Sample `curve` from `0` to `duration` seconds at `sample_rate`
+    /// samples per second. Returns `None` if the curve type is not supported.
+    pub fn sample(
+        curve: &VariableCurve,
+        duration: f32,
+        sample_rate: f32,
+    ) -> Option<AnimationClipTrack> {
+        macro_rules! sample_as {
+            ($wrapper:ident, $track:ident, $make:path, [$($curve:ty),*]) => {
+                $(
+                    if let Some($wrapper(inner)) = curve
+                        .0
+                        .as_any()
+                        .downcast_ref::<$wrapper<$curve>>()
+                    {
+                        return Some(AnimationClipTrack::$track(
+                            sample_keyframes(
+                                inner,
+                                $make,
+                                duration,
+                                sample_rate,
+                            ),
+                        ));
+                    }
+                )*
+            };
+        }
+        sample_as!(
+            TranslationCurve,
+            Translation,
+            interpolate::translation,
+            [
+                UnevenSampleAutoCurve<Vec3>,
+                SteppedKeyframeCurve<Vec3>,
+                CubicKeyframeCurve<Vec3>,
+                ConstantCurve<Vec3>
+            ]
+        );
+        sample_as!(
+            RotationCurve,
+            Rotation,
+            interpolate::rotation,
+            [
+                UnevenSampleAutoCurve<Quat>,
+                SteppedKeyframeCurve<Quat>,
+                CubicRotationCurve,
+                ConstantCurve<Quat>
+            ]
+        );
+        sample_as!(
+            ScaleCurve,
+            Scale,
+            interpolate::scale,
+            [
+                UnevenSampleAutoCurve<Vec3>,
+                SteppedKeyframeCurve<Vec3>,
+                CubicKeyframeCurve<Vec3>,
+                ConstantCurve<Vec3>
+            ]
+        );
+        None
+    }
+}
+
+fn sample_keyframes<I, V>(
+    curve: &impl Curve<V>,
+    make: fn(V, V) -> I,
+    duration: f32,
+    sample_rate: f32,
+) -> KeyframeTrack<I, V> {
+    let samples = (duration * sample_rate).ceil().max(1.) as usize;
+    interpolate::keyframe_track(
+        make,
+        (0..=samples).map(|i| {
+            let time = duration * i as f32 / samples as f32;
+            (time, curve.sample_clamped(time), EaseKind::Linear)
+        }),
+    )
+}
+
+/// Sample every supported curve of `clip` at `sample_rate` samples per
+/// second, paired with the joint it animates.
+pub fn animation_clip_tracks(
+    clip: &AnimationClip,
+    sample_rate: f32,
+) -> Vec<(AnimationTargetId, AnimationClipTrack)> {
+    let duration = clip.duration();
+    let mut unsupported = 0;
+    let tracks = clip
+        .curves()
+        .iter()
+        .flat_map(|(target, curves)| {
+            curves.iter().map(move |curve| (*target, curve))
+        })
+        .filter_map(|(target, curve)| {
+            let track =
+                AnimationClipTrack::sample(curve, duration, sample_rate);
+            if track.is_none() {
+                unsupported += 1;
+            }
+            Some((target, track?))
+        })
+        .collect();
+    if unsupported > 0 {
+        warn!("Skipped {unsupported} unsupported curves of `AnimationClip`");
+    }
+    tracks
+}
+
+/// Combinator to play `clip` as tweens, sampling its curves at
+/// `sample_rate` samples per second. See the [module docs](self).
+///
+/// Starts from last position and lasts for the duration of the clip.
+///
+/// Position is shifted to the clip's end.
+pub fn animation_clip(
+    clip: &AnimationClip,
+    sample_rate: f32,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    let duration = Duration::from_secs_f32(clip.duration().max(0.));
+    let tracks = animation_clip_tracks(clip, sample_rate);
+    move |a, pos| {
+        let start = *pos;
+        let end = start + duration;
+        let span = TimeSpan::try_from(start..end).unwrap();
+        for (joint, track) in tracks {
+            spawn_track(a, span, TargetJoint(joint), track);
+        }
+        *pos = end;
+    }
+}
+
+fn spawn_track(
+    a: &mut AnimationCommands,
+    span: TimeSpan,
+    target: impl Bundle,
+    track: AnimationClipTrack,
+) {
+    let mut commands = a.spawn((span, EaseKind::Linear, target));
+    let tween = TargetComponent::resolved();
+    match track {
+        AnimationClipTrack::Translation(track) => {
+            commands.insert(tween.with(track))
+        }
+        AnimationClipTrack::Rotation(track) => {
+            commands.insert(tween.with(track))
+        }
+        AnimationClipTrack::Scale(track) => commands.insert(tween.with(track)),
+    };
+}
+
+/// Bake the [`Transform`] of the targets of this [`TimeRunner`] into an
+/// [`AnimationClip`] by [`bake_animation_clip_system`].
+///
+/// The time runner is restarted forward without repeat and advanced by one
+/// sample per frame with [`ManualTick`] so every tween system, including
+/// blending and additive tweens, contributes to the baked values. Once the
+/// time runner reaches its length, the clip is added to
+/// [`Assets<AnimationClip>`], [`AnimationClipBaked`] is triggered on the time
+/// runner entity, and this component is removed. [`ManualTick`] is left on the time runner.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[require(ManualTick)]
+pub struct BakeAnimationClip {
+    /// Samples per second
+    pub sample_rate: f32,
+    /// Entities to sample paired with the target id in the baked clip
+    pub targets: Vec<(Entity, AnimationTargetId)>,
+    times: Vec<f32>,
+    samples: Vec<Vec<Transform>>,
+    started: bool,
+}
+
+impl BakeAnimationClip {
+    /// Create new [`BakeAnimationClip`] sampling at `sample_rate` samples
+    /// per second
+    pub fn new(sample_rate: f32) -> BakeAnimationClip {
+        BakeAnimationClip {
+            sample_rate,
+            targets: Vec::new(),
+            times: Vec::new(),
+            samples: Vec::new(),
+            started: false,
+        }
+    }
+
+    /// Add a target entity to sample as `id` in the baked clip
+    pub fn with_target(
+        mut self,
+        entity: Entity,
+        id: AnimationTargetId,
+    ) -> Self {
+        self.targets.push((entity, id));
+        self
+    }
+
+    fn build_clip(&self) -> AnimationClip {
+        let mut clip = AnimationClip::default();
+        for (index, (_, id)) in self.targets.iter().enumerate() {
+            let transforms = self.samples.iter().map(|sample| sample[index]);
+            if let Some(curve) = sampled_curve(
+                &self.times,
+                transforms.clone().map(|t| t.translation),
+            ) {
+                clip.add_curve_to_target(*id, TranslationCurve(curve));
+            }
+            if let Some(curve) = sampled_curve(
+                &self.times,
+                transforms.clone().map(|t| t.rotation),
+            ) {
+                clip.add_curve_to_target(*id, RotationCurve(curve));
+            }
+            if let Some(curve) =
+                sampled_curve(&self.times, transforms.map(|t| t.scale))
+            {
+                clip.add_curve_to_target(*id, ScaleCurve(curve));
+            }
+        }
+        clip
+    }
+}
+
+fn sampled_curve<T>(
+    times: &[f32],
+    values: impl IntoIterator<Item = T>,
+) -> Option<UnevenSampleAutoCurve<T>>
+where
+    T: StableInterpolate,
+{
+    UnevenSampleAutoCurve::new(times.iter().copied().zip(values)).ok()
+}
+
+/// Triggered on the time runner entity when [`BakeAnimationClip`] is done,
+/// by [`bake_animation_clip_system`].
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct AnimationClipBaked {
+    /// The time runner entity
+    pub time_runner: Entity,
+    /// The baked clip
+    pub clip: Handle<AnimationClip>,
+}
+
+/// Sample the targets of every [`BakeAnimationClip`] after the tweens are
+/// applied then advance its [`TimeRunner`] to the next sample.
+pub fn bake_animation_clip_system(
+    mut commands: Commands,
+    mut clips: ResMut<Assets<AnimationClip>>,
+    mut q_bake: Query<(Entity, &mut BakeAnimationClip, &mut TimeRunner)>,
+    q_transform: Query<&Transform>,
+) {
+    for (entity, mut bake, mut time_runner) in &mut q_bake {
+        if !bake.started {
+            bake.started = true;
+            bake.times.clear();
+            bake.samples.clear();
+            time_runner
+                .set_direction(TimeDirection::Forward)
+                .set_repeat(None)
+                .restart();
+            continue;
+        }
+        let now = time_runner.elasped().now();
+        let sample = bake
+            .targets
+            .iter()
+            .map(|(target, _)| {
+                q_transform.get(*target).copied().unwrap_or_default()
+            })
+            .collect();
+        bake.times.push(now);
+        bake.samples.push(sample);
+
+        let length = time_runner.length().as_secs_f32();
+        if now >= length || bake.sample_rate <= 0. {
+            let clip = clips.add(bake.build_clip());
+            commands.entity(entity).remove::<BakeAnimationClip>();
+            commands.trigger_targets(
+                AnimationClipBaked {
+                    time_runner: entity,
+                    clip,
+                },
+                entity,
+            );
+            continue;
+        }
+        let step = (1. / bake.sample_rate).min(length - now);
+        // `TimeRunner::tick` would apply the time scale to the step as well
+        time_runner.raw_tick(step);
+    }
+}
@@ -0,0 +1,149 @@
+//! Play animations of a glTF as tweens targeting its nodes by name.
+//!
+//! **Combinators**:
+//! - [`gltf_animation`]
+//!
+//! Unlike [`animation_clip`](super::animation_clip) which targets joints
+//! with [`TargetJoint`], the curves are mapped back to the path of node
+//! names in the glTF and targeted with [`TargetPath`], so the scene doesn't
+//! need an [`AnimationPlayer`] or any [`AnimationTarget`]. Spawn the
+//! animation as a child of the parent of the glTF's root nodes, which is the
+//! first child of the [`SceneRoot`] entity, so the paths resolve from it.
+//!
+//! ```no_run
+//! use bevy::{
+//!     gltf::{Gltf, GltfNode},
+//!     prelude::*,
+//! };
+//! use bevy_tween::{animation_clip::bevy_gltf::gltf_animation, prelude::*};
+//!
+//! #[derive(Resource)]
+//! struct Door(Handle<Gltf>);
+//!
+//! fn open_door(
+//!     mut commands: Commands,
+//!     door: Res<Door>,
+//!     gltfs: Res<Assets<Gltf>>,
+//!     nodes: Res<Assets<GltfNode>>,
+//!     clips: Res<Assets<AnimationClip>>,
+//!     q_scene: Query<&Children, With<SceneRoot>>,
+//! ) {
+//!     let Some(gltf) = gltfs.get(&door.0) else {
+//!         return;
+//!     };
+//!     let Some(open) = gltf_animation(gltf, &nodes, &clips, "Open", 30.)
+//!     else {
+//!         return;
+//!     };
+//!     let Some(&root) = q_scene.iter().find_map(|children| children.first())
+//!     else {
+//!         return;
+//!     };
+//!     commands.entity(root).with_children(|c| {
+//!         c.spawn_empty().animation().insert(open);
+//!     });
+//! }
+//! ```
+//!
+//! [`AnimationPlayer`]: bevy::animation::AnimationPlayer
+//! [`AnimationTarget`]: bevy::animation::AnimationTarget
+
+use std::time::Duration;
+
+use bevy::{
+    animation::{AnimationClip, AnimationTargetId},
+    gltf::{Gltf, GltfNode},
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use bevy_time_runner::TimeSpan;
+
+use super::{animation_clip_tracks, spawn_track, AnimationClipTrack};
+use crate::{
+    combinator::AnimationCommands,
+    tween::{TargetJoint, TargetPath},
+};
+
+/// Path of [`Name`]s separated by `/`, as used by [`TargetPath`], of every
+/// node in `gltf` by the [`AnimationTargetId`] of the node.
+///
+/// Nodes that are not loaded in `nodes` are skipped along with their
+/// children.
+pub fn gltf_node_paths(
+    gltf: &Gltf,
+    nodes: &Assets<GltfNode>,
+) -> HashMap<AnimationTargetId, String> {
+    let children = gltf
+        .nodes
+        .iter()
+        .filter_map(|node| nodes.get(node))
+        .flat_map(|node| node.children.iter().map(|child| child.id()))
+        .collect::<HashSet<_>>();
+    let mut paths = HashMap::new();
+    let mut stack = gltf
+        .nodes
+        .iter()
+        .filter(|node| !children.contains(&node.id()))
+        .map(|node| (node.clone(), Vec::<Name>::new()))
+        .collect::<Vec<_>>();
+    while let Some((handle, mut names)) = stack.pop() {
+        let Some(node) = nodes.get(&handle) else {
+            continue;
+        };
+        names.push(Name::new(node.name.clone()));
+        let path = names.iter().map(Name::as_str).collect::<Vec<_>>().join("/");
+        paths.insert(AnimationTargetId::from_names(names.iter()), path);
+        stack.extend(
+            node.children
+                .iter()
+                .map(|child| (child.clone(), names.clone())),
+        );
+    }
+    paths
+}
+
+/// Combinator to play the animation `name` of `gltf` as tweens targeting
+/// the nodes by [`TargetPath`], sampling its curves at `sample_rate`
+/// samples per second. See the [module docs](self).
+///
+/// Returns `None` if there's no animation named `name` or its clip is not
+/// loaded. Curves of nodes not found in `gltf` fall back to
+/// [`TargetJoint`].
+///
+/// Starts from last position and lasts for the duration of the clip.
+///
+/// Position is shifted to the clip's end.
+pub fn gltf_animation(
+    gltf: &Gltf,
+    nodes: &Assets<GltfNode>,
+    clips: &Assets<AnimationClip>,
+    name: &str,
+    sample_rate: f32,
+) -> Option<impl FnOnce(&mut AnimationCommands, &mut Duration)> {
+    let clip = clips.get(gltf.named_animations.get(name)?)?;
+    let duration = Duration::from_secs_f32(clip.duration().max(0.));
+    let paths = gltf_node_paths(gltf, nodes);
+    let tracks = animation_clip_tracks(clip, sample_rate)
+        .into_iter()
+        .map(|(id, track)| (paths.get(&id).cloned().ok_or(id), track))
+        .collect::<Vec<_>>();
+    Some(spawn_tracks(duration, tracks))
+}
+
+fn spawn_tracks(
+    duration: Duration,
+    tracks: Vec<(Result<String, AnimationTargetId>, AnimationClipTrack)>,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    move |a, pos| {
+        let start = *pos;
+        let end = start + duration;
+        let span = TimeSpan::try_from(start..end).unwrap();
+        for (target, track) in tracks {
+            match target {
+                Ok(path) => spawn_track(a, span, TargetPath(path), track),
+                Err(id) => spawn_track(a, span, TargetJoint(id), track),
+            }
+        }
+        *pos = end;
+    }
+}
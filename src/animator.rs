@@ -0,0 +1,368 @@
+//! Module containing the [`Animator`] state machine to switch between
+//! animations by conditions.
+//!
+//! # Animator
+//!
+//! **Plugins**:
+//! - [`AnimatorPlugin`]
+//!
+//! **Components**:
+//! - [`Animator`]
+//!
+//! **Systems**:
+//! - [`animator_system`]
+//!
+//! Each state of an [`Animator`] plays a template from
+//! [`AnimationLibrary`](crate::combinator::AnimationLibrary) targeting the
+//! entity with the [`Animator`]. Transitions are checked every frame in order
+//! and the first one whose condition is met is taken.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{
+//!     animator::{Animator, AnimatorCondition, AnimatorTransition},
+//!     prelude::*,
+//! };
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn(
+//!         Animator::new("idle")
+//!             .with_state("idle", "player_idle")
+//!             .with_state("run", "player_run")
+//!             .with_state("jump", "player_jump")
+//!             .with_transition(
+//!                 AnimatorTransition::new("idle", "run")
+//!                     .when(AnimatorCondition::bool("running", true))
+//!                     .blend(Duration::from_millis(200)),
+//!             )
+//!             .with_transition(
+//!                 AnimatorTransition::new("run", "idle")
+//!                     .when(AnimatorCondition::bool("running", false))
+//!                     .blend(Duration::from_millis(200)),
+//!             )
+//!             .with_transition(
+//!                 AnimatorTransition::any("jump")
+//!                     .when(AnimatorCondition::trigger("jump")),
+//!             )
+//!             .with_transition(
+//!                 AnimatorTransition::new("jump", "idle")
+//!                     .when(AnimatorCondition::Ended),
+//!             ),
+//!     );
+//! }
+//!
+//! fn control(keys: Res<ButtonInput<KeyCode>>, mut q: Query<&mut Animator>) {
+//!     for mut animator in &mut q {
+//!         animator.set_bool("running", keys.pressed(KeyCode::ArrowRight));
+//!         if keys.just_pressed(KeyCode::Space) {
+//!             animator.trigger("jump");
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::time::Duration;
+
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use bevy_time_runner::TimeRunner;
+
+use crate::{
+    combinator::PlayAnimationExt,
+    playback::{crossfade, CompletionPolicy},
+    TweenSystemSet,
+};
+
+/// Plugin for [`Animator`]
+pub struct AnimatorPlugin;
+
+impl Plugin for AnimatorPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            animator_system.before(TweenSystemSet::TickTimeRunner),
+        )
+        .register_type::<Animator>()
+        .register_type::<AnimatorState>()
+        .register_type::<AnimatorTransition>()
+        .register_type::<AnimatorCondition>();
+    }
+}
+
+/// State of an [`Animator`]
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct AnimatorState {
+    /// Name of the template in
+    /// [`AnimationLibrary`](crate::combinator::AnimationLibrary) played while
+    /// in this state
+    pub animation: String,
+}
+
+/// Condition of an [`AnimatorTransition`]
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum AnimatorCondition {
+    /// The animation of the current state is completed
+    Ended,
+    /// [`Animator::trigger`] is called with this name
+    Trigger(String),
+    /// Bool parameter set by [`Animator::set_bool`] equals to `value`.
+    /// Parameters that are not set are `false`.
+    Bool {
+        #[allow(missing_docs)]
+        name: String,
+        #[allow(missing_docs)]
+        value: bool,
+    },
+}
+
+impl AnimatorCondition {
+    /// Constructor for [`AnimatorCondition::Trigger`]
+    pub fn trigger(name: impl Into<String>) -> AnimatorCondition {
+        AnimatorCondition::Trigger(name.into())
+    }
+
+    /// Constructor for [`AnimatorCondition::Bool`]
+    pub fn bool(name: impl Into<String>, value: bool) -> AnimatorCondition {
+        AnimatorCondition::Bool {
+            name: name.into(),
+            value,
+        }
+    }
+
+    fn is_met(&self, animator: &Animator, ended: bool) -> bool {
+        match self {
+            AnimatorCondition::Ended => ended,
+            AnimatorCondition::Trigger(name) => {
+                animator.triggers.contains(name)
+            }
+            AnimatorCondition::Bool { name, value } => {
+                animator.bool(name) == *value
+            }
+        }
+    }
+}
+
+/// Transition between states of an [`Animator`]
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct AnimatorTransition {
+    /// State to transition from. `None` to transition from any state.
+    pub from: Option<String>,
+    /// State to transition to
+    pub to: String,
+    /// Condition to take this transition
+    pub condition: AnimatorCondition,
+    /// Duration to [`crossfade`] from the animation of the previous state
+    /// to the new state. The previous animation is despawned afterward.
+    pub blend: Duration,
+}
+
+impl AnimatorTransition {
+    /// Create new [`AnimatorTransition`] from `from` to `to` when the
+    /// animation of `from` is ended
+    pub fn new(
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> AnimatorTransition {
+        AnimatorTransition {
+            from: Some(from.into()),
+            to: to.into(),
+            condition: AnimatorCondition::Ended,
+            blend: Duration::ZERO,
+        }
+    }
+
+    /// Create new [`AnimatorTransition`] from any state other than `to`
+    /// when the animation of the current state is ended
+    pub fn any(to: impl Into<String>) -> AnimatorTransition {
+        AnimatorTransition {
+            from: None,
+            to: to.into(),
+            condition: AnimatorCondition::Ended,
+            blend: Duration::ZERO,
+        }
+    }
+
+    /// Set the condition
+    pub fn when(mut self, condition: AnimatorCondition) -> Self {
+        self.condition = condition;
+        self
+    }
+
+    /// Set the blend duration
+    pub fn blend(mut self, blend: Duration) -> Self {
+        self.blend = blend;
+        self
+    }
+}
+
+/// State machine playing an animation from
+/// [`AnimationLibrary`](crate::combinator::AnimationLibrary) per state.
+/// See the [module docs](self).
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Animator {
+    /// States by name
+    pub states: HashMap<String, AnimatorState>,
+    /// Transitions checked in order
+    pub transitions: Vec<AnimatorTransition>,
+    /// State entered first
+    pub initial: String,
+    current: Option<String>,
+    bools: HashMap<String, bool>,
+    triggers: HashSet<String>,
+    travel: Option<String>,
+    #[reflect(ignore)]
+    runner: Option<Entity>,
+    #[reflect(ignore)]
+    blending_out: Vec<(Entity, Duration)>,
+}
+
+impl Animator {
+    /// Create new [`Animator`] starting from the `initial` state
+    pub fn new(initial: impl Into<String>) -> Animator {
+        Animator {
+            initial: initial.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a state playing `animation` from
+    /// [`AnimationLibrary`](crate::combinator::AnimationLibrary)
+    pub fn with_state(
+        mut self,
+        name: impl Into<String>,
+        animation: impl Into<String>,
+    ) -> Self {
+        self.states.insert(
+            name.into(),
+            AnimatorState {
+                animation: animation.into(),
+            },
+        );
+        self
+    }
+
+    /// Add a transition
+    pub fn with_transition(mut self, transition: AnimatorTransition) -> Self {
+        self.transitions.push(transition);
+        self
+    }
+
+    /// Name of the current state. `None` before the initial state is entered.
+    pub fn current_state(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Time runner playing the animation of the current state
+    pub fn runner(&self) -> Option<Entity> {
+        self.runner
+    }
+
+    /// Set a bool parameter
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) {
+        self.bools.insert(name.into(), value);
+    }
+
+    /// Get a bool parameter. Parameters that are not set are `false`.
+    pub fn bool(&self, name: &str) -> bool {
+        self.bools.get(name).copied().unwrap_or(false)
+    }
+
+    /// Set a trigger that is consumed by the next check of the transitions
+    pub fn trigger(&mut self, name: impl Into<String>) {
+        self.triggers.insert(name.into());
+    }
+
+    /// Go to `state` on the next check of the transitions regardless of
+    /// the conditions
+    pub fn travel(&mut self, state: impl Into<String>) {
+        self.travel = Some(state.into());
+    }
+}
+
+/// Enter the initial state and take transitions of every [`Animator`],
+/// then despawn animations of previous states once their blend duration
+/// is over.
+pub fn animator_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_animator: Query<(Entity, &mut Animator)>,
+    q_time_runner: Query<&TimeRunner>,
+) {
+    let delta = time.delta();
+    for (entity, mut animator) in &mut q_animator {
+        if !animator.blending_out.is_empty() {
+            let animator = animator.bypass_change_detection();
+            animator.blending_out.retain_mut(|(runner, remaining)| {
+                *remaining = remaining.saturating_sub(delta);
+                if remaining.is_zero() {
+                    if let Some(runner) = commands.get_entity(*runner) {
+                        runner.despawn_recursive();
+                    }
+                    return false;
+                }
+                true
+            });
+        }
+
+        let (next, blend) = match (&animator.travel, &animator.current) {
+            (Some(travel), _) => (travel.clone(), Duration::ZERO),
+            (None, None) => (animator.initial.clone(), Duration::ZERO),
+            (None, Some(current)) => {
+                let ended = animator
+                    .runner
+                    .and_then(|runner| q_time_runner.get(runner).ok())
+                    .map(|time_runner| time_runner.is_completed())
+                    .unwrap_or(false);
+                let Some(transition) = animator.transitions.iter().find(|t| {
+                    t.from
+                        .as_ref()
+                        .map_or(&t.to != current, |from| from == current)
+                        && t.condition.is_met(&animator, ended)
+                }) else {
+                    if !animator.triggers.is_empty() {
+                        animator.triggers.clear();
+                    }
+                    continue;
+                };
+                (transition.to.clone(), transition.blend)
+            }
+        };
+
+        let Some(state) = animator.states.get(&next) else {
+            error!("Animator has no state named \"{next}\"");
+            animator.current = Some(next);
+            animator.travel = None;
+            continue;
+        };
+        let runner = commands.entity(entity).play_animation(&state.animation);
+        // Completed runners are kept for `AnimatorCondition::Ended` and are
+        // despawned here when leaving the state instead.
+        commands.entity(runner).insert(CompletionPolicy::Keep);
+        let animator = animator.into_inner();
+        if let Some(previous) = animator.runner.replace(runner) {
+            if blend.is_zero() {
+                if let Some(previous) = commands.get_entity(previous) {
+                    previous.despawn_recursive();
+                }
+            } else {
+                commands.queue(crossfade(previous, runner, blend));
+                animator.blending_out.push((previous, blend));
+            }
+        }
+        animator.current = Some(next);
+        animator.travel = None;
+        animator.triggers.clear();
+    }
+}
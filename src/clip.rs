@@ -0,0 +1,423 @@
+//! Module containing the [`TweenClip`] asset to author animations as data
+//! instead of code.
+//!
+//! # Clip
+//!
+//! **Plugins**:
+//! - [`TweenClipPlugin`]
+//!
+//! **Assets**:
+//! - [`TweenClip`] loaded by [`TweenClipLoader`] from `.tween.ron` or
+//!   `.tween.json` files
+//!
+//! **Components**:
+//! - [`TweenClipPlayer`]
+//!
+//! **Systems**:
+//! - [`tween_clip_player_system`]
+//!
+//! A clip is a list of tweens with their span, ease, interpolator, and a
+//! target found by [`Name`] from the entity with [`TweenClipPlayer`]:
+//!
+//! ```ron
+//! (
+//!     repeat: Infinitely,
+//!     repeat_style: PingPong,
+//!     tweens: [
+//!         (
+//!             start: 0.0,
+//!             end: 1.0,
+//!             ease: QuadraticOut,
+//!             target: Path("Body/Head"),
+//!             interpolator: Translation(start: (0.0, 0.0, 0.0), end: (0.0, 10.0, 0.0)),
+//!         ),
+//!         (
+//!             start: 0.5,
+//!             end: 1.5,
+//!             ease: Linear,
+//!             target: Root,
+//!             interpolator: AngleZ(start: 0.0, end: 3.14),
+//!         ),
+//!     ],
+//! )
+//! ```
+//!
+//! The same structure is used in JSON with serde's default representation
+//! of enums, such as `{ "Path": "Body/Head" }` for the target.
+//!
+//! The animation is spawned as a child [`TimeRunner`] of the entity with
+//! [`TweenClipPlayer`] and is respawned, keeping its elapsed time, whenever
+//! the clip asset is hot-reloaded.
+
+use std::time::Duration;
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    utils::HashSet,
+};
+use bevy_time_runner::{Repeat, RepeatStyle, TimeRunner, TimeSpan};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    interpolate,
+    interpolation::EaseKind,
+    tween::{ComponentTween, TargetComponent, TargetName, TargetPath},
+    TweenSystemSet,
+};
+
+/// Plugin for [`TweenClip`] and [`TweenClipPlayer`]
+pub struct TweenClipPlugin;
+
+impl Plugin for TweenClipPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            tween_clip_player_system.before(TweenSystemSet::TickTimeRunner),
+        )
+        .init_asset::<TweenClip>()
+        .init_asset_loader::<TweenClipLoader>()
+        .register_type::<TweenClipPlayer>();
+    }
+}
+
+/// Animation described as data. See the [module docs](self) for the format.
+#[derive(
+    Debug, Default, Clone, PartialEq, Asset, TypePath, Serialize, Deserialize,
+)]
+pub struct TweenClip {
+    /// Length of the animation in seconds. Defaults to the end of the last
+    /// tween.
+    #[serde(default)]
+    pub length: Option<f32>,
+    #[allow(missing_docs)]
+    #[serde(default)]
+    pub repeat: ClipRepeat,
+    #[allow(missing_docs)]
+    #[serde(default)]
+    pub repeat_style: ClipRepeatStyle,
+    #[allow(missing_docs)]
+    pub tweens: Vec<ClipTween>,
+}
+
+impl TweenClip {
+    /// Length of the animation or `None` if it's not finite or too large
+    pub fn length(&self) -> Option<Duration> {
+        let length = self.length.unwrap_or_else(|| {
+            self.tweens.iter().map(|t| t.end).fold(0., f32::max)
+        });
+        clip_duration(length)
+    }
+}
+
+/// Seconds from a clip with negative seconds clamped to zero or `None` if
+/// they're not finite or too large.
+fn clip_duration(secs: f32) -> Option<Duration> {
+    if !secs.is_finite() {
+        return None;
+    }
+    Duration::try_from_secs_f32(secs.max(0.)).ok()
+}
+
+/// Serializable [`Repeat`]
+#[allow(missing_docs)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ClipRepeat {
+    #[default]
+    Never,
+    Times(i32),
+    Infinitely,
+}
+
+/// Serializable [`RepeatStyle`]
+#[allow(missing_docs)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ClipRepeatStyle {
+    #[default]
+    WrapAround,
+    PingPong,
+}
+
+/// A tween in [`TweenClip`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClipTween {
+    /// Start of the span in seconds
+    pub start: f32,
+    /// End of the span in seconds
+    pub end: f32,
+    #[allow(missing_docs)]
+    #[serde(default = "default_ease")]
+    pub ease: EaseKind,
+    #[allow(missing_docs)]
+    pub target: ClipTarget,
+    #[allow(missing_docs)]
+    pub interpolator: ClipInterpolator,
+}
+
+fn default_ease() -> EaseKind {
+    EaseKind::Linear
+}
+
+/// Target of a [`ClipTween`] relative to the entity with [`TweenClipPlayer`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClipTarget {
+    /// The entity with [`TweenClipPlayer`]
+    Root,
+    /// See [`TargetName`]
+    Name(String),
+    /// See [`TargetPath`]
+    Path(String),
+}
+
+/// Built-in interpolators that can be used in [`TweenClip`]
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClipInterpolator {
+    Translation { start: Vec3, end: Vec3 },
+    Rotation { start: Quat, end: Quat },
+    Scale { start: Vec3, end: Vec3 },
+    AngleZ { start: f32, end: f32 },
+}
+
+/// Play a [`TweenClip`] on this entity.
+///
+/// Names in [`ClipTarget`] are looked up from the children of this entity.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct TweenClipPlayer {
+    /// Clip to play
+    pub clip: Handle<TweenClip>,
+    /// Time runner currently spawned for the clip
+    #[reflect(ignore)]
+    runner: Option<Entity>,
+}
+
+impl TweenClipPlayer {
+    /// Create new [`TweenClipPlayer`]
+    pub fn new(clip: Handle<TweenClip>) -> TweenClipPlayer {
+        TweenClipPlayer { clip, runner: None }
+    }
+
+    /// Time runner currently spawned for the clip
+    pub fn runner(&self) -> Option<Entity> {
+        self.runner
+    }
+}
+
+/// [`AssetLoader`] for [`TweenClip`] in RON or JSON
+#[derive(Default)]
+pub struct TweenClipLoader;
+
+/// Error from [`TweenClipLoader`]
+#[derive(Debug)]
+pub enum TweenClipLoaderError {
+    /// Failed to read the file
+    Io(std::io::Error),
+    /// Failed to parse RON
+    Ron(ron::error::SpannedError),
+    /// Failed to parse JSON
+    Json(serde_json::Error),
+}
+
+impl core::error::Error for TweenClipLoaderError {}
+
+impl core::fmt::Display for TweenClipLoaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TweenClipLoaderError::Io(e) => {
+                write!(f, "Could not read tween clip: {e}")
+            }
+            TweenClipLoaderError::Ron(e) => {
+                write!(f, "Could not parse tween clip: {e}")
+            }
+            TweenClipLoaderError::Json(e) => {
+                write!(f, "Could not parse tween clip: {e}")
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for TweenClipLoaderError {
+    fn from(e: std::io::Error) -> Self {
+        TweenClipLoaderError::Io(e)
+    }
+}
+
+impl From<ron::error::SpannedError> for TweenClipLoaderError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        TweenClipLoaderError::Ron(e)
+    }
+}
+
+impl From<serde_json::Error> for TweenClipLoaderError {
+    fn from(e: serde_json::Error) -> Self {
+        TweenClipLoaderError::Json(e)
+    }
+}
+
+impl AssetLoader for TweenClipLoader {
+    type Asset = TweenClip;
+    type Settings = ();
+    type Error = TweenClipLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<TweenClip, TweenClipLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let is_json = load_context
+            .path()
+            .to_string_lossy()
+            .ends_with(".tween.json");
+        if is_json {
+            Ok(serde_json::from_slice(&bytes)?)
+        } else {
+            Ok(ron::de::from_bytes(&bytes)?)
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tween.ron", "tween.json"]
+    }
+}
+
+/// Spawn the animation of every [`TweenClipPlayer`] when its clip is changed
+/// or loaded, and respawn it when the clip asset is modified.
+pub fn tween_clip_player_system(
+    mut commands: Commands,
+    mut q_player: Query<(Entity, &mut TweenClipPlayer)>,
+    q_time_runner: Query<&TimeRunner>,
+    clips: Res<Assets<TweenClip>>,
+    mut clip_events: EventReader<AssetEvent<TweenClip>>,
+) {
+    let modified = clip_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    for (entity, mut player) in &mut q_player {
+        let changed = player.is_changed();
+        if !changed && !modified.contains(&player.clip.id()) {
+            continue;
+        }
+        let Some(clip) = clips.get(&player.clip) else {
+            continue;
+        };
+        let Some(length) = clip.length() else {
+            error!("Tween clip has an invalid length");
+            continue;
+        };
+        let mut elapsed = None;
+        if let Some(runner) = player.runner {
+            elapsed = q_time_runner.get(runner).ok().map(|r| r.elasped().now());
+            if let Some(runner) = commands.get_entity(runner) {
+                runner.despawn_recursive();
+            }
+        }
+
+        let mut time_runner = TimeRunner::new(length);
+        let repeat_style = match clip.repeat_style {
+            ClipRepeatStyle::WrapAround => RepeatStyle::WrapAround,
+            ClipRepeatStyle::PingPong => RepeatStyle::PingPong,
+        };
+        match clip.repeat {
+            ClipRepeat::Never => {}
+            ClipRepeat::Times(times) => {
+                time_runner
+                    .set_repeat(Some((Repeat::times(times), repeat_style)));
+            }
+            ClipRepeat::Infinitely => {
+                time_runner
+                    .set_repeat(Some((Repeat::infinitely(), repeat_style)));
+            }
+        }
+        if let Some(elapsed) = elapsed {
+            time_runner.set_tick(elapsed);
+        }
+
+        let runner = commands
+            .spawn(time_runner)
+            .set_parent(entity)
+            .with_children(|c| {
+                for tween in &clip.tweens {
+                    spawn_clip_tween(c, entity, tween);
+                }
+            })
+            .id();
+        // Bypass change detection so the runner isn't respawned next frame.
+        player.bypass_change_detection().runner = Some(runner);
+    }
+}
+
+fn spawn_clip_tween(c: &mut ChildBuilder, root: Entity, tween: &ClipTween) {
+    let span = clip_duration(tween.start)
+        .zip(clip_duration(tween.end))
+        .and_then(|(start, end)| TimeSpan::try_from(start..end).ok());
+    let Some(span) = span else {
+        error!(
+            "Tween clip has an invalid span from {} to {}",
+            tween.start, tween.end
+        );
+        return;
+    };
+    let (target, target_name, target_path) = match &tween.target {
+        ClipTarget::Root => (TargetComponent::entity(root), None, None),
+        ClipTarget::Name(name) => (
+            TargetComponent::resolved(),
+            Some(TargetName(name.clone())),
+            None,
+        ),
+        ClipTarget::Path(path) => (
+            TargetComponent::resolved(),
+            None,
+            Some(TargetPath(path.clone())),
+        ),
+    };
+    let mut span = c.spawn((span, tween.ease));
+    match tween.interpolator.clone() {
+        ClipInterpolator::Translation { start, end } => {
+            span.insert(ComponentTween::new_target(
+                target,
+                interpolate::translation(start, end),
+            ));
+        }
+        ClipInterpolator::Rotation { start, end } => {
+            span.insert(ComponentTween::new_target(
+                target,
+                interpolate::rotation(start, end),
+            ));
+        }
+        ClipInterpolator::Scale { start, end } => {
+            span.insert(ComponentTween::new_target(
+                target,
+                interpolate::scale(start, end),
+            ));
+        }
+        ClipInterpolator::AngleZ { start, end } => {
+            span.insert(ComponentTween::new_target(
+                target,
+                interpolate::angle_z(start, end),
+            ));
+        }
+    }
+    if let Some(name) = target_name {
+        span.insert(name);
+    }
+    if let Some(path) = target_path {
+        span.insert(path);
+    }
+}
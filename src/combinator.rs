@@ -7,9 +7,13 @@ use bevy_time_runner::{
     Repeat, RepeatStyle, SkipTimeRunner, TimeDirection, TimeRunner, TimeSpan,
 };
 
+use crate::playback::{ManualTick, MaxDelta};
+
 mod animation_combinators;
+mod library;
 mod state;
 pub use animation_combinators::*;
+pub use library::{AnimationLibrary, AnimationTemplate, PlayAnimationExt};
 pub use state::{TargetState, TransformTargetState, TransformTargetStateExt};
 
 /// Commands to use within an animation combinator
@@ -68,6 +72,8 @@ pub struct AnimationBuilder<'a> {
     time_runner: Option<TimeRunner>,
     custom_length: Option<Duration>,
     skipped: bool,
+    manual_tick: bool,
+    max_delta: Option<MaxDelta>,
 }
 impl<'a> AnimationBuilder<'a> {
     /// Create new [`AnimationBuilder`]
@@ -77,6 +83,8 @@ impl<'a> AnimationBuilder<'a> {
             time_runner: None,
             custom_length: None,
             skipped: false,
+            manual_tick: false,
+            max_delta: None,
         }
     }
 
@@ -138,6 +146,20 @@ impl<'a> AnimationBuilder<'a> {
         self
     }
 
+    /// Insert [`ManualTick`] so [`TimeRunner`] is not ticked automatically
+    /// and must be advanced by [`ManualTick::tick`].
+    pub fn manual_tick(mut self, manual_tick: bool) -> Self {
+        self.manual_tick = manual_tick;
+        self
+    }
+
+    /// Insert [`MaxDelta`] to limit how far [`TimeRunner`] can be advanced
+    /// in a single tick.
+    pub fn max_delta(mut self, max_delta: MaxDelta) -> Self {
+        self.max_delta = Some(max_delta);
+        self
+    }
+
     /// [`Self::paused`] and [`Self::skipped`]
     pub fn disabled(self, disabled: bool) -> Self {
         self.paused(disabled).skipped(disabled)
@@ -182,6 +204,8 @@ impl<'a> AnimationBuilder<'a> {
             time_runner,
             custom_length,
             skipped,
+            manual_tick,
+            max_delta,
         } = self;
         let mut dur = Duration::ZERO;
         entity_commands.with_children(|c| {
@@ -201,6 +225,12 @@ impl<'a> AnimationBuilder<'a> {
         if skipped {
             entity_commands.insert(SkipTimeRunner);
         }
+        if manual_tick {
+            entity_commands.insert(ManualTick::default());
+        }
+        if let Some(max_delta) = max_delta {
+            entity_commands.insert(max_delta);
+        }
         entity_commands
     }
 
@@ -223,6 +253,8 @@ impl<'a> AnimationBuilder<'a> {
             time_runner,
             custom_length,
             skipped,
+            manual_tick,
+            max_delta,
         } = self;
         let mut time_runner = time_runner.unwrap_or_default();
         match custom_length {
@@ -243,6 +275,12 @@ impl<'a> AnimationBuilder<'a> {
         if skipped {
             entity_commands.insert(SkipTimeRunner);
         }
+        if manual_tick {
+            entity_commands.insert(ManualTick::default());
+        }
+        if let Some(max_delta) = max_delta {
+            entity_commands.insert(max_delta);
+        }
         entity_commands
     }
 }
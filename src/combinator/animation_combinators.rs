@@ -1,4 +1,5 @@
-use super::AnimationCommands;
+use super::{AnimationBuilderExt, AnimationCommands};
+use crate::playback::{NestedTimeRunner, TimeRemap};
 use crate::prelude::TweenEventData;
 use bevy::prelude::*;
 use bevy_time_runner::TimeSpan;
@@ -77,6 +78,89 @@ where
     }
 }
 
+/// Combinator for a nested animation played by its own
+/// [`TimeRunner`](bevy_time_runner::TimeRunner) that
+/// is driven by the parent's time. See [`NestedTimeRunner`].
+///
+/// Starts from last position and lasts for provided `duration`. The nested
+/// animation is held at its end if it's shorter than `duration`.
+///
+/// Position is shifted to this nested animation's end.
+///
+/// To loop the nested animation within `duration`, spawn it with
+/// [`AnimationBuilder::repeat`](crate::combinator::AnimationBuilder::repeat)
+/// instead:
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_time_runner::TimeSpan;
+/// use bevy_tween::{combinator::*, playback::NestedTimeRunner, prelude::*};
+///
+/// fn looped(
+///     duration: Duration,
+///     animation: impl FnOnce(&mut AnimationCommands, &mut Duration),
+/// ) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+///     move |a, pos| {
+///         let span = TimeSpan::try_from(*pos..*pos + duration).unwrap();
+///         a.spawn(NestedTimeRunner::new(span))
+///             .animation()
+///             .repeat(Repeat::infinitely())
+///             .insert(animation);
+///         *pos += duration;
+///     }
+/// }
+/// ```
+pub fn nested<F>(
+    duration: Duration,
+    animation: F,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration)
+where
+    F: FnOnce(&mut AnimationCommands, &mut Duration),
+{
+    move |a, pos| {
+        let start = *pos;
+        let end = start + duration;
+        a.spawn(NestedTimeRunner::new(
+            TimeSpan::try_from(start..end).unwrap(),
+        ))
+        .animation()
+        .insert(animation);
+        *pos = end;
+    }
+}
+
+/// Combinator to play the next `window` of the animation over `duration`.
+/// See [`TimeRemap`].
+///
+/// Position is not mutated because the window is over the other
+/// animations.
+pub fn time_remap(
+    window: Duration,
+    duration: Duration,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    move |a, pos| {
+        a.spawn((
+            TimeSpan::try_from(*pos..*pos + window).unwrap(),
+            TimeRemap::new(duration),
+        ));
+    }
+}
+
+/// Combinator to freeze the animation at current position for `duration`.
+/// See [`TimeRemap`].
+///
+/// Position is not mutated because the freeze has no length.
+pub fn freeze(
+    duration: Duration,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    move |a, pos| {
+        a.spawn((
+            TimeSpan::try_from(*pos..=*pos).unwrap(),
+            TimeRemap::new(duration),
+        ));
+    }
+}
+
 /// Combinator for creating an tween event.
 ///
 /// Event will be emitted at current position.
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use bevy::{ecs::system::EntityCommands, prelude::*, utils::HashMap};
+
+use super::{AnimationBuilder, AnimationBuilderExt};
+use crate::playback::CompletionPolicy;
+
+/// Template stored in [`AnimationLibrary`].
+///
+/// Called with the animator's [`AnimationBuilder`] and the target entity the
+/// animation was played on.
+pub type AnimationTemplate =
+    Arc<dyn for<'a> Fn(AnimationBuilder<'a>, Entity) + Send + Sync + 'static>;
+
+/// Animation templates registered by name to be played with
+/// [`PlayAnimationExt::play_animation`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::{
+///     combinator::{tween, AnimationLibrary, PlayAnimationExt},
+///     prelude::*,
+/// };
+///
+/// fn setup(mut library: ResMut<AnimationLibrary>) {
+///     library.insert("hurt_flash", |a, target| {
+///         let target = target.into_target();
+///         a.repeat(Repeat::times(2)).insert(tween(
+///             Duration::from_millis(100),
+///             EaseKind::Linear,
+///             target.with(interpolate::scale(Vec3::ONE, Vec3::splat(1.2))),
+///         ));
+///     });
+/// }
+///
+/// fn on_hit(mut commands: Commands, enemy: Entity) {
+///     commands.entity(enemy).play_animation("hurt_flash");
+/// }
+/// ```
+#[derive(Default, Clone, Resource)]
+pub struct AnimationLibrary {
+    templates: HashMap<String, AnimationTemplate>,
+}
+
+impl AnimationLibrary {
+    /// Register a template under `name`, replacing any template with the same
+    /// name.
+    pub fn insert<F>(&mut self, name: impl Into<String>, template: F)
+    where
+        F: for<'a> Fn(AnimationBuilder<'a>, Entity) + Send + Sync + 'static,
+    {
+        self.templates.insert(name.into(), Arc::new(template));
+    }
+
+    /// Remove the template registered under `name`
+    pub fn remove(&mut self, name: &str) -> Option<AnimationTemplate> {
+        self.templates.remove(name)
+    }
+
+    /// Get the template registered under `name`
+    pub fn get(&self, name: &str) -> Option<&AnimationTemplate> {
+        self.templates.get(name)
+    }
+
+    /// Returns true if a template is registered under `name`
+    pub fn contains(&self, name: &str) -> bool {
+        self.templates.contains_key(name)
+    }
+
+    /// Iterate over the registered names
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.templates.keys().map(String::as_str)
+    }
+}
+
+impl core::fmt::Debug for AnimationLibrary {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimationLibrary")
+            .field("templates", &self.templates.keys())
+            .finish()
+    }
+}
+
+/// Extension trait to play animations from [`AnimationLibrary`]
+pub trait PlayAnimationExt {
+    /// Instantiate the template registered under `name` targeting this
+    /// entity. The animation is spawned in a new animator entity which is
+    /// returned and despawned once the animation completes, unless the
+    /// template inserts another [`CompletionPolicy`].
+    ///
+    /// Logs an error and despawns the animator if no template is registered
+    /// under `name` when the command is applied.
+    fn play_animation(&mut self, name: impl Into<String>) -> Entity;
+}
+
+impl PlayAnimationExt for EntityCommands<'_> {
+    fn play_animation(&mut self, name: impl Into<String>) -> Entity {
+        let target = self.id();
+        let name = name.into();
+        let animator = self
+            .commands()
+            .spawn(CompletionPolicy::DespawnRecursive)
+            .id();
+        self.commands().queue(move |world: &mut World| {
+            let Some(template) = world
+                .get_resource::<AnimationLibrary>()
+                .and_then(|library| library.get(&name))
+                .cloned()
+            else {
+                error!("No animation named \"{name}\" in `AnimationLibrary`");
+                world.despawn(animator);
+                return;
+            };
+            let mut commands = world.commands();
+            if let Some(mut animator) = commands.get_entity(animator) {
+                template(animator.animation(), target);
+            }
+            world.flush();
+        });
+        animator
+    }
+}
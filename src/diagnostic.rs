@@ -0,0 +1,149 @@
+//! Module containing [`Diagnostic`]s of the animation load.
+//!
+//! # Diagnostic
+//!
+//! **Plugins**:
+//! - [`TweenDiagnosticsPlugin`]
+//!
+//! **Resources**:
+//! - [`TweenSystemsStart`]
+//!
+//! **Systems**:
+//! - [`tween_diagnostics_start_system`]
+//! - [`tween_diagnostics_system`]
+//!
+//! The diagnostics are registered in
+//! [`DiagnosticsStore`](bevy::diagnostic::DiagnosticsStore) so they show up
+//! with the other diagnostics such as in
+//! [`LogDiagnosticsPlugin`](bevy::diagnostic::LogDiagnosticsPlugin).
+//!
+//! This plugin is not in [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+//!
+//! ```no_run
+//! use bevy::{diagnostic::LogDiagnosticsPlugin, prelude::*};
+//! use bevy_tween::{diagnostic::TweenDiagnosticsPlugin, prelude::*};
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((
+//!             DefaultPlugins,
+//!             DefaultTweenPlugins,
+//!             TweenDiagnosticsPlugin,
+//!             LogDiagnosticsPlugin::default(),
+//!         ))
+//!         .run();
+//! }
+//! ```
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    prelude::*,
+    utils::Instant,
+};
+use bevy_time_runner::{TimeRunner, TimeSpanProgress};
+
+use crate::{
+    tween::{SkipTween, TweenInterpolationValue},
+    TweenSystemSet,
+};
+
+/// Plugin for the diagnostics of this crate
+pub struct TweenDiagnosticsPlugin;
+
+impl TweenDiagnosticsPlugin {
+    /// Time runners that are not paused nor completed
+    pub const ACTIVE_TIME_RUNNERS: DiagnosticPath =
+        DiagnosticPath::const_new("tween/active_time_runners");
+    /// Spans with [`TimeSpanProgress`]
+    pub const ACTIVE_SPANS: DiagnosticPath =
+        DiagnosticPath::const_new("tween/active_spans");
+    /// Tweens applied this frame, which are the tweens with
+    /// [`TweenInterpolationValue`] that are not skipped
+    pub const TWEEN_APPLICATIONS: DiagnosticPath =
+        DiagnosticPath::const_new("tween/tween_applications");
+    /// Wall-clock time in milliseconds from before
+    /// [`TweenSystemSet::TickTimeRunner`] to after
+    /// [`TweenSystemSet::ApplyAdditiveTween`].
+    ///
+    /// This is the latency of the whole span of the schedule running the
+    /// tween systems, including any other system that runs in between or
+    /// in parallel and time spent waiting, not the time spent in the tween
+    /// systems themselves.
+    pub const SYSTEMS_LATENCY: DiagnosticPath =
+        DiagnosticPath::const_new("tween/systems_latency");
+}
+
+impl Plugin for TweenDiagnosticsPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                tween_diagnostics_start_system
+                    .before(TweenSystemSet::TickTimeRunner),
+                tween_diagnostics_system
+                    .after(TweenSystemSet::ApplyAdditiveTween),
+            ),
+        )
+        .init_resource::<TweenSystemsStart>()
+        .register_diagnostic(Diagnostic::new(Self::ACTIVE_TIME_RUNNERS))
+        .register_diagnostic(Diagnostic::new(Self::ACTIVE_SPANS))
+        .register_diagnostic(Diagnostic::new(Self::TWEEN_APPLICATIONS))
+        .register_diagnostic(
+            Diagnostic::new(Self::SYSTEMS_LATENCY).with_suffix("ms"),
+        );
+    }
+}
+
+/// When the tween systems started this frame, recorded by
+/// [`tween_diagnostics_start_system`]
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct TweenSystemsStart(pub Option<Instant>);
+
+/// Record when the tween systems start for
+/// [`TweenDiagnosticsPlugin::SYSTEMS_LATENCY`]
+pub fn tween_diagnostics_start_system(mut start: ResMut<TweenSystemsStart>) {
+    start.0 = Some(Instant::now());
+}
+
+/// Add the measurements of [`TweenDiagnosticsPlugin`]
+pub fn tween_diagnostics_system(
+    mut diagnostics: Diagnostics,
+    start: Res<TweenSystemsStart>,
+    q_time_runner: Query<&TimeRunner>,
+    q_span: Query<(), With<TimeSpanProgress>>,
+    q_tween: Query<(), (With<TweenInterpolationValue>, Without<SkipTween>)>,
+) {
+    diagnostics.add_measurement(
+        &TweenDiagnosticsPlugin::ACTIVE_TIME_RUNNERS,
+        || {
+            q_time_runner
+                .iter()
+                .filter(|time_runner| {
+                    !time_runner.paused() && !time_runner.is_completed()
+                })
+                .count() as f64
+        },
+    );
+    diagnostics.add_measurement(&TweenDiagnosticsPlugin::ACTIVE_SPANS, || {
+        q_span.iter().count() as f64
+    });
+    diagnostics
+        .add_measurement(&TweenDiagnosticsPlugin::TWEEN_APPLICATIONS, || {
+            q_tween.iter().count() as f64
+        });
+    if let Some(start) = start.0 {
+        diagnostics
+            .add_measurement(&TweenDiagnosticsPlugin::SYSTEMS_LATENCY, || {
+                start.elapsed().as_secs_f64() * 1000.
+            });
+    }
+}
@@ -0,0 +1,185 @@
+//! Module containing gizmo visualization of the motion paths of tweens.
+//!
+//! # Gizmos
+//!
+//! **Plugins**:
+//! - [`TweenGizmosPlugin`]
+//!
+//! **Components**:
+//! - [`TweenGizmos`]
+//!
+//! **Systems**:
+//! - [`draw_tween_gizmos_system`]
+//!
+//! Insert [`TweenGizmos`] to a [`TimeRunner`] to draw the path of each of its
+//! translation tweens, such as straight lines, [`Path`] splines, and
+//! [`Orbit`] circles, with a marker at the current position of the target.
+//!
+//! This plugin is not in [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+//! Use [`draw_tween_gizmos_system`] to draw custom interpolators.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{gizmos::{TweenGizmos, TweenGizmosPlugin}, prelude::*};
+//!
+//! fn setup(mut commands: Commands) {
+//!     let target = commands.spawn(Transform::default()).id().into_target();
+//!     commands
+//!         .animation()
+//!         .insert_tween_here(
+//!             Duration::from_secs(1),
+//!             EaseKind::Linear,
+//!             target.with(interpolate::translation(Vec3::ZERO, Vec3::X * 100.)),
+//!         )
+//!         .insert(TweenGizmos::default());
+//! }
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((DefaultPlugins, DefaultTweenPlugins, TweenGizmosPlugin))
+//!         .add_systems(Startup, setup)
+//!         .run();
+//! }
+//! ```
+//!
+//! [`TimeRunner`]: bevy_time_runner::TimeRunner
+//! [`Path`]: crate::interpolate::Path
+//! [`Orbit`]: crate::interpolate::Orbit
+
+use bevy::prelude::*;
+
+use crate::{
+    interpolate::{Interpolator, Orbit, Path, Translation, TranslationPerAxis},
+    tween::{
+        resolve_tween_targets, AnimationTarget, ComponentTween,
+        ResolvedTargets, SkipTween, TweenPlayers,
+    },
+    TweenSystemSet,
+};
+
+/// Plugin for [`TweenGizmos`] of the built-in translation interpolators
+pub struct TweenGizmosPlugin;
+
+impl Plugin for TweenGizmosPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                draw_tween_gizmos_system::<Translation>,
+                draw_tween_gizmos_system::<TranslationPerAxis>,
+                draw_tween_gizmos_system::<Path>,
+                draw_tween_gizmos_system::<Orbit>,
+            )
+                .after(TweenSystemSet::ApplyAdditiveTween),
+        )
+        .register_type::<TweenGizmos>();
+    }
+}
+
+/// Draw the paths of the tweens of this [`TimeRunner`] with gizmos.
+/// Remove it or set `enabled` to `false` to stop drawing.
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TweenGizmos {
+    #[allow(missing_docs)]
+    pub enabled: bool,
+    /// Color of the paths
+    pub path_color: Color,
+    /// Color of the markers at the current positions
+    pub marker_color: Color,
+    /// Radius of the markers
+    pub marker_radius: f32,
+    /// Number of line segments per path
+    pub segments: usize,
+}
+
+impl Default for TweenGizmos {
+    fn default() -> Self {
+        TweenGizmos {
+            enabled: true,
+            path_color: Color::srgb(1., 0.8, 0.),
+            marker_color: Color::srgb(1., 0.2, 0.2),
+            marker_radius: 4.,
+            segments: 32,
+        }
+    }
+}
+
+/// Draw the path of every [`ComponentTween<I>`] played by a time runner with
+/// [`TweenGizmos`] by sampling its interpolator on a copy of the target's
+/// [`Transform`], then a marker at the target's current translation.
+///
+/// Paths are drawn in world space using the target's parent
+/// [`GlobalTransform`].
+#[allow(clippy::too_many_arguments)]
+pub fn draw_tween_gizmos_system<I>(
+    mut gizmos: Gizmos,
+    q_tween: Query<(Entity, &ComponentTween<I>), Without<SkipTween>>,
+    q_tween_gizmos: Query<&TweenGizmos>,
+    players: TweenPlayers,
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    q_target: Query<(&Transform, Option<&Parent>)>,
+    q_global_transform: Query<&GlobalTransform>,
+) where
+    I: Interpolator<Item = Transform> + Send + Sync + 'static,
+{
+    for (entity, tween) in &q_tween {
+        let Some(settings) = players
+            .time_runner(entity)
+            .and_then(|time_runner| q_tween_gizmos.get(time_runner).ok())
+        else {
+            continue;
+        };
+        if !settings.enabled {
+            continue;
+        }
+        let Some(targets) = resolve_tween_targets::<I>(
+            entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            None,
+        ) else {
+            continue;
+        };
+        for target in targets.iter() {
+            let Ok((transform, parent)) = q_target.get(*target) else {
+                continue;
+            };
+            let parent = parent
+                .and_then(|parent| q_global_transform.get(parent.get()).ok())
+                .copied()
+                .unwrap_or_default();
+            let segments = settings.segments.max(1);
+            gizmos.linestrip(
+                (0..=segments).map(|i| {
+                    let mut sample = *transform;
+                    tween
+                        .interpolator
+                        .interpolate(&mut sample, i as f32 / segments as f32);
+                    parent.transform_point(sample.translation)
+                }),
+                settings.path_color,
+            );
+            gizmos.sphere(
+                Isometry3d::from_translation(
+                    parent.transform_point(transform.translation),
+                ),
+                settings.marker_radius,
+                settings.marker_color,
+            );
+        }
+    }
+}
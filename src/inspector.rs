@@ -0,0 +1,258 @@
+//! Module containing an egui timeline inspector for [`TimeRunner`]s.
+//!
+//! # Inspector
+//!
+//! **Plugins**:
+//! - [`TimelineInspectorPlugin`]
+//!
+//! **Resources**:
+//! - [`TimelineInspector`]
+//!
+//! **Systems**:
+//! - [`timeline_inspector_system`]
+//!
+//! The inspector window shows the spans of the selected time runner on
+//! a timeline with a playhead that can be dragged to scrub the animation.
+//! The bounds of each span can be edited and are written back to its
+//! [`TimeSpan`].
+//!
+//! This plugin is not in [`DefaultTweenPlugins`](crate::DefaultTweenPlugins)
+//! and requires [`EguiPlugin`](bevy_egui::EguiPlugin).
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_egui::EguiPlugin;
+//! use bevy_tween::{inspector::TimelineInspectorPlugin, prelude::*};
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((
+//!             DefaultPlugins,
+//!             EguiPlugin {
+//!                 enable_multipass_for_primary_context: false,
+//!             },
+//!             DefaultTweenPlugins,
+//!             TimelineInspectorPlugin,
+//!         ))
+//!         .run();
+//! }
+//! ```
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use bevy_time_runner::{TimeBound, TimeRunner, TimeSpan};
+
+use crate::tween::TimeRunnerSpans;
+
+/// Plugin for [`TimelineInspector`]
+pub struct TimelineInspectorPlugin;
+
+impl Plugin for TimelineInspectorPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            timeline_inspector_system
+                .before(crate::TweenSystemSet::TickTimeRunner)
+                .before(bevy_egui::EguiPostUpdateSet::EndPass),
+        )
+        .init_resource::<TimelineInspector>();
+    }
+}
+
+/// State of the timeline inspector window
+#[derive(Debug, Clone, PartialEq, Resource)]
+pub struct TimelineInspector {
+    /// Show the window
+    pub open: bool,
+    /// Time runner shown in the window
+    pub selected: Option<Entity>,
+}
+
+impl Default for TimelineInspector {
+    fn default() -> Self {
+        TimelineInspector {
+            open: true,
+            selected: None,
+        }
+    }
+}
+
+fn label(entity: Entity, name: Option<&Name>) -> String {
+    match name {
+        Some(name) => format!("{name} ({entity})"),
+        None => format!("{entity}"),
+    }
+}
+
+fn with_duration(bound: TimeBound, duration: Duration) -> TimeBound {
+    match bound {
+        TimeBound::Inclusive(_) => TimeBound::Inclusive(duration),
+        TimeBound::Exclusive(_) => TimeBound::Exclusive(duration),
+    }
+}
+
+/// Show the timeline inspector window
+#[allow(clippy::type_complexity)]
+pub fn timeline_inspector_system(
+    mut contexts: EguiContexts,
+    inspector: ResMut<TimelineInspector>,
+    mut q_time_runner: Query<(
+        Entity,
+        &mut TimeRunner,
+        Option<&Name>,
+        Option<&TimeRunnerSpans>,
+    )>,
+    mut q_span: Query<(Entity, &mut TimeSpan, Option<&Name>)>,
+) {
+    if !inspector.open {
+        return;
+    }
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+    let inspector = inspector.into_inner();
+    let mut open = inspector.open;
+    egui::Window::new("Timeline")
+        .open(&mut open)
+        .show(ctx, |ui| {
+            let selected_text = inspector
+                .selected
+                .and_then(|e| q_time_runner.get(e).ok())
+                .map(|(e, _, name, _)| label(e, name))
+                .unwrap_or_else(|| "None".to_string());
+            egui::ComboBox::from_label("Time runner")
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    for (entity, _, name, _) in &q_time_runner {
+                        ui.selectable_value(
+                            &mut inspector.selected,
+                            Some(entity),
+                            label(entity, name),
+                        );
+                    }
+                });
+
+            let Some(selected) = inspector.selected else {
+                return;
+            };
+            let Ok((_, mut time_runner, _, spans)) =
+                q_time_runner.get_mut(selected)
+            else {
+                return;
+            };
+            let length = time_runner.length().as_secs_f32();
+
+            ui.horizontal(|ui| {
+                let mut paused = time_runner.paused();
+                if ui.checkbox(&mut paused, "Paused").changed() {
+                    time_runner.set_paused(paused);
+                }
+                let mut now = time_runner.elasped().now();
+                if ui
+                    .add(egui::Slider::new(&mut now, 0.0..=length).text("s"))
+                    .changed()
+                {
+                    time_runner.set_tick(now);
+                }
+            });
+            let now = time_runner.elasped().now();
+
+            ui.separator();
+            let Some(spans) = spans else {
+                return;
+            };
+            egui::Grid::new("timeline_spans")
+                .num_columns(4)
+                .striped(true)
+                .show(ui, |ui| {
+                    for span in &spans.0 {
+                        let Ok((entity, mut span, name)) =
+                            q_span.get_mut(*span)
+                        else {
+                            continue;
+                        };
+                        let mut min = span.min().duration().as_secs_f32();
+                        let mut max = span.max().duration().as_secs_f32();
+
+                        ui.label(label(entity, name));
+                        let min_changed = ui
+                            .add(
+                                egui::DragValue::new(&mut min)
+                                    .speed(0.01)
+                                    .range(0.0..=max)
+                                    .suffix(" s"),
+                            )
+                            .changed();
+                        let max_changed = ui
+                            .add(
+                                egui::DragValue::new(&mut max)
+                                    .speed(0.01)
+                                    .range(min..=f32::MAX)
+                                    .suffix(" s"),
+                            )
+                            .changed();
+                        if min_changed || max_changed {
+                            let new_span = TimeSpan::new(
+                                with_duration(
+                                    span.min(),
+                                    Duration::from_secs_f32(min.max(0.)),
+                                ),
+                                with_duration(
+                                    span.max(),
+                                    Duration::from_secs_f32(max.max(0.)),
+                                ),
+                            );
+                            if let Ok(new_span) = new_span {
+                                *span = new_span;
+                            }
+                        }
+
+                        let width = ui.available_width().max(200.);
+                        let (rect, _) = ui.allocate_exact_size(
+                            egui::vec2(width, 12.),
+                            egui::Sense::hover(),
+                        );
+                        let x = |secs: f32| {
+                            let fraction = if length > 0. {
+                                (secs / length).clamp(0., 1.)
+                            } else {
+                                0.
+                            };
+                            rect.left() + rect.width() * fraction
+                        };
+                        let painter = ui.painter();
+                        painter.rect_filled(
+                            rect,
+                            2.,
+                            ui.visuals().extreme_bg_color,
+                        );
+                        painter.rect_filled(
+                            egui::Rect::from_x_y_ranges(
+                                x(min)..=x(max),
+                                rect.y_range(),
+                            ),
+                            2.,
+                            ui.visuals().selection.bg_fill,
+                        );
+                        painter.vline(
+                            x(now),
+                            rect.y_range(),
+                            egui::Stroke::new(1.5, egui::Color32::RED),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+    inspector.open = open;
+}
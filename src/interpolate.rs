@@ -3,14 +3,49 @@
 //! **Plugins**:
 //! - [`DefaultDynInterpolatorsPlugin`]
 //! - [`DefaultInterpolatorsPlugin`]
+//! - [`BlendInterpolatorsPlugin`]
+//! - [`AdditiveInterpolatorsPlugin`]
+//! - [`FromCurrentInterpolatorsPlugin`]
+//! - [`ReflectTweenPlugin`]
 //!
 //! **Built-in interpolators**:
 //! - [`Translation`]
+//! - [`TranslationPerAxis`]
 //! - [`Rotation`]
+//! - [`RotationEuler`]
 //! - [`Scale`]
 //! - [`AngleZ`]
+//! - [`TranslationBy`], [`RotationBy`], and [`ScaleBy`]
+//! - [`Path`]
+//! - [`Orbit`]
+//! - [`LookAt`]
+//! - [`Shake`]
+//! - [`Steps`]
+//! - [`KeyframeTrack`]
+//! - [`FromCurrent`]
+//! - [`ReflectField`]
 //! - [`SpriteColor`]
+//! - [`SpriteAtlasIndex`]
 //! - [`ColorMaterial`]
+//! - [`TextColor`]
+//! - [`TextAlpha`]
+//! - [`Opacity`]
+//! - [`ColorGradient`]
+//! - [`NodeWidth`], [`NodeHeight`], [`NodeLeft`], [`NodeRight`],
+//!   [`NodeTop`], [`NodeBottom`], [`NodeMargin`], and [`NodePadding`]
+//! - [`PerspectiveFov`], [`OrthographicScale`], [`ProjectionNear`], and
+//!   [`ProjectionFar`]
+//! - [`ClearColor`]
+//! - [`AmbientLightColor`] and [`AmbientLightBrightness`]
+//! - [`FogColor`], [`FogLinearDistance`], and [`FogDensity`]
+//! - [`StandardMaterialBaseColor`], [`StandardMaterialAlpha`],
+//!   [`StandardMaterialEmissive`], [`StandardMaterialRoughness`], and
+//!   [`StandardMaterialMetallic`]
+//! - [`MorphWeight`] and [`MorphWeightsAll`]
+//! - [`BloomIntensity`], [`BloomThreshold`], and
+//!   [`ChromaticAberrationIntensity`]
+//! - [`AudioSinkVolume`], [`AudioSinkSpeed`], [`SpatialAudioSinkVolume`], and
+//!   [`SpatialAudioSinkSpeed`]
 //!
 //! # Your own [`Interpolator`]
 //!
@@ -69,23 +104,83 @@
 //! [`resource_tween_system`]: crate::tween::resource_tween_system
 //! [`asset_tween_system`]: crate::tween::asset_tween_system
 
+#[cfg(feature = "bevy_audio")]
+mod audio;
 mod blanket_impl;
+#[cfg(feature = "bevy_render")]
+mod camera;
+#[cfg(any(
+    feature = "bevy_render",
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text"
+))]
+mod color;
+#[cfg(feature = "bevy_core_pipeline")]
+mod core_pipeline;
+mod keyframe;
+#[cfg(any(
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text"
+))]
+mod opacity;
+#[cfg(feature = "bevy_pbr")]
+mod pbr;
+mod reflect;
+mod shake;
 #[cfg(feature = "bevy_sprite")]
 mod sprite;
+mod step;
+#[cfg(feature = "bevy_text")]
+mod text;
 mod transform;
 #[cfg(feature = "bevy_ui")]
 mod ui;
 
+pub use keyframe::*;
+#[cfg(any(
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text"
+))]
+pub use opacity::*;
+pub use reflect::*;
+pub use shake::*;
+pub use step::*;
 pub use transform::*;
 
+#[cfg(feature = "bevy_audio")]
+pub use audio::*;
+
+#[cfg(feature = "bevy_render")]
+pub use camera::*;
+
+#[cfg(any(
+    feature = "bevy_render",
+    feature = "bevy_sprite",
+    feature = "bevy_ui",
+    feature = "bevy_text"
+))]
+pub use color::{color_gradient, ColorGradient, ColorSpace};
+
+#[cfg(feature = "bevy_core_pipeline")]
+pub use core_pipeline::*;
+
+#[cfg(feature = "bevy_pbr")]
+pub use pbr::*;
+
 #[cfg(feature = "bevy_sprite")]
 pub use sprite::*;
 
+#[cfg(feature = "bevy_text")]
+pub use text::*;
+
 #[cfg(feature = "bevy_ui")]
 pub use ui::*;
 
-use crate::{tween, BevyTweenRegisterSystems};
-use bevy::prelude::*;
+use crate::{tween, tween::RegisterComponentTween, BevyTweenRegisterSystems};
+use bevy::{ecs::reflect::ReflectMapEntities, prelude::*};
 
 /// Alias for an `Interpolator` as a boxed trait object.
 pub type BoxedInterpolator<Item> = Box<dyn Interpolator<Item = Item>>;
@@ -116,6 +211,83 @@ pub trait Interpolator: Send + Sync + 'static {
     ///
     /// [`Interpolation`]: crate::interpolation::Interpolation
     fn interpolate(&self, item: &mut Self::Item, value: f32);
+
+    /// Names of the fields of the item this interpolator writes. Used to
+    /// detect tweens of different interpolators writing the same field of
+    /// the same target, see [`ConflictPolicy`](tween::ConflictPolicy).
+    ///
+    /// Empty by default which leaves the interpolator out of the check.
+    fn fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+}
+
+/// Item that can be blended by weight. Used by
+/// [`blend_component_tween_system`](tween::blend_component_tween_system).
+pub trait Blend {
+    /// Move `self` toward `other` by `weight` which is between 0–1.
+    fn blend(&mut self, other: &Self, weight: f32);
+}
+
+impl Blend for Transform {
+    fn blend(&mut self, other: &Self, weight: f32) {
+        self.translation = self.translation.lerp(other.translation, weight);
+        self.rotation = self.rotation.slerp(other.rotation, weight);
+        self.scale = self.scale.lerp(other.scale, weight);
+    }
+}
+
+/// [`Interpolator`] that can take its `start` from the current value of the
+/// item. Used by [`FromCurrent`].
+pub trait CaptureStart: Interpolator {
+    /// Set `start` to the current value of `item`.
+    fn capture_start(&mut self, item: &Self::Item);
+}
+
+/// [`Interpolator`] that ignores the `start` of the inner interpolator and
+/// instead tweens from whatever the target's value is when the tween becomes
+/// active, captured again every time it becomes active such as when its time
+/// runner is restarted.
+///
+/// This allows smoothly retargeting an interrupted animation. Must be applied
+/// with [`component_tween_from_current_system`] which captures the start
+/// before interpolating, registered for the built-in interpolators by
+/// [`FromCurrentInterpolatorsPlugin`].
+///
+/// [`component_tween_from_current_system`]: crate::tween::component_tween_from_current_system
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FromCurrent<I> {
+    #[allow(missing_docs)]
+    pub interpolator: I,
+    /// Whether `start` is captured since the tween last became active
+    pub captured: bool,
+}
+
+impl<I> Interpolator for FromCurrent<I>
+where
+    I: Interpolator,
+{
+    type Item = I::Item;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        self.interpolator.interpolate(item, value)
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        self.interpolator.fields()
+    }
+}
+
+/// Constructor for [`FromCurrent`]
+pub fn from_current<I>(interpolator: I) -> FromCurrent<I>
+where
+    I: CaptureStart,
+{
+    FromCurrent {
+        interpolator,
+        captured: false,
+    }
 }
 
 // /// Reflect [`Interpolator`] trait
@@ -199,11 +371,38 @@ pub trait Interpolator: Send + Sync + 'static {
 ///
 /// Register type and systems for the following interpolators:
 /// - [`Translation`]
+/// - [`TranslationPerAxis`]
 /// - [`Rotation`]
+/// - [`RotationEuler`]
 /// - [`Scale`]
 /// - [`AngleZ`]
-/// - [`SpriteColor`] and [`ColorMaterial`] if `"bevy_sprite"` feature is enabled.
-/// - [`BackgroundColor`] and [`BorderColor`] if `"bevy_ui"` feature is enabled.
+/// - [`Path`]
+/// - [`Orbit`]
+/// - [`LookAt`] with [`LookAtEntity`]
+/// - [`Shake`] with [`apply_shake_tween_system`]
+/// - [`TranslationBy`], [`RotationBy`], and [`ScaleBy`] with
+///   [`relative_component_tween_system`](tween::relative_component_tween_system)
+/// - [`Opacity`] and [`ColorGradient`] if any of `"bevy_sprite"`,
+///   `"bevy_ui"`, or `"bevy_text"` feature is enabled.
+/// - [`SpriteColor`], [`SpriteAtlasIndex`], and [`ColorMaterial`] if
+///   `"bevy_sprite"` feature is enabled.
+/// - [`BackgroundColor`], [`BorderColor`], [`ScrollOffset`] and its
+///   [`FromCurrent`] used by [`scroll_to`](ui::scroll_to), and [`Node`]
+///   layout interpolators such as [`NodeWidth`] if `"bevy_ui"` feature is
+///   enabled.
+/// - [`TextColor`] and [`TextAlpha`] if `"bevy_text"` feature is enabled.
+/// - [`PerspectiveFov`], [`OrthographicScale`], [`ProjectionNear`], and
+///   [`ProjectionFar`] components and [`ClearColor`] resource if
+///   `"bevy_render"` feature is enabled.
+/// - [`AmbientLightColor`], [`AmbientLightBrightness`], [`FogColor`],
+///   [`FogLinearDistance`], [`FogDensity`], and the [`StandardMaterial`]
+///   interpolators such as [`StandardMaterialBaseColor`], [`MorphWeight`],
+///   and [`MorphWeightsAll`] if `"bevy_pbr"` feature is enabled.
+/// - [`BloomIntensity`], [`BloomThreshold`], and
+///   [`ChromaticAberrationIntensity`] if `"bevy_core_pipeline"` feature is
+///   enabled.
+/// - [`AudioSinkVolume`], [`AudioSinkSpeed`], [`SpatialAudioSinkVolume`], and
+///   [`SpatialAudioSinkSpeed`] if `"bevy_audio"` feature is enabled.
 pub struct DefaultInterpolatorsPlugin;
 impl Plugin for DefaultInterpolatorsPlugin {
     /// # Panics
@@ -214,26 +413,188 @@ impl Plugin for DefaultInterpolatorsPlugin {
     fn build(&self, app: &mut App) {
         app.add_tween_systems((
             tween::component_tween_system::<Translation>(),
+            tween::component_tween_system::<TranslationPerAxis>(),
             tween::component_tween_system::<Rotation>(),
+            tween::component_tween_system::<RotationEuler>(),
             tween::component_tween_system::<Scale>(),
             tween::component_tween_system::<AngleZ>(),
+            tween::component_tween_system::<Path>(),
+            tween::component_tween_system::<Orbit>(),
+            tween::component_tween_system::<LookAt>(),
+        ))
+        .register_component_tween::<Translation>()
+        .register_component_tween::<TranslationPerAxis>()
+        .register_component_tween::<Rotation>()
+        .register_component_tween::<RotationEuler>()
+        .register_component_tween::<Scale>()
+        .register_component_tween::<AngleZ>()
+        .register_component_tween::<Path>()
+        .register_component_tween::<Orbit>()
+        .register_component_tween::<LookAt>()
+        .register_type::<LookAtEntity>();
+
+        app.add_tween_systems((
+            tween::relative_component_tween_system::<TranslationBy>(),
+            tween::relative_component_tween_system::<RotationBy>(),
+            tween::relative_component_tween_system::<ScaleBy>(),
+        ))
+        .register_component_tween::<TranslationBy>()
+        .register_component_tween::<RotationBy>()
+        .register_component_tween::<ScaleBy>();
+
+        let schedule = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world")
+            .schedule;
+        app.add_systems(
+            schedule,
+            look_at_entity_system.in_set(crate::TweenSystemSet::ResolveTarget),
+        );
+
+        // Not an `Interpolator` so it has no `ReflectComponentTween`
+        type ShakeTween = tween::ComponentTween<Shake>;
+        app.add_tween_systems(shake::apply_shake_tween_system)
+            .register_type::<ShakeTween>()
+            .register_type_data::<ShakeTween, ReflectMapEntities>();
+
+        #[cfg(any(
+            feature = "bevy_sprite",
+            feature = "bevy_ui",
+            feature = "bevy_text"
+        ))]
+        app.add_tween_systems((
+            opacity::apply_color_tween_system::<Opacity>,
+            opacity::apply_color_tween_system::<ColorGradient>,
         ))
-        .register_type::<tween::ComponentTween<Translation>>()
-        .register_type::<tween::ComponentTween<Rotation>>()
-        .register_type::<tween::ComponentTween<Scale>>()
-        .register_type::<tween::ComponentTween<AngleZ>>();
+        .register_component_tween::<Opacity>()
+        .register_component_tween::<ColorGradient>();
 
         #[cfg(feature = "bevy_sprite")]
-        app.add_tween_systems(tween::component_tween_system::<SpriteColor>())
-            .register_type::<tween::ComponentTween<SpriteColor>>();
+        app.add_tween_systems((
+            tween::component_tween_system::<SpriteColor>(),
+            tween::component_tween_system::<SpriteAtlasIndex>(),
+        ))
+        .register_component_tween::<SpriteColor>()
+        .register_component_tween::<SpriteAtlasIndex>();
 
         #[cfg(feature = "bevy_ui")]
         app.add_tween_systems((
             tween::component_tween_system::<ui::BackgroundColor>(),
             tween::component_tween_system::<ui::BorderColor>(),
+            tween::component_tween_system::<ui::NodeWidth>(),
+            tween::component_tween_system::<ui::NodeHeight>(),
+            tween::component_tween_system::<ui::NodeLeft>(),
+            tween::component_tween_system::<ui::NodeRight>(),
+            tween::component_tween_system::<ui::NodeTop>(),
+            tween::component_tween_system::<ui::NodeBottom>(),
+            tween::component_tween_system::<ui::NodeMargin>(),
+            tween::component_tween_system::<ui::NodePadding>(),
+            tween::component_tween_system::<ui::ScrollOffset>(),
+            tween::component_tween_from_current_system::<ui::ScrollOffset>(),
         ))
-        .register_type::<tween::ComponentTween<ui::BackgroundColor>>()
-        .register_type::<tween::ComponentTween<ui::BorderColor>>();
+        .register_component_tween::<ui::BackgroundColor>()
+        .register_component_tween::<ui::BorderColor>()
+        .register_component_tween::<ui::NodeWidth>()
+        .register_component_tween::<ui::NodeHeight>()
+        .register_component_tween::<ui::NodeLeft>()
+        .register_component_tween::<ui::NodeRight>()
+        .register_component_tween::<ui::NodeTop>()
+        .register_component_tween::<ui::NodeBottom>()
+        .register_component_tween::<ui::NodeMargin>()
+        .register_component_tween::<ui::NodePadding>()
+        .register_component_tween::<ui::ScrollOffset>()
+        .register_component_tween::<FromCurrent<ui::ScrollOffset>>();
+
+        #[cfg(feature = "bevy_render")]
+        app.add_tween_systems((
+            tween::component_tween_system::<camera::PerspectiveFov>(),
+            tween::component_tween_system::<camera::OrthographicScale>(),
+            tween::component_tween_system::<camera::ProjectionNear>(),
+            tween::component_tween_system::<camera::ProjectionFar>(),
+        ))
+        .register_component_tween::<camera::PerspectiveFov>()
+        .register_component_tween::<camera::OrthographicScale>()
+        .register_component_tween::<camera::ProjectionNear>()
+        .register_component_tween::<camera::ProjectionFar>();
+
+        #[cfg(feature = "bevy_render")]
+        app.add_tween_systems(
+            tween::resource_tween_system::<camera::ClearColor>(),
+        )
+        .register_type::<tween::ResourceTween<camera::ClearColor>>();
+
+        #[cfg(feature = "bevy_pbr")]
+        app.add_tween_systems((
+            tween::resource_tween_system::<pbr::AmbientLightColor>(),
+            tween::resource_tween_system::<pbr::AmbientLightBrightness>(),
+        ))
+        .register_type::<tween::ResourceTween<pbr::AmbientLightColor>>()
+        .register_type::<tween::ResourceTween<pbr::AmbientLightBrightness>>();
+
+        #[cfg(feature = "bevy_pbr")]
+        app.add_tween_systems((
+            tween::component_tween_system::<pbr::FogColor>(),
+            tween::component_tween_system::<pbr::FogLinearDistance>(),
+            tween::component_tween_system::<pbr::FogDensity>(),
+        ))
+        .register_component_tween::<pbr::FogColor>()
+        .register_component_tween::<pbr::FogLinearDistance>()
+        .register_component_tween::<pbr::FogDensity>();
+
+        #[cfg(feature = "bevy_pbr")]
+        app.add_tween_systems((
+            tween::component_tween_system::<pbr::MorphWeight>(),
+            tween::component_tween_system::<pbr::MorphWeightsAll>(),
+        ))
+        .register_component_tween::<pbr::MorphWeight>()
+        .register_component_tween::<pbr::MorphWeightsAll>();
+
+        #[cfg(feature = "bevy_pbr")]
+        app.add_tween_systems((
+            tween::asset_tween_system::<pbr::StandardMaterialBaseColor>(),
+            tween::asset_tween_system::<pbr::StandardMaterialAlpha>(),
+            tween::asset_tween_system::<pbr::StandardMaterialEmissive>(),
+            tween::asset_tween_system::<pbr::StandardMaterialRoughness>(),
+            tween::asset_tween_system::<pbr::StandardMaterialMetallic>(),
+        ))
+        .register_type::<tween::AssetTween<pbr::StandardMaterialBaseColor>>()
+        .register_type::<tween::AssetTween<pbr::StandardMaterialAlpha>>()
+        .register_type::<tween::AssetTween<pbr::StandardMaterialEmissive>>()
+        .register_type::<tween::AssetTween<pbr::StandardMaterialRoughness>>()
+        .register_type::<tween::AssetTween<pbr::StandardMaterialMetallic>>();
+
+        #[cfg(feature = "bevy_core_pipeline")]
+        app.add_tween_systems((
+            tween::component_tween_system::<core_pipeline::BloomIntensity>(),
+            tween::component_tween_system::<core_pipeline::BloomThreshold>(),
+            tween::component_tween_system::<
+                core_pipeline::ChromaticAberrationIntensity,
+            >(),
+        ))
+        .register_component_tween::<core_pipeline::BloomIntensity>()
+        .register_component_tween::<core_pipeline::BloomThreshold>()
+        .register_component_tween::<core_pipeline::ChromaticAberrationIntensity>();
+
+        #[cfg(feature = "bevy_audio")]
+        app.add_tween_systems((
+            tween::component_tween_system::<audio::AudioSinkVolume>(),
+            tween::component_tween_system::<audio::AudioSinkSpeed>(),
+            tween::component_tween_system::<audio::SpatialAudioSinkVolume>(),
+            tween::component_tween_system::<audio::SpatialAudioSinkSpeed>(),
+        ))
+        .register_component_tween::<audio::AudioSinkVolume>()
+        .register_component_tween::<audio::AudioSinkSpeed>()
+        .register_component_tween::<audio::SpatialAudioSinkVolume>()
+        .register_component_tween::<audio::SpatialAudioSinkSpeed>();
+
+        #[cfg(feature = "bevy_text")]
+        app.add_tween_systems((
+            tween::component_tween_system::<text::TextColor>(),
+            tween::component_tween_system::<text::TextAlpha>(),
+        ))
+        .register_component_tween::<text::TextColor>()
+        .register_component_tween::<text::TextAlpha>();
 
         #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset",))]
         app.add_tween_systems(
@@ -243,13 +604,133 @@ impl Plugin for DefaultInterpolatorsPlugin {
     }
 }
 
+/// Blending of [`Translation`], [`TranslationPerAxis`], [`Rotation`],
+/// [`RotationEuler`], [`Scale`], and [`AngleZ`] tweens played with
+/// [`BlendWeight`](crate::playback::BlendWeight) or
+/// [`ConflictPolicy::Blend`](crate::tween::ConflictPolicy::Blend) with
+/// [`blend_component_tween_system`](tween::blend_component_tween_system),
+/// such as by a cross-fade.
+///
+/// Not with [`DefaultTweenPlugins`](crate::DefaultTweenPlugins), without it
+/// blended tweens overwrite each other.
+pub struct BlendInterpolatorsPlugin;
+impl Plugin for BlendInterpolatorsPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        app.add_blend_tween_systems::<Translation, _>(
+            tween::blend_component_tween_system::<Translation>(),
+        )
+        .add_blend_tween_systems::<TranslationPerAxis, _>(
+            tween::blend_component_tween_system::<TranslationPerAxis>(),
+        )
+        .add_blend_tween_systems::<Rotation, _>(
+            tween::blend_component_tween_system::<Rotation>(),
+        )
+        .add_blend_tween_systems::<RotationEuler, _>(
+            tween::blend_component_tween_system::<RotationEuler>(),
+        )
+        .add_blend_tween_systems::<Scale, _>(
+            tween::blend_component_tween_system::<Scale>(),
+        )
+        .add_blend_tween_systems::<AngleZ, _>(
+            tween::blend_component_tween_system::<AngleZ>(),
+        );
+    }
+}
+
+/// [`Additive`](tween::Additive) tweens of [`TranslationBy`], [`RotationBy`],
+/// and [`ScaleBy`] with
+/// [`additive_component_tween_system`](tween::additive_component_tween_system).
+///
+/// Not with [`DefaultTweenPlugins`](crate::DefaultTweenPlugins), without it
+/// additive tweens aren't applied.
+pub struct AdditiveInterpolatorsPlugin;
+impl Plugin for AdditiveInterpolatorsPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        app.add_additive_tween_systems((
+            tween::additive_component_tween_system::<TranslationBy>(),
+            tween::additive_component_tween_system::<RotationBy>(),
+            tween::additive_component_tween_system::<ScaleBy>(),
+        ));
+    }
+}
+
+/// [`FromCurrent`] of [`Translation`], [`TranslationPerAxis`], [`Rotation`],
+/// [`RotationEuler`], [`Scale`], [`AngleZ`], and [`LookAt`] with
+/// [`component_tween_from_current_system`](tween::component_tween_from_current_system),
+/// and of:
+/// - [`SpriteColor`] if `"bevy_sprite"` feature is enabled.
+/// - [`BackgroundColor`] and [`BorderColor`] if `"bevy_ui"` feature is
+///   enabled, [`FromCurrent`] of [`ScrollOffset`] is already in
+///   [`DefaultInterpolatorsPlugin`].
+/// - [`TextColor`] if `"bevy_text"` feature is enabled.
+///
+/// Not with [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+pub struct FromCurrentInterpolatorsPlugin;
+impl Plugin for FromCurrentInterpolatorsPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        app.add_tween_systems((
+            tween::component_tween_from_current_system::<Translation>(),
+            tween::component_tween_from_current_system::<TranslationPerAxis>(),
+            tween::component_tween_from_current_system::<Rotation>(),
+            tween::component_tween_from_current_system::<RotationEuler>(),
+            tween::component_tween_from_current_system::<Scale>(),
+            tween::component_tween_from_current_system::<AngleZ>(),
+            tween::component_tween_from_current_system::<LookAt>(),
+        ))
+        .register_component_tween::<FromCurrent<Translation>>()
+        .register_component_tween::<FromCurrent<TranslationPerAxis>>()
+        .register_component_tween::<FromCurrent<Rotation>>()
+        .register_component_tween::<FromCurrent<RotationEuler>>()
+        .register_component_tween::<FromCurrent<Scale>>()
+        .register_component_tween::<FromCurrent<AngleZ>>()
+        .register_component_tween::<FromCurrent<LookAt>>();
+
+        #[cfg(feature = "bevy_sprite")]
+        app.add_tween_systems(tween::component_tween_from_current_system::<
+            SpriteColor,
+        >())
+        .register_component_tween::<FromCurrent<SpriteColor>>();
+
+        #[cfg(feature = "bevy_ui")]
+        app.add_tween_systems((
+            tween::component_tween_from_current_system::<ui::BackgroundColor>(),
+            tween::component_tween_from_current_system::<ui::BorderColor>(),
+        ))
+        .register_component_tween::<FromCurrent<ui::BackgroundColor>>()
+        .register_component_tween::<FromCurrent<ui::BorderColor>>();
+
+        #[cfg(feature = "bevy_text")]
+        app.add_tween_systems(tween::component_tween_from_current_system::<
+            text::TextColor,
+        >())
+        .register_component_tween::<FromCurrent<text::TextColor>>();
+    }
+}
+
 /// Default dynamic interpolators
 ///
 /// Register systems for the following:
 /// - [`Transform`] component.
 /// - [`Sprite`] component if `"bevy_sprite"` feature is enabled.
 /// - [`ColorMaterial`] asset if `"bevy_sprite"` feature is enabled.
-/// - [`BackgroundColor`] and [`BorderColor`] components if `"bevy_ui"` feature is enabled.
+/// - [`BackgroundColor`], [`BorderColor`], and [`Node`] components if `"bevy_ui"` feature is enabled.
+/// - [`TextColor`] component if `"bevy_text"` feature is enabled.
+/// - [`StandardMaterial`] asset if `"bevy_pbr"` feature is enabled.
 ///
 /// [`ColorMaterial`]: bevy::sprite::ColorMaterial
 pub struct DefaultDynInterpolatorsPlugin;
@@ -277,11 +758,22 @@ impl Plugin for DefaultDynInterpolatorsPlugin {
             tween::component_tween_system::<
                 BoxedInterpolator<bevy::prelude::BorderColor>,
             >(),
+            tween::component_tween_system::<BoxedInterpolator<Node>>(),
         ));
 
+        #[cfg(feature = "bevy_text")]
+        app.add_tween_systems(tween::component_tween_system::<
+            BoxedInterpolator<bevy::prelude::TextColor>,
+        >());
+
         #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset",))]
         app.add_tween_systems(tween::asset_tween_system::<
             BoxedInterpolator<bevy::sprite::ColorMaterial>,
         >());
+
+        #[cfg(feature = "bevy_pbr")]
+        app.add_tween_systems(tween::asset_tween_system::<
+            BoxedInterpolator<StandardMaterial>,
+        >());
     }
 }
@@ -0,0 +1,144 @@
+use crate::prelude::Interpolator;
+use bevy::{
+    audio::{AudioSink, AudioSinkPlayback, SpatialAudioSink},
+    prelude::*,
+};
+
+/// [`Interpolator`] for [`AudioSink`]'s volume
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AudioSinkVolume {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for AudioSinkVolume {
+    type Item = AudioSink;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_volume(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`AudioSinkVolume`]
+pub fn audio_sink_volume(start: f32, end: f32) -> AudioSinkVolume {
+    AudioSinkVolume { start, end }
+}
+
+/// Constructor for [`AudioSinkVolume`] that's relative to previous value using currying.
+pub fn audio_sink_volume_to(to: f32) -> impl Fn(&mut f32) -> AudioSinkVolume {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        audio_sink_volume(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`AudioSink`]'s playback speed
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AudioSinkSpeed {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for AudioSinkSpeed {
+    type Item = AudioSink;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_speed(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`AudioSinkSpeed`]
+pub fn audio_sink_speed(start: f32, end: f32) -> AudioSinkSpeed {
+    AudioSinkSpeed { start, end }
+}
+
+/// Constructor for [`AudioSinkSpeed`] that's relative to previous value using currying.
+pub fn audio_sink_speed_to(to: f32) -> impl Fn(&mut f32) -> AudioSinkSpeed {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        audio_sink_speed(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`SpatialAudioSink`]'s volume
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SpatialAudioSinkVolume {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for SpatialAudioSinkVolume {
+    type Item = SpatialAudioSink;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_volume(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`SpatialAudioSinkVolume`]
+pub fn spatial_audio_sink_volume(
+    start: f32,
+    end: f32,
+) -> SpatialAudioSinkVolume {
+    SpatialAudioSinkVolume { start, end }
+}
+
+/// Constructor for [`SpatialAudioSinkVolume`] that's relative to previous value using currying.
+pub fn spatial_audio_sink_volume_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> SpatialAudioSinkVolume {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        spatial_audio_sink_volume(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`SpatialAudioSink`]'s playback speed
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SpatialAudioSinkSpeed {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for SpatialAudioSinkSpeed {
+    type Item = SpatialAudioSink;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_speed(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`SpatialAudioSinkSpeed`]
+pub fn spatial_audio_sink_speed(start: f32, end: f32) -> SpatialAudioSinkSpeed {
+    SpatialAudioSinkSpeed { start, end }
+}
+
+/// Constructor for [`SpatialAudioSinkSpeed`] that's relative to previous value using currying.
+pub fn spatial_audio_sink_speed_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> SpatialAudioSinkSpeed {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        spatial_audio_sink_speed(start, end)
+    }
+}
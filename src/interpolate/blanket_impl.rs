@@ -10,6 +10,10 @@ where
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
         (**self).interpolate(item, value)
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        (**self).fields()
+    }
 }
 
 impl<I> Interpolator for &'static I
@@ -21,6 +25,10 @@ where
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
         (**self).interpolate(item, value)
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        (**self).fields()
+    }
 }
 
 impl<I> Interpolator for Arc<I>
@@ -32,6 +40,10 @@ where
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
         (**self).interpolate(item, value)
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        (**self).fields()
+    }
 }
 
 impl<I: 'static> Interpolator for dyn Fn(&mut I, f32) + Send + Sync + 'static {
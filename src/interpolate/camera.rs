@@ -0,0 +1,189 @@
+use crate::interpolate::{color::impl_in_space, ColorSpace, Interpolator};
+use bevy::prelude::*;
+
+/// [`Interpolator`] for the [`PerspectiveProjection::fov`] of a
+/// [`Projection`]. Does nothing to an orthographic projection.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PerspectiveFov {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for PerspectiveFov {
+    type Item = Projection;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Projection::Perspective(perspective) = item {
+            perspective.fov = self.start.lerp(self.end, value);
+        }
+    }
+}
+
+/// Constructor for [`PerspectiveFov`]
+pub fn perspective_fov(start: f32, end: f32) -> PerspectiveFov {
+    PerspectiveFov { start, end }
+}
+
+/// Constructor for [`PerspectiveFov`] that's relative to previous value using currying.
+pub fn perspective_fov_to(to: f32) -> impl Fn(&mut f32) -> PerspectiveFov {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        perspective_fov(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`OrthographicProjection::scale`] used by 2D
+/// cameras.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct OrthographicScale {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for OrthographicScale {
+    type Item = OrthographicProjection;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.scale = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`OrthographicScale`]
+pub fn orthographic_scale(start: f32, end: f32) -> OrthographicScale {
+    OrthographicScale { start, end }
+}
+
+/// Constructor for [`OrthographicScale`] that's relative to previous value using currying.
+pub fn orthographic_scale_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> OrthographicScale {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        orthographic_scale(start, end)
+    }
+}
+
+/// [`Interpolator`] for the near clipping plane of a [`Projection`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ProjectionNear {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for ProjectionNear {
+    type Item = Projection;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let near = self.start.lerp(self.end, value);
+        match item {
+            Projection::Perspective(perspective) => perspective.near = near,
+            Projection::Orthographic(orthographic) => orthographic.near = near,
+        }
+    }
+}
+
+/// Constructor for [`ProjectionNear`]
+pub fn projection_near(start: f32, end: f32) -> ProjectionNear {
+    ProjectionNear { start, end }
+}
+
+/// Constructor for [`ProjectionNear`] that's relative to previous value using currying.
+pub fn projection_near_to(to: f32) -> impl Fn(&mut f32) -> ProjectionNear {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        projection_near(start, end)
+    }
+}
+
+/// [`Interpolator`] for the far clipping plane of a [`Projection`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ProjectionFar {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for ProjectionFar {
+    type Item = Projection;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let far = self.start.lerp(self.end, value);
+        match item {
+            Projection::Perspective(perspective) => perspective.far = far,
+            Projection::Orthographic(orthographic) => orthographic.far = far,
+        }
+    }
+}
+
+/// Constructor for [`ProjectionFar`]
+pub fn projection_far(start: f32, end: f32) -> ProjectionFar {
+    ProjectionFar { start, end }
+}
+
+/// Constructor for [`ProjectionFar`] that's relative to previous value using currying.
+pub fn projection_far_to(to: f32) -> impl Fn(&mut f32) -> ProjectionFar {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        projection_far(start, end)
+    }
+}
+
+/// [`Interpolator`] for the [`ClearColor`](bevy::prelude::ClearColor) resource.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ClearColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+}
+
+impl Interpolator for ClearColor {
+    type Item = bevy::prelude::ClearColor;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.0 = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+/// Constructor for [`ClearColor`](crate::interpolate::ClearColor)
+pub fn clear_color(start: Color, end: Color) -> ClearColor {
+    ClearColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
+}
+
+/// Constructor for [`ClearColor`](crate::interpolate::ClearColor) that's relative to previous value using currying.
+pub fn clear_color_to(to: Color) -> impl Fn(&mut Color) -> ClearColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        clear_color(start, end)
+    }
+}
+
+impl_in_space!(ClearColor);
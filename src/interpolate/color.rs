@@ -0,0 +1,122 @@
+use crate::interpolate::Interpolator;
+use bevy::prelude::*;
+
+/// Color space used by color interpolators to mix between `start` and `end`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorSpace {
+    /// Mix in the color space of `start`, same as [`Color::mix`].
+    #[default]
+    Native,
+    /// Mix in [`Srgba`]
+    Srgb,
+    /// Mix in [`LinearRgba`]
+    Linear,
+    /// Mix in [`Hsva`] taking the shortest path around the hue
+    Hsv,
+    /// Mix in [`Oklaba`]
+    Oklab,
+    /// Mix in [`Oklcha`] taking the shortest path around the hue
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Mix `start` and `end` by `value` in this color space.
+    pub fn mix(&self, start: &Color, end: &Color, value: f32) -> Color {
+        fn mix_in<C>(start: &Color, end: &Color, value: f32) -> Color
+        where
+            C: Mix + From<Color> + Into<Color>,
+        {
+            C::from(*start).mix(&C::from(*end), value).into()
+        }
+        match self {
+            ColorSpace::Native => start.mix(end, value),
+            ColorSpace::Srgb => mix_in::<Srgba>(start, end, value),
+            ColorSpace::Linear => mix_in::<LinearRgba>(start, end, value),
+            ColorSpace::Hsv => mix_in::<Hsva>(start, end, value),
+            ColorSpace::Oklab => mix_in::<Oklaba>(start, end, value),
+            ColorSpace::Oklch => mix_in::<Oklcha>(start, end, value),
+        }
+    }
+}
+
+/// [`Interpolator`] for a [`Color`] sampled from a gradient of ordered
+/// `(position, color)` stops where position is typically between 0–1.
+///
+/// Values before the first stop or after the last stop use the first or last
+/// color. Applied to the same components as [`Opacity`] by
+/// [`apply_color_tween_system`].
+///
+/// [`Opacity`]: crate::interpolate::Opacity
+/// [`apply_color_tween_system`]: crate::interpolate::apply_color_tween_system
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ColorGradient {
+    /// `(position, color)` stops sorted by position
+    pub stops: Vec<(f32, Color)>,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+}
+
+impl ColorGradient {
+    /// Sample the gradient at `value`.
+    ///
+    /// Returns [`Color::NONE`] if there's no stop.
+    pub fn sample(&self, value: f32) -> Color {
+        let next = self
+            .stops
+            .partition_point(|(position, _)| *position <= value);
+        let start = next.checked_sub(1).and_then(|i| self.stops.get(i));
+        match (start, self.stops.get(next)) {
+            (Some((start_position, start)), Some((end_position, end))) => {
+                let t =
+                    (value - start_position) / (end_position - start_position);
+                self.space.mix(start, end, t)
+            }
+            (Some((_, color)), None) | (None, Some((_, color))) => *color,
+            (None, None) => Color::NONE,
+        }
+    }
+}
+
+impl Interpolator for ColorGradient {
+    type Item = Color;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        *item = self.sample(value);
+    }
+}
+
+/// Constructor for [`ColorGradient`]. Stops are sorted by position.
+pub fn color_gradient(
+    stops: impl IntoIterator<Item = (f32, Color)>,
+) -> ColorGradient {
+    let mut stops = stops.into_iter().collect::<Vec<_>>();
+    stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    ColorGradient {
+        stops,
+        space: ColorSpace::default(),
+    }
+}
+
+/// Implements an `in_space` builder for color interpolators with a
+/// `space: ColorSpace` field.
+macro_rules! impl_in_space {
+    ($($name:ty),* $(,)?) => {
+        $(
+            impl $name {
+                /// Interpolate in this [`ColorSpace`](crate::interpolate::ColorSpace)
+                pub fn in_space(
+                    mut self,
+                    space: $crate::interpolate::ColorSpace,
+                ) -> Self {
+                    self.space = space;
+                    self
+                }
+            }
+        )*
+    };
+}
+pub(crate) use impl_in_space;
+
+impl_in_space!(ColorGradient);
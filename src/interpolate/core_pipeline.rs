@@ -0,0 +1,107 @@
+use crate::prelude::Interpolator;
+use bevy::core_pipeline::{bloom::Bloom, post_process::ChromaticAberration};
+use bevy::prelude::*;
+
+/// [`Interpolator`] for [`Bloom::intensity`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BloomIntensity {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for BloomIntensity {
+    type Item = Bloom;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.intensity = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`BloomIntensity`]
+pub fn bloom_intensity(start: f32, end: f32) -> BloomIntensity {
+    BloomIntensity { start, end }
+}
+
+/// Constructor for [`BloomIntensity`] that's relative to previous value using currying.
+pub fn bloom_intensity_to(to: f32) -> impl Fn(&mut f32) -> BloomIntensity {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        bloom_intensity(start, end)
+    }
+}
+
+/// [`Interpolator`] for the prefilter threshold of [`Bloom`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct BloomThreshold {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for BloomThreshold {
+    type Item = Bloom;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.prefilter.threshold = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`BloomThreshold`]
+pub fn bloom_threshold(start: f32, end: f32) -> BloomThreshold {
+    BloomThreshold { start, end }
+}
+
+/// Constructor for [`BloomThreshold`] that's relative to previous value using currying.
+pub fn bloom_threshold_to(to: f32) -> impl Fn(&mut f32) -> BloomThreshold {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        bloom_threshold(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`ChromaticAberration::intensity`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ChromaticAberrationIntensity {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for ChromaticAberrationIntensity {
+    type Item = ChromaticAberration;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.intensity = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`ChromaticAberrationIntensity`]
+pub fn chromatic_aberration_intensity(
+    start: f32,
+    end: f32,
+) -> ChromaticAberrationIntensity {
+    ChromaticAberrationIntensity { start, end }
+}
+
+/// Constructor for [`ChromaticAberrationIntensity`] that's relative to previous value using currying.
+pub fn chromatic_aberration_intensity_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> ChromaticAberrationIntensity {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        chromatic_aberration_intensity(start, end)
+    }
+}
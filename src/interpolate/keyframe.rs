@@ -0,0 +1,135 @@
+use crate::interpolate::Interpolator;
+use crate::interpolation::EaseKind;
+use std::time::Duration;
+
+/// A keyframe of [`KeyframeTrack`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Keyframe<V> {
+    /// Time in seconds from the start of the track
+    pub time: f32,
+    #[allow(missing_docs)]
+    pub value: V,
+    /// Ease used from this keyframe to the next
+    pub ease: EaseKind,
+}
+
+/// [`Interpolator`] that evaluates many keyframes of one property in a
+/// single tween entity instead of a child span per pair of keyframes.
+///
+/// Each pair of adjacent keyframes is interpolated by the interpolator `I`
+/// built with `make`, usually one of the built-in constructors such as
+/// [`translation`](crate::interpolate::translation). Insert it as a tween of
+/// [`KeyframeTrack::duration`] with [`EaseKind::Linear`] so the interpolation
+/// value maps linearly onto the track time.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::interpolate::{keyframe_track, translation, KeyframeTrack};
+/// use bevy_tween::prelude::*;
+///
+/// let track: KeyframeTrack<interpolate::Translation, Vec3> = keyframe_track(
+///     translation,
+///     [
+///         (0., Vec3::ZERO, EaseKind::QuadraticOut),
+///         (0.5, Vec3::Y * 100., EaseKind::BounceOut),
+///         (1.5, Vec3::ZERO, EaseKind::Linear),
+///     ],
+/// );
+/// let duration = track.duration();
+///
+/// let mut app = App::new();
+/// app.add_tween_systems(bevy_tween::component_tween_system::<
+///     KeyframeTrack<interpolate::Translation, Vec3>,
+/// >());
+/// ```
+pub struct KeyframeTrack<I, V> {
+    /// Keyframes sorted by time
+    pub keyframes: Vec<Keyframe<V>>,
+    /// Create the interpolator between two keyframe values
+    pub make: fn(V, V) -> I,
+}
+
+impl<I, V> KeyframeTrack<I, V> {
+    /// Time of the last keyframe as a [`Duration`], to use as the tween
+    /// duration.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(
+            self.keyframes.last().map(|k| k.time.max(0.)).unwrap_or(0.),
+        )
+    }
+}
+
+impl<I, V> Clone for KeyframeTrack<I, V>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        KeyframeTrack {
+            keyframes: self.keyframes.clone(),
+            make: self.make,
+        }
+    }
+}
+
+impl<I, V> core::fmt::Debug for KeyframeTrack<I, V>
+where
+    V: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyframeTrack")
+            .field("keyframes", &self.keyframes)
+            .finish()
+    }
+}
+
+impl<I, V> Interpolator for KeyframeTrack<I, V>
+where
+    I: Interpolator,
+    V: Clone + Send + Sync + 'static,
+{
+    type Item = I::Item;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let (Some(first), Some(last)) =
+            (self.keyframes.first(), self.keyframes.last())
+        else {
+            return;
+        };
+        let time = value * last.time;
+        let next = self.keyframes.partition_point(|k| k.time <= time);
+        let (start, end, local) = if next == 0 {
+            (first, first, 0.)
+        } else if next == self.keyframes.len() {
+            (last, last, 1.)
+        } else {
+            let start = &self.keyframes[next - 1];
+            let end = &self.keyframes[next];
+            let length = end.time - start.time;
+            let local = if length > 0. {
+                (time - start.time) / length
+            } else {
+                1.
+            };
+            (start, end, start.ease.sample(local))
+        };
+        (self.make)(start.value.clone(), end.value.clone())
+            .interpolate(item, local);
+    }
+}
+
+/// Constructor for [`KeyframeTrack`] from `(time, value, ease)`. Keyframes
+/// are sorted by time.
+pub fn keyframe_track<I, V>(
+    make: fn(V, V) -> I,
+    keyframes: impl IntoIterator<Item = (f32, V, EaseKind)>,
+) -> KeyframeTrack<I, V> {
+    let mut keyframes = keyframes
+        .into_iter()
+        .map(|(time, value, ease)| Keyframe { time, value, ease })
+        .collect::<Vec<_>>();
+    keyframes.sort_by(|a, b| a.time.total_cmp(&b.time));
+    KeyframeTrack { keyframes, make }
+}
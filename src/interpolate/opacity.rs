@@ -0,0 +1,136 @@
+use crate::prelude::Interpolator;
+use crate::tween::{
+    resolve_tween_targets, AnimationTarget, ComponentTween, ResolvedTargets,
+    SkipTween, TweenInterpolationValue, TweenTargetErrors,
+};
+use bevy::prelude::*;
+
+/// [`Interpolator`] for the alpha of a [`Color`], keeping its other
+/// channels.
+///
+/// A [`ComponentTween<Opacity>`] is applied by [`apply_color_tween_system`]
+/// to whichever of the following the target entity has:
+/// - [`Sprite`] if `"bevy_sprite"` feature is enabled.
+/// - [`ColorMaterial`](bevy::sprite::ColorMaterial) through
+///   [`MeshMaterial2d`] if `"bevy_sprite"` and `"bevy_asset"` features are
+///   enabled.
+/// - [`BackgroundColor`](bevy::prelude::BackgroundColor) and [`ImageNode`]
+///   if `"bevy_ui"` feature is enabled.
+/// - [`TextColor`](bevy::prelude::TextColor) if `"bevy_text"` feature is
+///   enabled.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Opacity {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for Opacity {
+    type Item = Color;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.set_alpha(self.start.lerp(self.end, value))
+    }
+}
+
+/// Constructor for [`Opacity`]
+pub fn opacity(start: f32, end: f32) -> Opacity {
+    Opacity { start, end }
+}
+
+/// Constructor for [`Opacity`] that's relative to previous value using currying.
+pub fn opacity_to(to: f32) -> impl Fn(&mut f32) -> Opacity {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        opacity(start, end)
+    }
+}
+
+/// Apply [`ComponentTween<I>`] of any [`Color`] [`Interpolator`] such as
+/// [`Opacity`] to every supported color-carrying component of the target.
+/// See [`Opacity`] for the supported components.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_color_tween_system<I>(
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_tween: Query<
+        (Entity, &ComponentTween<I>, &TweenInterpolationValue),
+        Without<SkipTween>,
+    >,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    #[cfg(feature = "bevy_sprite")] mut q_sprite: Query<&mut Sprite>,
+    #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+    q_color_material: Query<&MeshMaterial2d<bevy::sprite::ColorMaterial>>,
+    #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+    mut color_materials: ResMut<Assets<bevy::sprite::ColorMaterial>>,
+    #[cfg(feature = "bevy_ui")] mut q_background_color: Query<
+        &mut bevy::prelude::BackgroundColor,
+    >,
+    #[cfg(feature = "bevy_ui")] mut q_image_node: Query<&mut ImageNode>,
+    #[cfg(feature = "bevy_text")] mut q_text_color: Query<
+        &mut bevy::prelude::TextColor,
+    >,
+    mut errors: Local<TweenTargetErrors>,
+) where
+    I: Interpolator<Item = Color>,
+{
+    q_tween.iter().for_each(|(entity, tween, ease_value)| {
+        let Some(targets) = resolve_tween_targets::<I>(
+            entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            Some(&mut errors),
+        ) else {
+            return;
+        };
+        let interpolator = &tween.interpolator;
+        let value = ease_value.0;
+        for &target in targets.iter() {
+            #[allow(unused_mut)]
+            let mut applied = false;
+            #[cfg(feature = "bevy_sprite")]
+            if let Ok(mut sprite) = q_sprite.get_mut(target) {
+                interpolator.interpolate(&mut sprite.color, value);
+                applied = true;
+            }
+            #[cfg(all(feature = "bevy_sprite", feature = "bevy_asset"))]
+            if let Ok(material) = q_color_material.get(target) {
+                if let Some(material) = color_materials.get_mut(&material.0) {
+                    interpolator.interpolate(&mut material.color, value);
+                    applied = true;
+                }
+            }
+            #[cfg(feature = "bevy_ui")]
+            if let Ok(mut background_color) =
+                q_background_color.get_mut(target)
+            {
+                interpolator.interpolate(&mut background_color.0, value);
+                applied = true;
+            }
+            #[cfg(feature = "bevy_ui")]
+            if let Ok(mut image_node) = q_image_node.get_mut(target) {
+                interpolator.interpolate(&mut image_node.color, value);
+                applied = true;
+            }
+            #[cfg(feature = "bevy_text")]
+            if let Ok(mut text_color) = q_text_color.get_mut(target) {
+                interpolator.interpolate(&mut text_color.0, value);
+                applied = true;
+            }
+            if !applied {
+                errors.report(target, || {
+                    format!(
+                        "{} attempted to tween entity {target} but it has no supported component",
+                        std::any::type_name::<I>(),
+                    )
+                });
+            }
+        }
+    });
+    errors.end_run();
+}
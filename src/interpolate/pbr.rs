@@ -0,0 +1,495 @@
+use crate::interpolate::{color::impl_in_space, ColorSpace, Interpolator};
+use bevy::prelude::*;
+
+/// [`Interpolator`] for the color of the [`AmbientLight`](bevy::prelude::AmbientLight) resource.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AmbientLightColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+}
+
+impl Interpolator for AmbientLightColor {
+    type Item = AmbientLight;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.color = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+/// Constructor for [`AmbientLightColor`]
+pub fn ambient_light_color(start: Color, end: Color) -> AmbientLightColor {
+    AmbientLightColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
+}
+
+/// Constructor for [`AmbientLightColor`] that's relative to previous value using currying.
+pub fn ambient_light_color_to(
+    to: Color,
+) -> impl Fn(&mut Color) -> AmbientLightColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        ambient_light_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for the brightness of the [`AmbientLight`](bevy::prelude::AmbientLight) resource.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AmbientLightBrightness {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for AmbientLightBrightness {
+    type Item = AmbientLight;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.brightness = self.start.lerp(self.end, value)
+    }
+}
+
+/// Constructor for [`AmbientLightBrightness`]
+pub fn ambient_light_brightness(
+    start: f32,
+    end: f32,
+) -> AmbientLightBrightness {
+    AmbientLightBrightness { start, end }
+}
+
+/// Constructor for [`AmbientLightBrightness`] that's relative to previous value using currying.
+pub fn ambient_light_brightness_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> AmbientLightBrightness {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        ambient_light_brightness(start, end)
+    }
+}
+
+/// [`Interpolator`] for the color of [`DistanceFog`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FogColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+}
+
+impl Interpolator for FogColor {
+    type Item = DistanceFog;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.color = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+/// Constructor for [`FogColor`]
+pub fn fog_color(start: Color, end: Color) -> FogColor {
+    FogColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
+}
+
+/// Constructor for [`FogColor`] that's relative to previous value using currying.
+pub fn fog_color_to(to: Color) -> impl Fn(&mut Color) -> FogColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        fog_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for the start and end distances of
+/// [`FogFalloff::Linear`] in [`DistanceFog`]. `x` is the start distance and
+/// `y` is the end distance.
+///
+/// Other falloffs are replaced by [`FogFalloff::Linear`].
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FogLinearDistance {
+    #[allow(missing_docs)]
+    pub start: Vec2,
+    #[allow(missing_docs)]
+    pub end: Vec2,
+}
+
+impl Interpolator for FogLinearDistance {
+    type Item = DistanceFog;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let distance = self.start.lerp(self.end, value);
+        item.falloff = FogFalloff::Linear {
+            start: distance.x,
+            end: distance.y,
+        };
+    }
+}
+
+/// Constructor for [`FogLinearDistance`]
+pub fn fog_linear_distance(start: Vec2, end: Vec2) -> FogLinearDistance {
+    FogLinearDistance { start, end }
+}
+
+/// Constructor for [`FogLinearDistance`] that's relative to previous value using currying.
+pub fn fog_linear_distance_to(
+    to: Vec2,
+) -> impl Fn(&mut Vec2) -> FogLinearDistance {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        fog_linear_distance(start, end)
+    }
+}
+
+/// [`Interpolator`] for the density of [`FogFalloff::Exponential`] and
+/// [`FogFalloff::ExponentialSquared`] in [`DistanceFog`].
+///
+/// Does nothing to other falloffs.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FogDensity {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for FogDensity {
+    type Item = DistanceFog;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        match &mut item.falloff {
+            FogFalloff::Exponential { density }
+            | FogFalloff::ExponentialSquared { density } => {
+                *density = self.start.lerp(self.end, value)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Constructor for [`FogDensity`]
+pub fn fog_density(start: f32, end: f32) -> FogDensity {
+    FogDensity { start, end }
+}
+
+/// Constructor for [`FogDensity`] that's relative to previous value using currying.
+pub fn fog_density_to(to: f32) -> impl Fn(&mut f32) -> FogDensity {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        fog_density(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`StandardMaterial::base_color`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StandardMaterialBaseColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+}
+
+impl Interpolator for StandardMaterialBaseColor {
+    type Item = StandardMaterial;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.base_color = self.space.mix(&self.start, &self.end, value);
+    }
+}
+
+/// Constructor for [`StandardMaterialBaseColor`]
+pub fn standard_material_base_color(
+    start: Color,
+    end: Color,
+) -> StandardMaterialBaseColor {
+    StandardMaterialBaseColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
+}
+
+/// Constructor for [`StandardMaterialBaseColor`] that's relative to previous value using currying.
+pub fn standard_material_base_color_to(
+    to: Color,
+) -> impl Fn(&mut Color) -> StandardMaterialBaseColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        standard_material_base_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for the alpha of [`StandardMaterial::base_color`],
+/// keeping its other channels.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StandardMaterialAlpha {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for StandardMaterialAlpha {
+    type Item = StandardMaterial;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.base_color.set_alpha(self.start.lerp(self.end, value));
+    }
+}
+
+/// Constructor for [`StandardMaterialAlpha`]
+pub fn standard_material_alpha(start: f32, end: f32) -> StandardMaterialAlpha {
+    StandardMaterialAlpha { start, end }
+}
+
+/// Constructor for [`StandardMaterialAlpha`] that's relative to previous value using currying.
+pub fn standard_material_alpha_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> StandardMaterialAlpha {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        standard_material_alpha(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`StandardMaterial::emissive`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StandardMaterialEmissive {
+    #[allow(missing_docs)]
+    pub start: LinearRgba,
+    #[allow(missing_docs)]
+    pub end: LinearRgba,
+}
+
+impl Interpolator for StandardMaterialEmissive {
+    type Item = StandardMaterial;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.emissive = self.start.mix(&self.end, value);
+    }
+}
+
+/// Constructor for [`StandardMaterialEmissive`]
+pub fn standard_material_emissive(
+    start: LinearRgba,
+    end: LinearRgba,
+) -> StandardMaterialEmissive {
+    StandardMaterialEmissive { start, end }
+}
+
+/// Constructor for [`StandardMaterialEmissive`] that's relative to previous value using currying.
+pub fn standard_material_emissive_to(
+    to: LinearRgba,
+) -> impl Fn(&mut LinearRgba) -> StandardMaterialEmissive {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        standard_material_emissive(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`StandardMaterial::perceptual_roughness`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StandardMaterialRoughness {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for StandardMaterialRoughness {
+    type Item = StandardMaterial;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.perceptual_roughness = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`StandardMaterialRoughness`]
+pub fn standard_material_roughness(
+    start: f32,
+    end: f32,
+) -> StandardMaterialRoughness {
+    StandardMaterialRoughness { start, end }
+}
+
+/// Constructor for [`StandardMaterialRoughness`] that's relative to previous value using currying.
+pub fn standard_material_roughness_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> StandardMaterialRoughness {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        standard_material_roughness(start, end)
+    }
+}
+
+/// [`Interpolator`] for [`StandardMaterial::metallic`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StandardMaterialMetallic {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for StandardMaterialMetallic {
+    type Item = StandardMaterial;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.metallic = self.start.lerp(self.end, value);
+    }
+}
+
+/// Constructor for [`StandardMaterialMetallic`]
+pub fn standard_material_metallic(
+    start: f32,
+    end: f32,
+) -> StandardMaterialMetallic {
+    StandardMaterialMetallic { start, end }
+}
+
+/// Constructor for [`StandardMaterialMetallic`] that's relative to previous value using currying.
+pub fn standard_material_metallic_to(
+    to: f32,
+) -> impl Fn(&mut f32) -> StandardMaterialMetallic {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        standard_material_metallic(start, end)
+    }
+}
+
+/// [`Interpolator`] for a single weight of [`MorphWeights`] at `index`.
+///
+/// Does nothing if `index` is out of bounds.
+///
+/// [`MorphWeights`]: bevy::render::mesh::morph::MorphWeights
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MorphWeight {
+    /// Index of the morph target weight
+    pub index: usize,
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for MorphWeight {
+    type Item = bevy::render::mesh::morph::MorphWeights;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Some(weight) = item.weights_mut().get_mut(self.index) {
+            *weight = self.start.lerp(self.end, value);
+        }
+    }
+}
+
+/// Constructor for [`MorphWeight`]
+pub fn morph_weight(index: usize, start: f32, end: f32) -> MorphWeight {
+    MorphWeight { index, start, end }
+}
+
+/// Constructor for [`MorphWeight`] that's relative to previous value using currying.
+pub fn morph_weight_to(
+    index: usize,
+    to: f32,
+) -> impl Fn(&mut f32) -> MorphWeight {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        morph_weight(index, start, end)
+    }
+}
+
+/// [`Interpolator`] for all weights of [`MorphWeights`].
+///
+/// Weights are interpolated pairwise. Only the weights that exist in `start`,
+/// `end`, and the component are changed.
+///
+/// [`MorphWeights`]: bevy::render::mesh::morph::MorphWeights
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MorphWeightsAll {
+    #[allow(missing_docs)]
+    pub start: Vec<f32>,
+    #[allow(missing_docs)]
+    pub end: Vec<f32>,
+}
+
+impl Interpolator for MorphWeightsAll {
+    type Item = bevy::render::mesh::morph::MorphWeights;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.weights_mut()
+            .iter_mut()
+            .zip(self.start.iter().zip(&self.end))
+            .for_each(|(weight, (start, end))| {
+                *weight = start.lerp(*end, value);
+            });
+    }
+}
+
+/// Constructor for [`MorphWeightsAll`]
+pub fn morph_weights_all(
+    start: impl Into<Vec<f32>>,
+    end: impl Into<Vec<f32>>,
+) -> MorphWeightsAll {
+    MorphWeightsAll {
+        start: start.into(),
+        end: end.into(),
+    }
+}
+
+/// Constructor for [`MorphWeightsAll`] that's relative to previous value using currying.
+pub fn morph_weights_all_to(
+    to: impl Into<Vec<f32>>,
+) -> impl Fn(&mut Vec<f32>) -> MorphWeightsAll {
+    let to = to.into();
+    move |state| {
+        let start = std::mem::replace(state, to.clone());
+        morph_weights_all(start, to.clone())
+    }
+}
+
+impl_in_space!(AmbientLightColor, FogColor, StandardMaterialBaseColor);
@@ -0,0 +1,243 @@
+use crate::{
+    tween::{
+        resolve_tween_targets, AnimationTarget, ComponentTween,
+        RegisterComponentTween, ResolvedTargets, SkipTween, TargetComponent,
+        TweenInterpolationValue,
+    },
+    BevyTweenRegisterSystems,
+};
+use bevy::{
+    ecs::system::SystemState,
+    prelude::*,
+    reflect::{GetPath, PartialReflect},
+    utils::HashSet,
+};
+use std::any::TypeId;
+
+/// Plugin for [`ReflectTween`](crate::tween::ReflectTween).
+///
+/// Not in [`DefaultTweenPlugins`](crate::DefaultTweenPlugins) because
+/// [`apply_reflect_tween_system`] needs exclusive access to the world, which
+/// stops other systems from running in parallel with it.
+pub struct ReflectTweenPlugin;
+
+impl Plugin for ReflectTweenPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        app.add_tween_systems(
+            apply_reflect_tween_system
+                .run_if(any_with_component::<ComponentTween<ReflectField>>),
+        )
+        .register_component_tween::<ReflectField>();
+    }
+}
+
+/// Value interpolated by [`ReflectField`]
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[allow(missing_docs)]
+pub enum ReflectValue {
+    F32(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Vec4(Vec4),
+}
+
+impl ReflectValue {
+    /// Linearly interpolate to `end`. Returns [`None`] if both values are
+    /// not the same variant.
+    pub fn lerp(&self, end: &ReflectValue, value: f32) -> Option<ReflectValue> {
+        Some(match (self, end) {
+            (ReflectValue::F32(a), ReflectValue::F32(b)) => {
+                ReflectValue::F32(a.lerp(*b, value))
+            }
+            (ReflectValue::Vec2(a), ReflectValue::Vec2(b)) => {
+                ReflectValue::Vec2(a.lerp(*b, value))
+            }
+            (ReflectValue::Vec3(a), ReflectValue::Vec3(b)) => {
+                ReflectValue::Vec3(a.lerp(*b, value))
+            }
+            (ReflectValue::Vec4(a), ReflectValue::Vec4(b)) => {
+                ReflectValue::Vec4(a.lerp(*b, value))
+            }
+            _ => return None,
+        })
+    }
+
+    fn as_partial_reflect(&self) -> &dyn PartialReflect {
+        match self {
+            ReflectValue::F32(v) => v,
+            ReflectValue::Vec2(v) => v,
+            ReflectValue::Vec3(v) => v,
+            ReflectValue::Vec4(v) => v,
+        }
+    }
+}
+
+impl From<f32> for ReflectValue {
+    fn from(value: f32) -> Self {
+        ReflectValue::F32(value)
+    }
+}
+
+impl From<Vec2> for ReflectValue {
+    fn from(value: Vec2) -> Self {
+        ReflectValue::Vec2(value)
+    }
+}
+
+impl From<Vec3> for ReflectValue {
+    fn from(value: Vec3) -> Self {
+        ReflectValue::Vec3(value)
+    }
+}
+
+impl From<Vec4> for ReflectValue {
+    fn from(value: Vec4) -> Self {
+        ReflectValue::Vec4(value)
+    }
+}
+
+/// Interpolate a field of any reflected component by its [`TypeId`] and a
+/// reflect path such as `"translation.x"`, without knowing the component
+/// type at compile time.
+///
+/// The component must be registered with `#[reflect(Component)]`. Applied by
+/// [`apply_reflect_tween_system`] as a [`ReflectTween`], which is added by
+/// [`ReflectTweenPlugin`].
+///
+/// [`ReflectTween`]: crate::tween::ReflectTween
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct ReflectField {
+    /// [`TypeId`] of the component
+    pub component: TypeId,
+    /// Reflect path to the field in the component
+    pub path: String,
+    #[allow(missing_docs)]
+    pub start: ReflectValue,
+    #[allow(missing_docs)]
+    pub end: ReflectValue,
+}
+
+/// Constructor for [`ReflectField`] of the component `C`
+pub fn reflect_field<C: Component>(
+    path: impl Into<String>,
+    start: impl Into<ReflectValue>,
+    end: impl Into<ReflectValue>,
+) -> ReflectField {
+    ReflectField {
+        component: TypeId::of::<C>(),
+        path: path.into(),
+        start: start.into(),
+        end: end.into(),
+    }
+}
+
+type ReflectTweenQueries<'w, 's> = (
+    Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static ComponentTween<ReflectField>,
+            &'static TweenInterpolationValue,
+        ),
+        Without<SkipTween>,
+    >,
+    Query<'w, 's, (Option<&'static Parent>, Has<AnimationTarget>)>,
+    Query<'w, 's, &'static ResolvedTargets>,
+);
+
+/// Apply any [`ReflectTween`](crate::tween::ReflectTween) through the
+/// [`AppTypeRegistry`].
+pub fn apply_reflect_tween_system(
+    world: &mut World,
+    state: &mut SystemState<ReflectTweenQueries>,
+    mut last_error: Local<HashSet<Entity>>,
+) {
+    let mut error = HashSet::new();
+    let tweens = {
+        let (q_tween, q_animation_target, q_resolved_targets) =
+            state.get(world);
+        q_tween
+            .iter()
+            .map(|(entity, tween, ease_value)| {
+                let targets = resolve_tween_targets::<ReflectField>(
+                    entity,
+                    &tween.target,
+                    &q_animation_target,
+                    &q_resolved_targets,
+                    None,
+                )
+                .map(|targets| targets.to_vec())
+                .unwrap_or_default();
+                let is_marker = tween.target == TargetComponent::Marker;
+                (
+                    entity,
+                    is_marker,
+                    targets,
+                    tween.interpolator.clone(),
+                    ease_value.0,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for (entity, is_marker, targets, field, value) in tweens {
+        let mut report = |message: String| {
+            if !last_error.contains(&entity) {
+                error!("Tween {entity} {message}");
+            }
+            error.insert(entity);
+        };
+        let Some(interpolated) = field.start.lerp(&field.end, value) else {
+            report(format!(
+                "has mismatched reflect values {:?} and {:?}",
+                field.start, field.end
+            ));
+            continue;
+        };
+        let Some(reflect_component) =
+            registry.get_type_data::<ReflectComponent>(field.component)
+        else {
+            report(format!(
+                "targets {:?} which is not a registered reflect component",
+                field.component
+            ));
+            continue;
+        };
+        if is_marker && targets.is_empty() {
+            report("cannot find AnimationTarget marker".to_string());
+            continue;
+        }
+        for target in targets {
+            let Ok(target_mut) = world.get_entity_mut(target) else {
+                report(format!("attempted to tween missing entity {target}"));
+                continue;
+            };
+            let Some(mut component) = reflect_component.reflect_mut(target_mut)
+            else {
+                report(format!(
+                    "attempted to tween entity {target} without the component"
+                ));
+                continue;
+            };
+            let result = component
+                .reflect_path_mut(field.path.as_str())
+                .map_err(|e| e.to_string())
+                .and_then(|item| {
+                    item.try_apply(interpolated.as_partial_reflect())
+                        .map_err(|e| e.to_string())
+                });
+            if let Err(e) = result {
+                report(format!("cannot set {:?}: {e}", field.path));
+            }
+        }
+    }
+    *last_error = error;
+}
@@ -0,0 +1,147 @@
+use crate::tween::{
+    resolve_tween_targets, AnimationTarget, ComponentTween, ResolvedTargets,
+    SkipTween, TweenInterpolationValue, TweenTargetErrors,
+};
+use bevy::{prelude::*, utils::HashMap};
+
+/// Procedural translation and rotation noise applied on top of the target's
+/// [`Transform`] so it stacks with other tweens.
+///
+/// The noise has `frequency` oscillations over the whole tween and its
+/// amplitude fades by `(1 - value).powf(decay)` so the target settles back at
+/// the end. A [`ComponentTween<Shake>`] is applied by
+/// [`apply_shake_tween_system`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Shake {
+    /// Maximum translation offset per axis
+    pub translation: Vec3,
+    /// Maximum rotation offset per axis in radians
+    pub rotation: Vec3,
+    /// Number of oscillations over the whole tween
+    pub frequency: f32,
+    /// Exponent of the fade out envelope. `0.` never fades.
+    pub decay: f32,
+    /// Seed of the noise
+    pub seed: u32,
+}
+
+impl Default for Shake {
+    fn default() -> Self {
+        Shake {
+            translation: Vec3::ZERO,
+            rotation: Vec3::ZERO,
+            frequency: 20.,
+            decay: 1.,
+            seed: 0,
+        }
+    }
+}
+
+impl Shake {
+    /// Camera shake preset where `trauma` between 0–1 scales translation on
+    /// the X and Y axes by `max_offset` and rolls around the Z axis. Fades
+    /// quadratically like trauma-based shake.
+    pub fn camera(trauma: f32, max_offset: f32) -> Shake {
+        Shake {
+            translation: Vec3::new(max_offset, max_offset, 0.) * trauma,
+            rotation: Vec3::new(0., 0., 0.1) * trauma,
+            frequency: 30.,
+            decay: 2.,
+            seed: 0,
+        }
+    }
+
+    /// Use this seed for the noise
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Translation and rotation offset at `value`
+    pub fn offset(&self, value: f32) -> (Vec3, Quat) {
+        let envelope = (1. - value).clamp(0., 1.).powf(self.decay);
+        let x = value * self.frequency;
+        let sample = |axis: u32| noise(self.seed.wrapping_add(axis), x);
+        let translation = self.translation
+            * Vec3::new(sample(0), sample(1), sample(2))
+            * envelope;
+        let rotation = self.rotation
+            * Vec3::new(sample(3), sample(4), sample(5))
+            * envelope;
+        (
+            translation,
+            Quat::from_euler(EulerRot::XYZ, rotation.x, rotation.y, rotation.z),
+        )
+    }
+}
+
+/// Constructor for [`Shake`]
+pub fn shake(translation: Vec3, rotation: Vec3) -> Shake {
+    Shake {
+        translation,
+        rotation,
+        ..Default::default()
+    }
+}
+
+/// Smooth value noise between -1 and 1
+fn noise(seed: u32, x: f32) -> f32 {
+    fn hash(seed: u32, i: i32) -> f32 {
+        let mut h = (i as u32)
+            .wrapping_mul(0x9E37_79B1)
+            .wrapping_add(seed.wrapping_mul(0x85EB_CA6B));
+        h ^= h >> 15;
+        h = h.wrapping_mul(0x2C1B_3C6D);
+        h ^= h >> 12;
+        h as f32 / u32::MAX as f32 * 2. - 1.
+    }
+    let i = x.floor();
+    let t = x - i;
+    let t = t * t * (3. - 2. * t);
+    hash(seed, i as i32).lerp(hash(seed, i as i32 + 1), t)
+}
+
+/// Apply [`ComponentTween<Shake>`] by adding the change of its offset since
+/// the last time it was applied to the target's [`Transform`].
+#[allow(clippy::type_complexity)]
+pub fn apply_shake_tween_system(
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_tween: Query<
+        (Entity, &ComponentTween<Shake>, &TweenInterpolationValue),
+        Without<SkipTween>,
+    >,
+    q_tween_exists: Query<(), With<ComponentTween<Shake>>>,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    mut q_transform: Query<&mut Transform>,
+    mut applied_offsets: Local<HashMap<Entity, (Vec3, Quat)>>,
+    mut errors: Local<TweenTargetErrors>,
+) {
+    applied_offsets.retain(|tween, _| q_tween_exists.contains(*tween));
+    q_tween.iter().for_each(|(entity, tween, ease_value)| {
+        let (translation, rotation) = tween.interpolator.offset(ease_value.0);
+        let (last_translation, last_rotation) = applied_offsets
+            .insert(entity, (translation, rotation))
+            .unwrap_or((Vec3::ZERO, Quat::IDENTITY));
+        let Some(targets) = resolve_tween_targets::<Shake>(
+            entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            Some(&mut errors),
+        ) else {
+            return;
+        };
+        for &target in targets.iter() {
+            match q_transform.get_mut(target) {
+                Ok(mut transform) => {
+                    transform.translation += translation - last_translation;
+                    transform.rotation =
+                        transform.rotation * last_rotation.inverse() * rotation;
+                }
+                Err(e) => errors.query_error::<Shake, Transform>(target, e),
+            }
+        }
+    });
+    errors.end_run();
+}
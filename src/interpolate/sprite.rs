@@ -1,29 +1,44 @@
-use crate::interpolate::Interpolator;
+use crate::interpolate::{
+    color::impl_in_space, CaptureStart, ColorSpace, Interpolator,
+};
 use bevy::prelude::*;
 
 // type ReflectInterpolatorSprite = ReflectInterpolator<Sprite>;
 
 /// [`Interpolator`] for [`Sprite`]'s color
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 // #[reflect(InterpolatorSprite)]
 pub struct SpriteColor {
     #[allow(missing_docs)]
     pub start: Color,
     #[allow(missing_docs)]
     pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
 }
 
 impl Interpolator for SpriteColor {
     type Item = Sprite;
 
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
-        item.color = self.start.mix(&self.end, value)
+        item.color = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+impl CaptureStart for SpriteColor {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.color;
     }
 }
 
 /// Constructor for [`SpriteColor`]
 pub fn sprite_color(start: Color, end: Color) -> SpriteColor {
-    SpriteColor { start, end }
+    SpriteColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
 }
 
 /// Constructor for [`SpriteColor`] that's relative to previous value using currying.
@@ -41,25 +56,32 @@ pub fn sprite_color_to(to: Color) -> impl Fn(&mut Color) -> SpriteColor {
 
 /// [`Interpolator`] for [`Sprite`]'s [`ColorMaterial`]
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 // #[reflect(InterpolatorColorMaterial)]
 pub struct ColorMaterial {
     #[allow(missing_docs)]
     pub start: Color,
     #[allow(missing_docs)]
     pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
 }
 
 impl Interpolator for ColorMaterial {
     type Item = bevy::sprite::ColorMaterial;
 
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
-        item.color = self.start.mix(&self.end, value);
+        item.color = self.space.mix(&self.start, &self.end, value);
     }
 }
 
 /// Constructor for [`ColorMaterial`](crate::interpolate::ColorMaterial)
 pub fn color_material(start: Color, end: Color) -> ColorMaterial {
-    ColorMaterial { start, end }
+    ColorMaterial {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
 }
 
 /// Constructor for [`ColorMaterial`](crate::interpolate::ColorMaterial) that's relative to previous value using currying.
@@ -71,3 +93,88 @@ pub fn color_material_to(to: Color) -> impl Fn(&mut Color) -> ColorMaterial {
         color_material(start, end)
     }
 }
+
+/// [`Interpolator`] for the [`TextureAtlas`] index of a [`Sprite`], stepping
+/// through every frame from `start` to `end` (inclusive) for flipbook
+/// animation.
+///
+/// Each frame is shown for an equal share of the tween unless `holds` is not
+/// empty, in which case it's used as the relative duration of each frame in
+/// order. Missing entries default to `1.0`.
+///
+/// Does nothing if the sprite has no [`TextureAtlas`].
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SpriteAtlasIndex {
+    #[allow(missing_docs)]
+    pub start: usize,
+    #[allow(missing_docs)]
+    pub end: usize,
+    /// Relative duration of each frame
+    pub holds: Vec<f32>,
+}
+
+impl SpriteAtlasIndex {
+    /// Hold each frame for the given relative duration
+    pub fn with_holds(mut self, holds: impl Into<Vec<f32>>) -> Self {
+        self.holds = holds.into();
+        self
+    }
+
+    fn frame_at(&self, value: f32) -> usize {
+        let frame_count = self.start.abs_diff(self.end) + 1;
+        let value = value.clamp(0., 1.);
+        let offset = if self.holds.is_empty() {
+            ((value * frame_count as f32) as usize).min(frame_count - 1)
+        } else {
+            let hold =
+                |i: usize| self.holds.get(i).copied().unwrap_or(1.).max(0.);
+            let total: f32 = (0..frame_count).map(hold).sum();
+            let mut remaining = value * total;
+            (0..frame_count)
+                .find(|&i| {
+                    remaining -= hold(i);
+                    remaining < 0.
+                })
+                .unwrap_or(frame_count - 1)
+        };
+        if self.start <= self.end {
+            self.start + offset
+        } else {
+            self.start - offset
+        }
+    }
+}
+
+impl Interpolator for SpriteAtlasIndex {
+    type Item = Sprite;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Some(atlas) = &mut item.texture_atlas {
+            atlas.index = self.frame_at(value);
+        }
+    }
+}
+
+/// Constructor for [`SpriteAtlasIndex`]
+pub fn sprite_atlas_index(start: usize, end: usize) -> SpriteAtlasIndex {
+    SpriteAtlasIndex {
+        start,
+        end,
+        holds: Vec::new(),
+    }
+}
+
+/// Constructor for [`SpriteAtlasIndex`] that's relative to previous value using currying.
+pub fn sprite_atlas_index_to(
+    to: usize,
+) -> impl Fn(&mut usize) -> SpriteAtlasIndex {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        sprite_atlas_index(start, end)
+    }
+}
+
+impl_in_space!(SpriteColor, ColorMaterial);
@@ -0,0 +1,121 @@
+use crate::interpolate::Interpolator;
+
+/// [`Interpolator`] that switches between discrete values without blending,
+/// such as integer counters, frame indices, or enum states.
+///
+/// `steps` is a list of `(threshold, value)` sorted by threshold. The value
+/// of the last step whose threshold is lower than or equal to the
+/// interpolation value is set with `set`, or the first value if none.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::interpolate::{step_range, steps, Steps};
+///
+/// #[derive(Component)]
+/// struct Ammo(i32);
+///
+/// #[derive(Component, Clone, Copy, PartialEq)]
+/// enum Light {
+///     Red,
+///     Yellow,
+///     Green,
+/// }
+///
+/// fn set_light(light: &mut Light, value: &Light) {
+///     *light = *value;
+/// }
+///
+/// let light: Steps<Light, Light> = steps(
+///     set_light,
+///     [(0., Light::Red), (0.45, Light::Yellow), (0.5, Light::Green)],
+/// );
+/// let ammo: Steps<Ammo, i32> =
+///     step_range(|ammo: &mut Ammo, value: &i32| ammo.0 = *value, 30, 0);
+///
+/// let mut app = App::new();
+/// app.add_tween_systems((
+///     bevy_tween::component_tween_system::<Steps<Light, Light>>(),
+///     bevy_tween::component_tween_system::<Steps<Ammo, i32>>(),
+/// ));
+/// ```
+pub struct Steps<C, V> {
+    /// `(threshold, value)` sorted by threshold
+    pub steps: Vec<(f32, V)>,
+    /// Set the value to the item
+    pub set: fn(&mut C, &V),
+}
+
+impl<C, V> Steps<C, V> {
+    /// Value of the step at `value`
+    pub fn sample(&self, value: f32) -> Option<&V> {
+        let next = self.steps.partition_point(|(t, _)| *t <= value);
+        self.steps
+            .get(next.saturating_sub(1))
+            .map(|(_, value)| value)
+    }
+}
+
+impl<C, V> Clone for Steps<C, V>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Steps {
+            steps: self.steps.clone(),
+            set: self.set,
+        }
+    }
+}
+
+impl<C, V> core::fmt::Debug for Steps<C, V>
+where
+    V: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Steps").field("steps", &self.steps).finish()
+    }
+}
+
+impl<C, V> Interpolator for Steps<C, V>
+where
+    C: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    type Item = C;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        if let Some(value) = self.sample(value) {
+            (self.set)(item, value);
+        }
+    }
+}
+
+/// Constructor for [`Steps`]. Steps are sorted by threshold.
+pub fn steps<C, V>(
+    set: fn(&mut C, &V),
+    steps: impl IntoIterator<Item = (f32, V)>,
+) -> Steps<C, V> {
+    let mut steps = steps.into_iter().collect::<Vec<_>>();
+    steps.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    Steps { steps, set }
+}
+
+/// Constructor for [`Steps`] counting every integer from `start` to `end`
+/// (inclusive), each shown for an equal share of the tween.
+pub fn step_range<C>(
+    set: fn(&mut C, &i32),
+    start: i32,
+    end: i32,
+) -> Steps<C, i32> {
+    let count = start.abs_diff(end) + 1;
+    let direction = if start <= end { 1 } else { -1 };
+    Steps {
+        steps: (0..count)
+            .map(|i| (i as f32 / count as f32, start + direction * i as i32))
+            .collect(),
+        set,
+    }
+}
@@ -0,0 +1,89 @@
+use crate::interpolate::{
+    color::impl_in_space, CaptureStart, ColorSpace, Interpolator,
+};
+use bevy::prelude::*;
+
+/// [`Interpolator`] for Bevy's [`TextColor`](bevy::prelude::TextColor) used in
+/// texts and text spans.
+///
+/// Every text span is its own entity so target the span entity to tween a
+/// single section.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextColor {
+    #[allow(missing_docs)]
+    pub start: Color,
+    #[allow(missing_docs)]
+    pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
+}
+
+impl Interpolator for TextColor {
+    type Item = bevy::prelude::TextColor;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.0 = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+impl CaptureStart for TextColor {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.0;
+    }
+}
+
+/// Constructor for [`TextColor`](crate::interpolate::TextColor)
+pub fn text_color(start: Color, end: Color) -> TextColor {
+    TextColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
+}
+
+/// Constructor for [`TextColor`](crate::interpolate::TextColor) that's relative to previous value using currying.
+pub fn text_color_to(to: Color) -> impl Fn(&mut Color) -> TextColor {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        text_color(start, end)
+    }
+}
+
+/// [`Interpolator`] for the alpha of Bevy's
+/// [`TextColor`](bevy::prelude::TextColor), keeping its other channels.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextAlpha {
+    #[allow(missing_docs)]
+    pub start: f32,
+    #[allow(missing_docs)]
+    pub end: f32,
+}
+
+impl Interpolator for TextAlpha {
+    type Item = bevy::prelude::TextColor;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.0.set_alpha(self.start.lerp(self.end, value))
+    }
+}
+
+/// Constructor for [`TextAlpha`]
+pub fn text_alpha(start: f32, end: f32) -> TextAlpha {
+    TextAlpha { start, end }
+}
+
+/// Constructor for [`TextAlpha`] that's relative to previous value using currying.
+pub fn text_alpha_to(to: f32) -> impl Fn(&mut f32) -> TextAlpha {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        text_alpha(start, end)
+    }
+}
+
+impl_in_space!(TextColor);
@@ -1,10 +1,20 @@
 // type ReflectInterpolatorTransform = ReflectInterpolator<Transform>;
 
-use crate::interpolate::Interpolator;
-use bevy::prelude::*;
+use crate::interpolate::{CaptureStart, FromCurrent, Interpolator};
+use crate::interpolation::EaseKind;
+use crate::tween::{
+    resolve_tween_targets, AnimationTarget, ComponentTween, ResolvedTargets,
+    TweenInterpolationValue,
+};
+use bevy::{
+    ecs::{entity::MapEntities, reflect::ReflectMapEntities},
+    prelude::*,
+};
+use std::f32::consts::{PI, TAU};
 
 /// [`Interpolator`] for [`Transform`]'s translation.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 // #[reflect(InterpolatorTransform)]
 pub struct Translation {
     #[allow(missing_docs)]
@@ -18,6 +28,16 @@ impl Interpolator for Translation {
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
         item.translation = self.start.lerp(self.end, value);
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["translation"]
+    }
+}
+
+impl CaptureStart for Translation {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.translation;
+    }
 }
 
 /// Constructor for [`Translation`]
@@ -45,8 +65,77 @@ pub fn translation_by(by: Vec3) -> impl Fn(&mut Vec3) -> Translation {
     }
 }
 
+/// [`Interpolator`] for [`Transform`]'s translation with a separate ease per
+/// axis, such as an arcing jump that moves linearly on X but bounces on Y.
+///
+/// Each axis eases the span's interpolation value again, so the tween itself
+/// should usually use [`EaseKind::Linear`].
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TranslationPerAxis {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    /// Ease of the X, Y, and Z axis
+    pub ease: [EaseKind; 3],
+}
+
+impl Default for TranslationPerAxis {
+    fn default() -> Self {
+        translation_per_axis(Vec3::ZERO, Vec3::ZERO, [EaseKind::Linear; 3])
+    }
+}
+
+impl Interpolator for TranslationPerAxis {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let [x, y, z] = self.ease;
+        let value =
+            Vec3::new(x.sample(value), y.sample(value), z.sample(value));
+        item.translation = self.start + (self.end - self.start) * value;
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["translation"]
+    }
+}
+
+impl CaptureStart for TranslationPerAxis {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.translation;
+    }
+}
+
+/// Constructor for [`TranslationPerAxis`]
+pub fn translation_per_axis(
+    start: Vec3,
+    end: Vec3,
+    ease: [EaseKind; 3],
+) -> TranslationPerAxis {
+    TranslationPerAxis { start, end, ease }
+}
+
+/// Constructor for [`TranslationPerAxis`] that's relative to previous value using currying.
+pub fn translation_per_axis_to(
+    to: Vec3,
+    ease: [EaseKind; 3],
+) -> impl Fn(&mut Vec3) -> TranslationPerAxis {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        translation_per_axis(start, end, ease)
+    }
+}
+
 /// [`Interpolator`] for [`Transform`]'s rotation using the [`Quat::slerp`] function.
+///
+/// Always takes the shortest arc between `start` and `end`. Use
+/// [`RotationEuler`] or [`AngleZ`] to rotate more than half a turn.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 // #[reflect(InterpolatorTransform)]
 pub struct Rotation {
     #[allow(missing_docs)]
@@ -60,6 +149,16 @@ impl Interpolator for Rotation {
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
         item.rotation = self.start.slerp(self.end, value);
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["rotation"]
+    }
+}
+
+impl CaptureStart for Rotation {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.rotation;
+    }
 }
 
 /// Constructor for [`Rotation`]
@@ -87,8 +186,115 @@ pub fn rotation_by(by: Quat) -> impl Fn(&mut Quat) -> Rotation {
     }
 }
 
+/// [`Interpolator`] for [`Transform`]'s rotation by linearly interpolating
+/// euler angles in radians with the given [`EulerRot`] order.
+///
+/// Angles are not wrapped so multi-turn rotations such as `0` to `4π` spin
+/// twice. Use [`RotationEuler::shortest_path`] to instead turn each angle the
+/// shortest way around.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+// #[reflect(InterpolatorTransform)]
+pub struct RotationEuler {
+    #[allow(missing_docs)]
+    pub start: Vec3,
+    #[allow(missing_docs)]
+    pub end: Vec3,
+    /// Order of the euler angles
+    pub order: EulerRot,
+    /// Turn each angle the shortest way around
+    pub shortest_path: bool,
+}
+
+impl RotationEuler {
+    /// Turn each angle the shortest way around instead of the full
+    /// difference between `start` and `end`.
+    pub fn shortest_path(mut self) -> Self {
+        self.shortest_path = true;
+        self
+    }
+}
+
+impl Default for RotationEuler {
+    fn default() -> Self {
+        RotationEuler {
+            start: Vec3::ZERO,
+            end: Vec3::ZERO,
+            order: EulerRot::XYZ,
+            shortest_path: false,
+        }
+    }
+}
+
+impl Interpolator for RotationEuler {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let mut delta = self.end - self.start;
+        if self.shortest_path {
+            delta = (delta + Vec3::splat(PI)).rem_euclid(Vec3::splat(TAU))
+                - Vec3::splat(PI);
+        }
+        let angles = delta.mul_add(Vec3::splat(value), self.start);
+        item.rotation =
+            Quat::from_euler(self.order, angles.x, angles.y, angles.z);
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["rotation"]
+    }
+}
+
+impl CaptureStart for RotationEuler {
+    fn capture_start(&mut self, item: &Self::Item) {
+        let (a, b, c) = item.rotation.to_euler(self.order);
+        self.start = Vec3::new(a, b, c);
+    }
+}
+
+/// Constructor for [`RotationEuler`]
+pub fn rotation_euler(
+    order: EulerRot,
+    start: Vec3,
+    end: Vec3,
+) -> RotationEuler {
+    RotationEuler {
+        start,
+        end,
+        order,
+        shortest_path: false,
+    }
+}
+
+/// Constructor for [`RotationEuler`] that's relative to previous value using currying.
+pub fn rotation_euler_to(
+    order: EulerRot,
+    to: Vec3,
+) -> impl Fn(&mut Vec3) -> RotationEuler {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        rotation_euler(order, start, end)
+    }
+}
+
+/// Constructor for [`RotationEuler`] that's relative to previous value using currying.
+pub fn rotation_euler_by(
+    order: EulerRot,
+    by: Vec3,
+) -> impl Fn(&mut Vec3) -> RotationEuler {
+    move |state| {
+        let start = *state;
+        let end = *state + by;
+        *state += by;
+        rotation_euler(order, start, end)
+    }
+}
+
 /// [`Interpolator`] for [`Transform`]'s scale
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 // #[reflect(InterpolatorTransform)]
 pub struct Scale {
     #[allow(missing_docs)]
@@ -102,6 +308,16 @@ impl Interpolator for Scale {
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
         item.scale = self.start.lerp(self.end, value);
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["scale"]
+    }
+}
+
+impl CaptureStart for Scale {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.scale;
+    }
 }
 
 /// Constructor for [`Scale`]
@@ -132,6 +348,7 @@ pub fn scale_by(by: Vec3) -> impl Fn(&mut Vec3) -> Scale {
 /// [`Interpolator`] for [`Transform`]'s rotation at Z axis.
 /// Usually used for 2D rotation.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 // #[reflect(InterpolatorTransform)]
 pub struct AngleZ {
     #[allow(missing_docs)]
@@ -146,6 +363,16 @@ impl Interpolator for AngleZ {
         let angle = (self.end - self.start).mul_add(value, self.start);
         item.rotation = Quat::from_rotation_z(angle);
     }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["rotation"]
+    }
+}
+
+impl CaptureStart for AngleZ {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.rotation.to_euler(EulerRot::ZYX).0;
+    }
 }
 
 /// Constructor for [`AngleZ`]
@@ -172,3 +399,386 @@ pub fn angle_z_by(by: f32) -> impl Fn(&mut f32) -> AngleZ {
         angle_z(start, end)
     }
 }
+
+/// [`Interpolator`] for [`Transform`]'s translation that moves the target by
+/// `by` relative to wherever it is.
+///
+/// Must be applied with [`relative_component_tween_system`] so tweens such as
+/// a bob and a shake can stack on the same target.
+///
+/// [`relative_component_tween_system`]: crate::tween::relative_component_tween_system
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TranslationBy {
+    #[allow(missing_docs)]
+    pub by: Vec3,
+}
+impl Interpolator for TranslationBy {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.translation += self.by * value;
+    }
+}
+
+/// Constructor for [`TranslationBy`]
+pub fn relative_translation(by: Vec3) -> TranslationBy {
+    TranslationBy { by }
+}
+
+/// [`Interpolator`] for [`Transform`]'s rotation that rotates the target by
+/// `by` relative to its current rotation.
+///
+/// Must be applied with [`relative_component_tween_system`] so multiple
+/// relative tweens can stack on the same target.
+///
+/// [`relative_component_tween_system`]: crate::tween::relative_component_tween_system
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RotationBy {
+    #[allow(missing_docs)]
+    pub by: Quat,
+}
+impl Interpolator for RotationBy {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let (axis, angle) = self.by.to_axis_angle();
+        item.rotation *= Quat::from_axis_angle(axis, angle * value);
+    }
+}
+
+/// Constructor for [`RotationBy`]
+pub fn relative_rotation(by: Quat) -> RotationBy {
+    RotationBy { by }
+}
+
+/// [`Interpolator`] for [`Transform`]'s scale that adds `by` to the target's
+/// current scale.
+///
+/// Must be applied with [`relative_component_tween_system`] so multiple
+/// relative tweens can stack on the same target.
+///
+/// [`relative_component_tween_system`]: crate::tween::relative_component_tween_system
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ScaleBy {
+    #[allow(missing_docs)]
+    pub by: Vec3,
+}
+impl Interpolator for ScaleBy {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        item.scale += self.by * value;
+    }
+}
+
+/// Constructor for [`ScaleBy`]
+pub fn relative_scale(by: Vec3) -> ScaleBy {
+    ScaleBy { by }
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation along a [`CubicCurve`]
+/// such as a cubic Bezier or Catmull-Rom spline.
+///
+/// The whole curve is traversed from `0` to `1` regardless of its segment
+/// count.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+// #[reflect(InterpolatorTransform)]
+pub struct Path {
+    #[allow(missing_docs)]
+    pub curve: CubicCurve<Vec3>,
+    /// If set, rotate the target so its forward follows the curve's tangent
+    /// with this up direction.
+    pub align_to_tangent: Option<Dir3>,
+}
+
+impl Path {
+    /// Rotate the target so its forward follows the curve's tangent with
+    /// `up` as the up direction.
+    pub fn align_to_tangent(mut self, up: Dir3) -> Self {
+        self.align_to_tangent = Some(up);
+        self
+    }
+}
+
+impl Interpolator for Path {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let t = value * self.curve.segments().len() as f32;
+        item.translation = self.curve.position(t);
+        if let Some(up) = self.align_to_tangent {
+            if let Ok(forward) = Dir3::new(self.curve.velocity(t)) {
+                item.look_to(forward, up);
+            }
+        }
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        if self.align_to_tangent.is_some() {
+            &["translation", "rotation"]
+        } else {
+            &["translation"]
+        }
+    }
+}
+
+/// Constructor for [`Path`]
+pub fn path(curve: CubicCurve<Vec3>) -> Path {
+    Path {
+        curve,
+        align_to_tangent: None,
+    }
+}
+
+/// Constructor for [`Path`] along cubic Bezier segments of
+/// `[start, control, control, end]` points.
+///
+/// # Panics
+///
+/// Panics if `segments` is empty.
+pub fn path_bezier(segments: impl Into<Vec<[Vec3; 4]>>) -> Path {
+    path(
+        CubicBezier::new(segments)
+            .to_curve()
+            .expect("bezier path to have at least one segment"),
+    )
+}
+
+/// Constructor for [`Path`] along a Catmull-Rom spline passing through
+/// `points`.
+///
+/// # Panics
+///
+/// Panics if there are less than 2 points.
+pub fn path_catmull_rom(points: impl Into<Vec<Vec3>>) -> Path {
+    path(
+        CubicCardinalSpline::new_catmull_rom(points)
+            .to_curve()
+            .expect("catmull-rom path to have at least 2 points"),
+    )
+}
+
+/// [`Interpolator`] for [`Transform`]'s translation orbiting around `pivot`
+/// by sweeping the angle in radians and the radius, producing circular or
+/// spiral motion.
+///
+/// Angle `0` points toward +X projected onto the plane perpendicular to
+/// `axis`. Angles are not wrapped so multi-turn orbits are possible.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+// #[reflect(InterpolatorTransform)]
+pub struct Orbit {
+    /// Point to orbit around
+    pub pivot: Vec3,
+    /// Axis to orbit around
+    pub axis: Dir3,
+    #[allow(missing_docs)]
+    pub start_angle: f32,
+    #[allow(missing_docs)]
+    pub end_angle: f32,
+    #[allow(missing_docs)]
+    pub start_radius: f32,
+    #[allow(missing_docs)]
+    pub end_radius: f32,
+}
+
+impl Orbit {
+    /// Orbit around `axis` instead of Z
+    pub fn around(mut self, axis: Dir3) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Change the radius from the start radius to `end_radius`
+    pub fn spiral_to(mut self, end_radius: f32) -> Self {
+        self.end_radius = end_radius;
+        self
+    }
+}
+
+impl Default for Orbit {
+    fn default() -> Self {
+        Orbit {
+            pivot: Vec3::ZERO,
+            axis: Dir3::Z,
+            start_angle: 0.,
+            end_angle: 0.,
+            start_radius: 0.,
+            end_radius: 0.,
+        }
+    }
+}
+
+impl Interpolator for Orbit {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let reference = Vec3::X
+            .reject_from_normalized(*self.axis)
+            .try_normalize()
+            .unwrap_or_else(|| self.axis.any_orthonormal_vector());
+        let angle = self.start_angle.lerp(self.end_angle, value);
+        let radius = self.start_radius.lerp(self.end_radius, value);
+        item.translation = self.pivot
+            + Quat::from_axis_angle(*self.axis, angle) * reference * radius;
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["translation"]
+    }
+}
+
+/// Constructor for [`Orbit`] around the Z axis with a constant `radius`
+pub fn orbit(
+    pivot: Vec3,
+    radius: f32,
+    start_angle: f32,
+    end_angle: f32,
+) -> Orbit {
+    Orbit {
+        pivot,
+        axis: Dir3::Z,
+        start_angle,
+        end_angle,
+        start_radius: radius,
+        end_radius: radius,
+    }
+}
+
+/// [`Interpolator`] for [`Transform`]'s rotation from `start` toward looking
+/// at `point` from the current translation.
+///
+/// Use with [`FromCurrent`] to turn from
+/// the current facing, and add [`LookAtEntity`] to the tween entity to follow
+/// another entity. `point` is in the same space as the target's translation,
+/// which is its parent's space.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+// #[reflect(InterpolatorTransform)]
+pub struct LookAt {
+    #[allow(missing_docs)]
+    pub start: Quat,
+    /// Point to look at
+    pub point: Vec3,
+    /// Up direction while looking at `point`
+    pub up: Dir3,
+}
+
+impl Default for LookAt {
+    fn default() -> Self {
+        LookAt {
+            start: Quat::IDENTITY,
+            point: Vec3::ZERO,
+            up: Dir3::Y,
+        }
+    }
+}
+
+impl Interpolator for LookAt {
+    type Item = Transform;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let end = Transform::from_translation(item.translation)
+            .looking_at(self.point, self.up)
+            .rotation;
+        item.rotation = self.start.slerp(end, value);
+    }
+
+    fn fields(&self) -> &'static [&'static str] {
+        &["rotation"]
+    }
+}
+
+impl CaptureStart for LookAt {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.rotation;
+    }
+}
+
+/// Constructor for [`LookAt`]
+pub fn look_at(start: Quat, point: Vec3, up: Dir3) -> LookAt {
+    LookAt { start, point, up }
+}
+
+/// Constructor for [`LookAt`] from the current facing. See [`FromCurrent`].
+pub fn look_at_from_current(point: Vec3, up: Dir3) -> FromCurrent<LookAt> {
+    crate::interpolate::from_current(look_at(Quat::IDENTITY, point, up))
+}
+
+/// Keep the `point` of a [`LookAt`] tween on this entity at the
+/// [`GlobalTransform`] translation of another entity, converted into the
+/// space of the tween target's parent. Updated by [`look_at_entity_system`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[reflect(Component, MapEntities)]
+pub struct LookAtEntity(pub Entity);
+
+impl MapEntities for LookAtEntity {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// Update the `point` of [`LookAt`] tweens that have [`LookAtEntity`].
+///
+/// The world translation of the entity looked at is converted into the
+/// space of the parent of the tween's target so it matches the target's
+/// translation. If the tween targets multiple entities, the first one is
+/// used.
+#[allow(clippy::type_complexity)]
+pub fn look_at_entity_system(
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    mut q_tween: Query<
+        (
+            Entity,
+            &LookAtEntity,
+            Option<&mut ComponentTween<LookAt>>,
+            Option<&mut ComponentTween<FromCurrent<LookAt>>>,
+        ),
+        With<TweenInterpolationValue>,
+    >,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    q_parent: Query<&Parent>,
+    q_global_transform: Query<&GlobalTransform>,
+) {
+    q_tween.iter_mut().for_each(
+        |(entity, look_at_entity, tween, from_current_tween)| {
+            let Ok(looked_at) = q_global_transform.get(look_at_entity.0) else {
+                return;
+            };
+            let target = match (&tween, &from_current_tween) {
+                (Some(tween), _) => &tween.target,
+                (None, Some(tween)) => &tween.target,
+                (None, None) => return,
+            };
+            let target = resolve_tween_targets::<LookAt>(
+                entity,
+                target,
+                &q_animation_target,
+                &q_resolved_targets,
+                None,
+            )
+            .and_then(|targets| targets.first().copied());
+            let parent = target
+                .and_then(|target| q_parent.get(target).ok())
+                .and_then(|parent| q_global_transform.get(parent.get()).ok());
+            let point = match parent {
+                Some(parent) => parent
+                    .affine()
+                    .inverse()
+                    .transform_point3(looked_at.translation()),
+                None => looked_at.translation(),
+            };
+            if let Some(mut tween) = tween {
+                tween.interpolator.point = point;
+            }
+            if let Some(mut tween) = from_current_tween {
+                tween.interpolator.interpolator.point = point;
+            }
+        },
+    );
+}
@@ -1,26 +1,49 @@
-use crate::prelude::Interpolator;
+use crate::{
+    combinator::AnimationBuilderExt,
+    interpolate::{
+        color::impl_in_space, from_current, CaptureStart, ColorSpace,
+        Interpolator,
+    },
+    interpolation::EaseKind,
+    playback::CompletionPolicy,
+    tween::IntoTarget,
+};
 use bevy::prelude::*;
+use std::time::Duration;
 
 /// [`Interpolator`] for Bevy's [`BackgroundColor`](bevy::prelude::BackgroundColor) used in UIs.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BackgroundColor {
     #[allow(missing_docs)]
     pub start: Color,
     #[allow(missing_docs)]
     pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
 }
 
 impl Interpolator for BackgroundColor {
     type Item = bevy::prelude::BackgroundColor;
 
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
-        item.0 = self.start.mix(&self.end, value)
+        item.0 = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+impl CaptureStart for BackgroundColor {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.0;
     }
 }
 
 /// Constructor for [`BackgroundColor`](crate::interpolate::BackgroundColor)
 pub fn background_color(start: Color, end: Color) -> BackgroundColor {
-    BackgroundColor { start, end }
+    BackgroundColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
 }
 
 /// Constructor for [`BackgroundColor`](crate::interpolate::BackgroundColor) that's relative to previous value using currying.
@@ -37,24 +60,37 @@ pub fn background_color_to(
 
 /// [`Interpolator`] for Bevy's [`BorderColor`](bevy::prelude::BorderColor) used in UIs.
 #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct BorderColor {
     #[allow(missing_docs)]
     pub start: Color,
     #[allow(missing_docs)]
     pub end: Color,
+    /// Color space to interpolate in
+    pub space: ColorSpace,
 }
 
 impl Interpolator for BorderColor {
     type Item = bevy::prelude::BorderColor;
 
     fn interpolate(&self, item: &mut Self::Item, value: f32) {
-        item.0 = self.start.mix(&self.end, value)
+        item.0 = self.space.mix(&self.start, &self.end, value)
+    }
+}
+
+impl CaptureStart for BorderColor {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = item.0;
     }
 }
 
 /// Constructor for [`BorderColor`](crate::interpolate::BorderColor)
 pub fn border_color(start: Color, end: Color) -> BorderColor {
-    BorderColor { start, end }
+    BorderColor {
+        start,
+        end,
+        space: ColorSpace::default(),
+    }
 }
 
 /// Constructor for [`BorderColor`](crate::interpolate::BorderColor) that's relative to previous value using currying.
@@ -66,3 +102,186 @@ pub fn border_color_to(to: Color) -> impl Fn(&mut Color) -> BorderColor {
         border_color(start, end)
     }
 }
+
+/// Interpolate between two [`Val`]s of the same unit.
+///
+/// [`Val::Auto`] and mixed units can't be interpolated so the result snaps
+/// from `start` to `end` once `value` reaches `1.`.
+pub fn lerp_val(start: Val, end: Val, value: f32) -> Val {
+    match (start, end) {
+        (Val::Px(start), Val::Px(end)) => Val::Px(start.lerp(end, value)),
+        (Val::Percent(start), Val::Percent(end)) => {
+            Val::Percent(start.lerp(end, value))
+        }
+        (Val::Vw(start), Val::Vw(end)) => Val::Vw(start.lerp(end, value)),
+        (Val::Vh(start), Val::Vh(end)) => Val::Vh(start.lerp(end, value)),
+        (Val::VMin(start), Val::VMin(end)) => Val::VMin(start.lerp(end, value)),
+        (Val::VMax(start), Val::VMax(end)) => Val::VMax(start.lerp(end, value)),
+        _ => {
+            if value >= 1. {
+                end
+            } else {
+                start
+            }
+        }
+    }
+}
+
+/// Interpolate between two [`UiRect`]s side by side using [`lerp_val`].
+pub fn lerp_ui_rect(start: UiRect, end: UiRect, value: f32) -> UiRect {
+    UiRect {
+        left: lerp_val(start.left, end.left, value),
+        right: lerp_val(start.right, end.right, value),
+        top: lerp_val(start.top, end.top, value),
+        bottom: lerp_val(start.bottom, end.bottom, value),
+    }
+}
+
+macro_rules! node_interpolator {
+    (
+        $(#[$doc:meta])*
+        $name:ident, $field:ident, $ty:ty, $lerp:ident,
+        $constructor:ident, $constructor_to:ident
+    ) => {
+        $(#[$doc])*
+        #[derive(Debug, Default, Clone, PartialEq, Reflect)]
+        #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+        pub struct $name {
+            #[allow(missing_docs)]
+            pub start: $ty,
+            #[allow(missing_docs)]
+            pub end: $ty,
+        }
+
+        impl Interpolator for $name {
+            type Item = Node;
+
+            fn interpolate(&self, item: &mut Self::Item, value: f32) {
+                item.$field = $lerp(self.start, self.end, value);
+            }
+        }
+
+        #[doc = concat!("Constructor for [`", stringify!($name), "`]")]
+        pub fn $constructor(start: $ty, end: $ty) -> $name {
+            $name { start, end }
+        }
+
+        #[doc = concat!(
+            "Constructor for [`",
+            stringify!($name),
+            "`] that's relative to previous value using currying."
+        )]
+        pub fn $constructor_to(to: $ty) -> impl Fn(&mut $ty) -> $name {
+            move |state| {
+                let start = *state;
+                let end = to;
+                *state = to;
+                $constructor(start, end)
+            }
+        }
+    };
+}
+
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::width`]. See [`lerp_val`].
+    NodeWidth, width, Val, lerp_val, node_width, node_width_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::height`]. See [`lerp_val`].
+    NodeHeight, height, Val, lerp_val, node_height, node_height_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::left`]. See [`lerp_val`].
+    NodeLeft, left, Val, lerp_val, node_left, node_left_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::right`]. See [`lerp_val`].
+    NodeRight, right, Val, lerp_val, node_right, node_right_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::top`]. See [`lerp_val`].
+    NodeTop, top, Val, lerp_val, node_top, node_top_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::bottom`]. See [`lerp_val`].
+    NodeBottom, bottom, Val, lerp_val, node_bottom, node_bottom_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::margin`]. See [`lerp_ui_rect`].
+    NodeMargin, margin, UiRect, lerp_ui_rect, node_margin, node_margin_to
+);
+node_interpolator!(
+    /// [`Interpolator`] for [`Node::padding`]. See [`lerp_ui_rect`].
+    NodePadding, padding, UiRect, lerp_ui_rect, node_padding, node_padding_to
+);
+
+/// [`Interpolator`] for Bevy's [`ScrollPosition`] used in scrollable UI nodes.
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ScrollOffset {
+    #[allow(missing_docs)]
+    pub start: Vec2,
+    #[allow(missing_docs)]
+    pub end: Vec2,
+}
+
+impl Interpolator for ScrollOffset {
+    type Item = ScrollPosition;
+
+    fn interpolate(&self, item: &mut Self::Item, value: f32) {
+        let offset = self.start.lerp(self.end, value);
+        item.offset_x = offset.x;
+        item.offset_y = offset.y;
+    }
+}
+
+impl CaptureStart for ScrollOffset {
+    fn capture_start(&mut self, item: &Self::Item) {
+        self.start = Vec2::new(item.offset_x, item.offset_y);
+    }
+}
+
+/// Constructor for [`ScrollOffset`]
+pub fn scroll_offset(start: Vec2, end: Vec2) -> ScrollOffset {
+    ScrollOffset { start, end }
+}
+
+/// Constructor for [`ScrollOffset`] that's relative to previous value using currying.
+pub fn scroll_offset_to(to: Vec2) -> impl Fn(&mut Vec2) -> ScrollOffset {
+    move |state| {
+        let start = *state;
+        let end = to;
+        *state = to;
+        scroll_offset(start, end)
+    }
+}
+
+/// Smoothly scroll `entity` from its current [`ScrollPosition`] to
+/// `position` over `duration` with [`EaseKind::CubicOut`].
+///
+/// Spawns and returns a new animator entity which is despawned once the
+/// scroll completes. The tween is applied by
+/// [`component_tween_from_current_system`] of [`ScrollOffset`] in
+/// [`DefaultInterpolatorsPlugin`](crate::interpolate::DefaultInterpolatorsPlugin).
+///
+/// [`component_tween_from_current_system`]: crate::tween::component_tween_from_current_system
+pub fn scroll_to(
+    commands: &mut Commands,
+    entity: Entity,
+    position: Vec2,
+    duration: Duration,
+) -> Entity {
+    commands
+        .animation()
+        .insert_tween_here(
+            duration,
+            EaseKind::CubicOut,
+            entity
+                .into_target()
+                .with(from_current(scroll_offset(Vec2::ZERO, position))),
+        )
+        .insert(CompletionPolicy::DespawnRecursive)
+        .id()
+}
+
+impl_in_space!(BackgroundColor, BorderColor);
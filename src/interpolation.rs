@@ -4,24 +4,46 @@
 //!
 //! **Built-in interpolations**:
 //! - [`EaseKind`]
+//! - [`EaseCubicBezier`]
+//! - [`EaseSteps`]
+//! - [`EaseElastic`]
+//! - [`EaseBack`]
+//! - [`EaseSpring`]
+//! - [`EaseDirectional`]
+//! - [`EaseHermite`]
+//! - [`Reversed`] and [`Mirrored`]
+//! - [`EaseChain`] and [`EaseProduct`]
 //! - [`EaseClosure`]
+//! - [`EaseLut`]
 //!
 //! **Systems**:
 //! - [`sample_interpolations_system`]
+//! - [`sample_directional_interpolations_system`]
+//! - [`spring_settle_system`]
 
 use bevy::prelude::*;
 
-use crate::{tween::TweenInterpolationValue, TweenSystemSet};
-use bevy_time_runner::TimeSpanProgress;
+use crate::{
+    tween::{TimeRunnerOf, TimeRunnerSpans, TweenInterpolationValue},
+    TweenSystemSet,
+};
+use bevy::reflect::{reflect_trait, GetTypeRegistration, Typed};
+use bevy_time_runner::{
+    TimeBound, TimeDirection, TimeRunner, TimeSpan, TimeSpanProgress,
+};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::{marker::PhantomData, sync::Arc, time::Duration};
 
 #[cfg(feature = "bevy_lookup_curve")]
 pub mod bevy_lookup_curve;
 
 /// A trait for implementing interpolation algorithms.
 ///
-/// Currently only used for registering [`sample_interpolations_system`].
+/// Used for registering [`sample_interpolations_system`]. Interpolations
+/// with [`ReflectInterpolation`] registered can also be sampled without the
+/// system, such as by [`BakeTimeline`](crate::timeline::BakeTimeline).
+#[reflect_trait]
 pub trait Interpolation {
     /// Sample a value from this algorithm.
     /// Input should be between 0–1 and returns value that should be
@@ -59,7 +81,7 @@ impl Plugin for EaseKindPlugin {
 ///
 /// [unit interval]: `Interval::UNIT`
 #[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
-#[reflect(Component)]
+#[reflect(Component, Interpolation)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 pub enum EaseKind {
     /// `f(t) = t`
@@ -99,6 +121,14 @@ pub enum EaseKind {
     SineOut,
     /// Behaves as `EaseFunction::SineIn` for t < 0.5 and as `EaseFunction::SineOut` for t >= 0.5
     SineInOut,
+    /// `f(t) = 1.0 - sin((1.0 - t) * π / 4.0) / sin(π / 4.0)`, half of the
+    /// quarter wave of `EaseKind::SineIn` so it's gentler
+    HalfSineIn,
+    /// `f(t) = sin(t * π / 4.0) / sin(π / 4.0)`, half of the quarter wave of
+    /// `EaseKind::SineOut` so it's gentler
+    HalfSineOut,
+    /// Behaves as `EaseKind::HalfSineIn` for t < 0.5 and as `EaseKind::HalfSineOut` for t >= 0.5
+    HalfSineInOut,
 
     /// `f(t) = 1.0 - sqrt(1.0 - t²)`
     CircularIn,
@@ -140,6 +170,23 @@ pub enum EaseKind {
 
     /// `f(omega,t) = 1 - (1 - t)²(2sin(omega * t) / omega + cos(omega * t))`, parametrized by `omega`
     Elastic(f32),
+
+    /// `f(t) = 3t² - 2t³`
+    SmoothStep,
+    /// `f(t) = 6t⁵ - 15t⁴ + 10t³`
+    SmootherStep,
+
+    /// `f(p,t) = tᵖ`, parametrized by the exponent `p`
+    PowIn(f32),
+    /// `f(p,t) = 1.0 - (1.0 - t)ᵖ`, parametrized by the exponent `p`
+    PowOut(f32),
+    /// Behaves as `EaseKind::PowIn` for t < 0.5 and as `EaseKind::PowOut` for t >= 0.5
+    PowInOut(f32),
+
+    /// `f(b,t) = (bᵗ - 1.0) / (b - 1.0)`, parametrized by the base `b`
+    ExponentialBaseIn(f32),
+    /// `f(b,t) = 1.0 - (b¹⁻ᵗ - 1.0) / (b - 1.0)`, parametrized by the base `b`
+    ExponentialBaseOut(f32),
 }
 
 impl EaseKind {
@@ -162,6 +209,9 @@ impl EaseKind {
             EaseKind::SineIn => easing_functions::sine_in(t),
             EaseKind::SineOut => easing_functions::sine_out(t),
             EaseKind::SineInOut => easing_functions::sine_in_out(t),
+            EaseKind::HalfSineIn => easing_functions::half_sine_in(t),
+            EaseKind::HalfSineOut => easing_functions::half_sine_out(t),
+            EaseKind::HalfSineInOut => easing_functions::half_sine_in_out(t),
             EaseKind::CircularIn => easing_functions::circular_in(t),
             EaseKind::CircularOut => easing_functions::circular_out(t),
             EaseKind::CircularInOut => easing_functions::circular_in_out(t),
@@ -183,6 +233,17 @@ impl EaseKind {
                 easing_functions::steps(*num_steps, t)
             }
             EaseKind::Elastic(omega) => easing_functions::elastic(*omega, t),
+            EaseKind::SmoothStep => easing_functions::smooth_step(t),
+            EaseKind::SmootherStep => easing_functions::smoother_step(t),
+            EaseKind::PowIn(p) => easing_functions::pow_in(*p, t),
+            EaseKind::PowOut(p) => easing_functions::pow_out(*p, t),
+            EaseKind::PowInOut(p) => easing_functions::pow_in_out(*p, t),
+            EaseKind::ExponentialBaseIn(base) => {
+                easing_functions::exponential_base_in(*base, t)
+            }
+            EaseKind::ExponentialBaseOut(base) => {
+                1.0 - easing_functions::exponential_base_in(*base, 1.0 - t)
+            }
         }
     }
 }
@@ -233,6 +294,942 @@ impl From<EaseFunction> for EaseKind {
     }
 }
 
+/// Plugin for [`EaseCubicBezier`]
+pub struct EaseCubicBezierPlugin;
+
+impl Plugin for EaseCubicBezierPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseCubicBezier>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseCubicBezier>();
+    }
+}
+
+/// Cubic Bezier easing curve equivalent to CSS `cubic-bezier(x1, y1, x2, y2)`.
+///
+/// The curve starts at `(0, 0)` and ends at `(1, 1)` with `(x1, y1)` and
+/// `(x2, y2)` as control points. `x1` and `x2` are clamped to `0..=1` like in
+/// CSS while `y1` and `y2` may overshoot.
+///
+/// ```no_run
+/// use bevy_tween::interpolation::EaseCubicBezier;
+///
+/// // CSS `ease`
+/// let ease = EaseCubicBezier(0.25, 0.1, 0.25, 1.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component, Interpolation)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseCubicBezier(pub f32, pub f32, pub f32, pub f32);
+
+impl EaseCubicBezier {
+    /// CSS `ease`
+    pub const EASE: EaseCubicBezier = EaseCubicBezier(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`
+    pub const EASE_IN: EaseCubicBezier = EaseCubicBezier(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`
+    pub const EASE_OUT: EaseCubicBezier = EaseCubicBezier(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`
+    pub const EASE_IN_OUT: EaseCubicBezier =
+        EaseCubicBezier(0.42, 0.0, 0.58, 1.0);
+
+    /// Sample a value from this curve.
+    pub fn sample(&self, t: f32) -> f32 {
+        let EaseCubicBezier(x1, y1, x2, y2) = *self;
+        easing_functions::cubic_bezier(
+            x1.clamp(0., 1.),
+            y1,
+            x2.clamp(0., 1.),
+            y2,
+            t,
+        )
+    }
+}
+
+impl Default for EaseCubicBezier {
+    fn default() -> Self {
+        EaseCubicBezier::EASE
+    }
+}
+
+impl Interpolation for EaseCubicBezier {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
+/// Plugin for [`EaseSteps`]
+pub struct EaseStepsPlugin;
+
+impl Plugin for EaseStepsPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseSteps>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseSteps>();
+    }
+}
+
+/// Where the jumps of [`EaseSteps`] happen, equivalent to the CSS
+/// `<step-position>`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum JumpTerm {
+    /// First jump happens right at the start. CSS `jump-start`.
+    Start,
+    /// Last jump happens right at the end. CSS `jump-end`.
+    #[default]
+    End,
+    /// No jump at either end, the first and last step are held for a full
+    /// interval. CSS `jump-none`.
+    None,
+    /// Jumps at both the start and the end. CSS `jump-both`.
+    Both,
+}
+
+/// Discrete jumps across the span, equivalent to CSS
+/// `steps(count, jump_term)`.
+///
+/// Unlike [`EaseKind::Steps`] which rounds to the nearest step, each step is
+/// held for a whole `1 / count` of the span.
+#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component, Interpolation)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseSteps {
+    /// Number of intervals
+    pub count: usize,
+    /// Where the jumps happen
+    pub jump_term: JumpTerm,
+}
+
+impl EaseSteps {
+    /// Create new [`EaseSteps`]
+    pub fn new(count: usize, jump_term: JumpTerm) -> EaseSteps {
+        EaseSteps { count, jump_term }
+    }
+
+    /// Sample a value from this ease function.
+    pub fn sample(&self, t: f32) -> f32 {
+        easing_functions::jump_steps(self.count, self.jump_term, t)
+    }
+}
+
+impl Default for EaseSteps {
+    fn default() -> Self {
+        EaseSteps::new(1, JumpTerm::End)
+    }
+}
+
+impl Interpolation for EaseSteps {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
+/// Plugin for [`EaseElastic`]
+pub struct EaseElasticPlugin;
+
+impl Plugin for EaseElasticPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseElastic>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseElastic>();
+    }
+}
+
+/// Plugin for [`EaseBack`]
+pub struct EaseBackPlugin;
+
+impl Plugin for EaseBackPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseBack>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseBack>();
+    }
+}
+
+/// Which end of the span a parameterized ease is applied to
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum EaseMode {
+    /// Ease at the start
+    In,
+    /// Ease at the end
+    #[default]
+    Out,
+    /// Ease at both the start and the end
+    InOut,
+}
+
+/// Elastic easing with tunable `amplitude` and `period`.
+///
+/// The default is equivalent to [`EaseKind::ElasticOut`]. Like the built-in
+/// functions, [`EaseMode::InOut`] stretches the period by `1.5`.
+#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component, Interpolation)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseElastic {
+    /// Peak of the oscillation. Values below `1.` are treated as `1.`.
+    pub amplitude: f32,
+    /// Length of one oscillation as a fraction of the span
+    pub period: f32,
+    #[allow(missing_docs)]
+    pub mode: EaseMode,
+}
+
+impl EaseElastic {
+    /// Create new [`EaseElastic`]
+    pub fn new(amplitude: f32, period: f32, mode: EaseMode) -> EaseElastic {
+        EaseElastic {
+            amplitude,
+            period,
+            mode,
+        }
+    }
+
+    /// Sample a value from this ease function.
+    pub fn sample(&self, t: f32) -> f32 {
+        let (amplitude, period) = (self.amplitude, self.period);
+        match self.mode {
+            EaseMode::In => {
+                1. - easing_functions::elastic_out_with(
+                    amplitude,
+                    period,
+                    1. - t,
+                )
+            }
+            EaseMode::Out => {
+                easing_functions::elastic_out_with(amplitude, period, t)
+            }
+            EaseMode::InOut => {
+                let period = period * 1.5;
+                if t < 0.5 {
+                    (1. - easing_functions::elastic_out_with(
+                        amplitude,
+                        period,
+                        1. - 2. * t,
+                    )) / 2.
+                } else {
+                    (1. + easing_functions::elastic_out_with(
+                        amplitude,
+                        period,
+                        2. * t - 1.,
+                    )) / 2.
+                }
+            }
+        }
+    }
+}
+
+impl Default for EaseElastic {
+    fn default() -> Self {
+        EaseElastic::new(1., 0.3, EaseMode::Out)
+    }
+}
+
+impl Interpolation for EaseElastic {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
+/// Back easing with tunable `overshoot`.
+///
+/// The default is equivalent to [`EaseKind::BackOut`]. Like the built-in
+/// functions, [`EaseMode::InOut`] scales the overshoot by `1.525`.
+#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component, Interpolation)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseBack {
+    /// How far the curve pulls back past the start or end. `0.` is a cubic.
+    pub overshoot: f32,
+    #[allow(missing_docs)]
+    pub mode: EaseMode,
+}
+
+impl EaseBack {
+    /// Create new [`EaseBack`]
+    pub fn new(overshoot: f32, mode: EaseMode) -> EaseBack {
+        EaseBack { overshoot, mode }
+    }
+
+    /// Sample a value from this ease function.
+    pub fn sample(&self, t: f32) -> f32 {
+        let overshoot = self.overshoot;
+        match self.mode {
+            EaseMode::In => easing_functions::back_in_with(overshoot, t),
+            EaseMode::Out => {
+                1. - easing_functions::back_in_with(overshoot, 1. - t)
+            }
+            EaseMode::InOut => {
+                let overshoot = overshoot * 1.525;
+                if t < 0.5 {
+                    easing_functions::back_in_with(overshoot, 2. * t) / 2.
+                } else {
+                    (2. - easing_functions::back_in_with(
+                        overshoot,
+                        2. - 2. * t,
+                    )) / 2.
+                }
+            }
+        }
+    }
+}
+
+impl Default for EaseBack {
+    fn default() -> Self {
+        EaseBack::new(1.70158, EaseMode::Out)
+    }
+}
+
+impl Interpolation for EaseBack {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
+/// Plugin for [`EaseSpring`]
+pub struct EaseSpringPlugin;
+
+impl Plugin for EaseSpringPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                spring_settle_system
+                    .after(crate::tween::time_runner_of_system)
+                    .before(TweenSystemSet::TickTimeRunner),
+                sample_interpolations_system::<EaseSpring>
+                    .in_set(TweenSystemSet::UpdateInterpolationValue),
+            ),
+        )
+        .register_type::<EaseSpring>()
+        .register_type::<SpringSettle>();
+    }
+}
+
+/// Damped spring simulated from `0` toward `1` with unit mass.
+///
+/// The span is mapped onto the time the spring takes to settle, so the
+/// motion always comes to rest exactly at the end of the span regardless of
+/// its duration. For an open-ended span that runs at the spring's physical
+/// speed and completes when it settles, add [`SpringSettle`] next to it.
+///
+/// ```no_run
+/// use bevy_tween::interpolation::EaseSpring;
+///
+/// let spring = EaseSpring::new(180., 12., 0.);
+/// // Time the spring takes to settle, the span duration with `SpringSettle`.
+/// let duration = spring.duration();
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component, Interpolation)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseSpring {
+    /// Spring constant. Higher is snappier.
+    pub stiffness: f32,
+    /// Damping coefficient. Lower overshoots and oscillates more.
+    pub damping: f32,
+    /// Velocity at the start in span distance per second
+    pub initial_velocity: f32,
+}
+
+impl EaseSpring {
+    /// Distance and velocity from the target under which the spring is
+    /// considered settled
+    pub const SETTLE_THRESHOLD: f32 = 0.001;
+
+    /// Create new [`EaseSpring`]
+    pub fn new(
+        stiffness: f32,
+        damping: f32,
+        initial_velocity: f32,
+    ) -> EaseSpring {
+        EaseSpring {
+            stiffness,
+            damping,
+            initial_velocity,
+        }
+    }
+
+    fn omega_zeta(&self) -> (f32, f32) {
+        let omega = self.stiffness.max(f32::EPSILON).sqrt();
+        // Undamped springs never settle so keep a little damping.
+        let zeta = (self.damping / (2. * omega)).max(0.01);
+        // Snap near-critical damping to avoid dividing by a tiny root.
+        if (zeta - 1.).abs() < 1e-3 {
+            (omega, 1.)
+        } else {
+            (omega, zeta)
+        }
+    }
+
+    /// Distance from the target at `time` seconds.
+    fn offset(&self, time: f32) -> f32 {
+        let (omega, zeta) = self.omega_zeta();
+        let x0 = -1.;
+        let v0 = self.initial_velocity;
+        if zeta < 1. {
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let b = (v0 + zeta * omega * x0) / omega_d;
+            (-zeta * omega * time).exp()
+                * (x0 * (omega_d * time).cos() + b * (omega_d * time).sin())
+        } else if zeta == 1. {
+            (x0 + (v0 + omega * x0) * time) * (-omega * time).exp()
+        } else {
+            let root = omega * (zeta * zeta - 1.).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+            c1 * (r1 * time).exp() + c2 * (r2 * time).exp()
+        }
+    }
+
+    /// Time in seconds until the spring stays within
+    /// [`SETTLE_THRESHOLD`](Self::SETTLE_THRESHOLD) of the target.
+    pub fn settle_time(&self) -> f32 {
+        let (omega, zeta) = self.omega_zeta();
+        let x0 = -1_f32;
+        let v0 = self.initial_velocity;
+        let threshold = Self::SETTLE_THRESHOLD;
+        let time = if zeta < 1. {
+            let omega_d = omega * (1. - zeta * zeta).sqrt();
+            let b = (v0 + zeta * omega * x0) / omega_d;
+            let envelope = (x0 * x0 + b * b).sqrt();
+            (envelope / threshold).ln() / (zeta * omega)
+        } else if zeta == 1. {
+            let b = (v0 + omega * x0).abs();
+            let mut time = (1. / threshold).ln() / omega;
+            for _ in 0..8 {
+                time = ((x0.abs() + b * time) / threshold).ln() / omega;
+            }
+            time
+        } else {
+            let root = omega * (zeta * zeta - 1.).sqrt();
+            let r1 = -zeta * omega + root;
+            let r2 = -zeta * omega - root;
+            let c2 = (v0 - r1 * x0) / (r2 - r1);
+            let c1 = x0 - c2;
+            ((c1.abs() + c2.abs()) / threshold).ln() / -r1
+        };
+        time.max(0.)
+    }
+
+    /// [`settle_time`](Self::settle_time) as a [`Duration`] to use as the
+    /// span duration.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f32(self.settle_time())
+    }
+
+    /// Sample a value from this ease function.
+    pub fn sample(&self, t: f32) -> f32 {
+        if t >= 1. {
+            return 1.;
+        }
+        1. + self.offset(t.max(0.) * self.settle_time())
+    }
+}
+
+impl Default for EaseSpring {
+    fn default() -> Self {
+        EaseSpring::new(180., 12., 0.)
+    }
+}
+
+impl Interpolation for EaseSpring {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
+/// Open-ended mode of [`EaseSpring`] where the span completes when the
+/// spring settles instead of at a fixed duration.
+///
+/// Insert next to [`EaseSpring`] on a span entity. [`spring_settle_system`]
+/// then moves the end of its [`TimeSpan`] to [`EaseSpring::settle_time`]
+/// after its start and fits the length of the [`TimeRunner`] playing it,
+/// again every time the spring changes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct SpringSettle;
+
+/// End the [`TimeSpan`] of every [`SpringSettle`] span when its
+/// [`EaseSpring`] settles and fit the [`TimeRunner`] playing it to its
+/// spans.
+///
+/// If the time runner has no [`TimeRunnerSpans`], it's only lengthened.
+pub fn spring_settle_system(
+    q_spring: Query<(Entity, Ref<EaseSpring>, Ref<SpringSettle>)>,
+    mut q_span: Query<&mut TimeSpan>,
+    q_parent: Query<&Parent>,
+    q_time_runner_of: Query<&TimeRunnerOf>,
+    q_time_runner_spans: Query<&TimeRunnerSpans>,
+    mut q_time_runner: Query<&mut TimeRunner>,
+) {
+    for (entity, spring, settle) in &q_spring {
+        let Ok(mut span) = q_span.get_mut(entity) else {
+            continue;
+        };
+        if !(spring.is_changed() || settle.is_added() || span.is_added()) {
+            continue;
+        }
+        let end = span.min().duration() + spring.duration();
+        let end_bound = match span.max() {
+            TimeBound::Inclusive(_) => TimeBound::Inclusive(end),
+            TimeBound::Exclusive(_) => TimeBound::Exclusive(end),
+        };
+        if span.max().duration() != end {
+            let Ok(new) = TimeSpan::new(span.min(), end_bound) else {
+                continue;
+            };
+            *span = new;
+        }
+
+        let time_runner = match q_time_runner_of.get(entity) {
+            Ok(TimeRunnerOf(time_runner)) => Some(*time_runner),
+            Err(_) => std::iter::successors(Some(entity), |curr| {
+                q_parent.get(*curr).ok().map(Parent::get)
+            })
+            .find(|curr| q_time_runner.contains(*curr)),
+        };
+        let Some(time_runner) = time_runner else {
+            continue;
+        };
+        let spans_end =
+            q_time_runner_spans.get(time_runner).ok().map(|spans| {
+                spans
+                    .0
+                    .iter()
+                    .filter_map(|span| q_span.get(*span).ok())
+                    .map(|span| span.max().duration())
+                    .fold(end, Duration::max)
+            });
+        let Ok(mut time_runner) = q_time_runner.get_mut(time_runner) else {
+            continue;
+        };
+        let length = spans_end.unwrap_or(time_runner.length().max(end));
+        if time_runner.length() != length {
+            time_runner.set_length(length);
+        }
+    }
+}
+
+/// Plugin for [`EaseHermite`]
+pub struct EaseHermitePlugin;
+
+impl Plugin for EaseHermitePlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseHermite>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseHermite>();
+    }
+}
+
+/// Cubic Hermite easing defined by the tangents at both ends of the span, as
+/// exported by animation editors for keyframes with tangent handles.
+///
+/// Tangents are slopes over the unit interval: `1.` on both ends is linear
+/// and `0.` on both ends, the default, is [`EaseKind::SmoothStep`].
+/// Use [`EaseHermite::from_keyframes`] to convert tangents measured in value
+/// per second between two keyframes. For weighted handles, use
+/// [`EaseCubicBezier`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component, Interpolation)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseHermite {
+    /// Slope leaving the start of the span
+    pub out_tangent: f32,
+    /// Slope arriving at the end of the span
+    pub in_tangent: f32,
+}
+
+impl EaseHermite {
+    /// Create new [`EaseHermite`]
+    pub fn new(out_tangent: f32, in_tangent: f32) -> EaseHermite {
+        EaseHermite {
+            out_tangent,
+            in_tangent,
+        }
+    }
+
+    /// Create new [`EaseHermite`] from two keyframes at `(time, value)` with
+    /// tangents in value per second.
+    ///
+    /// If both keyframes have the same value, the tangents can't be
+    /// normalized and the default [`EaseHermite`], a smoothstep, is returned.
+    pub fn from_keyframes(
+        (start_time, start_value): (f32, f32),
+        out_tangent: f32,
+        (end_time, end_value): (f32, f32),
+        in_tangent: f32,
+    ) -> EaseHermite {
+        let delta = end_value - start_value;
+        if delta == 0. {
+            return EaseHermite::default();
+        }
+        let scale = (end_time - start_time) / delta;
+        EaseHermite::new(out_tangent * scale, in_tangent * scale)
+    }
+
+    /// Sample a value from this ease function.
+    pub fn sample(&self, t: f32) -> f32 {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        (t3 - 2. * t2 + t) * self.out_tangent
+            + (-2. * t3 + 3. * t2)
+            + (t3 - t2) * self.in_tangent
+    }
+}
+
+impl Interpolation for EaseHermite {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
+/// Plugin for [`EaseDirectional<I>`]
+pub struct EaseDirectionalPlugin<I>(PhantomData<I>);
+
+impl<I> Default for EaseDirectionalPlugin<I> {
+    fn default() -> Self {
+        EaseDirectionalPlugin(PhantomData)
+    }
+}
+
+impl<I> Plugin for EaseDirectionalPlugin<I>
+where
+    I: Interpolation
+        + FromReflect
+        + Typed
+        + GetTypeRegistration
+        + Send
+        + Sync
+        + 'static,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_directional_interpolations_system::<I>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseDirectional<I>>()
+        .register_type_data::<EaseDirectional<I>, ReflectInterpolation>();
+    }
+}
+
+/// Use a different interpolation when the span is played backward, such as
+/// the second half of [`RepeatStyle::PingPong`].
+///
+/// When `backward` is [`None`], `forward` is mirrored so the curve keeps its
+/// shape relative to the playback direction, e.g. an ease-out going up becomes
+/// an ease-out coming down.
+///
+/// Sampled forward when used as a plain [`Interpolation`], such as by
+/// [`BakeTimeline`] which plays the timeline through once from the start.
+///
+/// [`RepeatStyle::PingPong`]: bevy_time_runner::RepeatStyle::PingPong
+/// [`BakeTimeline`]: crate::timeline::BakeTimeline
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseDirectional<I> {
+    /// Interpolation used while playing forward
+    pub forward: I,
+    /// Interpolation used while playing backward
+    pub backward: Option<I>,
+}
+
+impl<I> EaseDirectional<I> {
+    /// Create new [`EaseDirectional`] with an explicit backward interpolation
+    pub fn new(forward: I, backward: I) -> EaseDirectional<I> {
+        EaseDirectional {
+            forward,
+            backward: Some(backward),
+        }
+    }
+
+    /// Create new [`EaseDirectional`] that mirrors `forward` when playing
+    /// backward
+    pub fn mirrored(forward: I) -> EaseDirectional<I> {
+        EaseDirectional {
+            forward,
+            backward: None,
+        }
+    }
+}
+
+impl<I: Interpolation> EaseDirectional<I> {
+    /// Sample a value for the given playback direction.
+    pub fn sample(&self, v: f32, backward: bool) -> f32 {
+        if !backward {
+            return self.forward.sample(v);
+        }
+        match &self.backward {
+            Some(interpolation) => interpolation.sample(v),
+            None => 1. - self.forward.sample(1. - v),
+        }
+    }
+}
+
+impl<I: Interpolation> Interpolation for EaseDirectional<I> {
+    fn sample(&self, v: f32) -> f32 {
+        self.forward.sample(v)
+    }
+}
+
+/// Plugin for [`Reversed<I>`], [`Mirrored<I>`], [`EaseChain<I>`], and
+/// [`EaseProduct<I>`]
+pub struct EaseModifiersPlugin<I>(PhantomData<I>);
+
+impl<I> Default for EaseModifiersPlugin<I> {
+    fn default() -> Self {
+        EaseModifiersPlugin(PhantomData)
+    }
+}
+
+impl<I> Plugin for EaseModifiersPlugin<I>
+where
+    I: Interpolation
+        + FromReflect
+        + Typed
+        + GetTypeRegistration
+        + Send
+        + Sync
+        + 'static,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                sample_interpolations_system::<Reversed<I>>,
+                sample_interpolations_system::<Mirrored<I>>,
+                sample_interpolations_system::<EaseChain<I>>,
+                sample_interpolations_system::<EaseProduct<I>>,
+            )
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<Reversed<I>>()
+        .register_type::<Mirrored<I>>()
+        .register_type::<EaseChain<I>>()
+        .register_type::<EaseProduct<I>>()
+        .register_type_data::<Reversed<I>, ReflectInterpolation>()
+        .register_type_data::<Mirrored<I>, ReflectInterpolation>()
+        .register_type_data::<EaseChain<I>, ReflectInterpolation>()
+        .register_type_data::<EaseProduct<I>, ReflectInterpolation>();
+    }
+}
+
+/// Flip an interpolation both ways, `f(t) = 1 - inner(1 - t)`, turning an
+/// ease-in into an ease-out and vice versa.
+#[derive(Debug, Default, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Reversed<I>(pub I);
+
+impl<I: Interpolation> Interpolation for Reversed<I> {
+    fn sample(&self, v: f32) -> f32 {
+        1. - self.0.sample(1. - v)
+    }
+}
+
+/// Play an interpolation forward over the first half of the span then
+/// backward over the second half, symmetric around the middle.
+#[derive(Debug, Default, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Mirrored<I>(pub I);
+
+impl<I: Interpolation> Interpolation for Mirrored<I> {
+    fn sample(&self, v: f32) -> f32 {
+        if v < 0.5 {
+            self.0.sample(2. * v)
+        } else {
+            self.0.sample(2. - 2. * v)
+        }
+    }
+}
+
+/// One segment of [`EaseChain`]
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseSegment<I> {
+    /// Time in the span where this segment ends, between 0–1
+    pub end_time: f32,
+    /// Value reached at the end of this segment
+    pub end_value: f32,
+    /// Interpolation from the previous segment's value to `end_value`
+    pub ease: I,
+}
+
+/// Split the span into segments, each easing from the previous segment's
+/// value to its own, starting from `(0, 0)`. The last segment's value is
+/// held past its `end_time`.
+///
+/// For example, anticipate then overshoot:
+///
+/// ```no_run
+/// use bevy_tween::interpolation::{EaseChain, EaseKind};
+///
+/// let anticipate_overshoot = EaseChain::new()
+///     .then(0.2, -0.1, EaseKind::QuadraticOut)
+///     .then(0.8, 1.1, EaseKind::CubicInOut)
+///     .then(1.0, 1.0, EaseKind::QuadraticInOut);
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseChain<I> {
+    /// Segments ordered by `end_time`
+    pub segments: Vec<EaseSegment<I>>,
+}
+
+impl<I> EaseChain<I> {
+    /// Create new empty [`EaseChain`]
+    pub fn new() -> EaseChain<I> {
+        EaseChain {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Append a segment ending at `end_time` with `end_value`
+    pub fn then(mut self, end_time: f32, end_value: f32, ease: I) -> Self {
+        self.segments.push(EaseSegment {
+            end_time,
+            end_value,
+            ease,
+        });
+        self
+    }
+}
+
+impl<I> Default for EaseChain<I> {
+    fn default() -> Self {
+        EaseChain::new()
+    }
+}
+
+impl<I: Interpolation> Interpolation for EaseChain<I> {
+    fn sample(&self, v: f32) -> f32 {
+        let mut start_time = 0.;
+        let mut start_value = 0.;
+        for segment in &self.segments {
+            if v <= segment.end_time {
+                let length = segment.end_time - start_time;
+                let local = if length > 0. {
+                    ((v - start_time) / length).clamp(0., 1.)
+                } else {
+                    1.
+                };
+                return start_value
+                    .lerp(segment.end_value, segment.ease.sample(local));
+            }
+            start_time = segment.end_time;
+            start_value = segment.end_value;
+        }
+        start_value
+    }
+}
+
+/// Multiply two interpolations together, `f(t) = a(t) * b(t)`.
+#[derive(Debug, Default, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct EaseProduct<I>(pub I, pub I);
+
+impl<I: Interpolation> Interpolation for EaseProduct<I> {
+    fn sample(&self, v: f32) -> f32 {
+        self.0.sample(v) * self.1.sample(v)
+    }
+}
+
 /// Plugin for [`EaseClosure`]. In case you want to use custom an ease
 /// function. Since most people likely wouldn't use this type, this plugin is
 /// not with [`DefaultTweenPlugins`] to reduce unused system.
@@ -283,30 +1280,172 @@ impl Interpolation for EaseClosure {
     }
 }
 
+/// Plugin for [`EaseLut`]
+pub struct EaseLutPlugin;
+impl Plugin for EaseLutPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            sample_interpolations_system::<EaseLut>
+                .in_set(TweenSystemSet::UpdateInterpolationValue),
+        )
+        .register_type::<EaseLut>();
+    }
+}
+
+/// Easing pre-baked into a lookup table, sampled with linear interpolation.
+///
+/// Bake an expensive interpolation once, for example at startup, then clone
+/// the [`EaseLut`] into every tween. Clones share the same table, which is
+/// why it's reflected as an opaque value.
+///
+/// ```no_run
+/// use bevy_tween::interpolation::{EaseClosure, EaseLut};
+///
+/// let lut = EaseLut::bake(&EaseClosure::new(|t| t.powf(2.2)), 256);
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Debug, PartialEq, Interpolation)]
+pub struct EaseLut(Arc<[f32]>);
+
+impl EaseLut {
+    /// Bake `interpolation` into `samples` evenly spaced samples over the
+    /// unit interval. At least 2 samples are taken.
+    pub fn bake<I: Interpolation>(
+        interpolation: &I,
+        samples: usize,
+    ) -> EaseLut {
+        let samples = samples.max(2);
+        let last = (samples - 1) as f32;
+        EaseLut(
+            (0..samples)
+                .map(|i| interpolation.sample(i as f32 / last))
+                .collect(),
+        )
+    }
+
+    /// Baked samples
+    pub fn samples(&self) -> &[f32] {
+        &self.0
+    }
+
+    /// Sample a value from the table.
+    pub fn sample(&self, v: f32) -> f32 {
+        let last = self.0.len() - 1;
+        let position = v.clamp(0., 1.) * last as f32;
+        let index = (position as usize).min(last - 1);
+        let a = self.0[index];
+        let b = self.0[index + 1];
+        a + (b - a) * (position - index as f32)
+    }
+}
+
+impl Interpolation for EaseLut {
+    fn sample(&self, v: f32) -> f32 {
+        self.sample(v)
+    }
+}
+
 /// This system will automatically sample in each entities with a
-/// [`TimeSpanProgress`] component then insert [`TweenInterpolationValue`].
+/// [`TimeSpanProgress`] component then insert [`TweenInterpolationValue`]
+/// unless it's the same as the current one.
 /// Remove [`TweenInterpolationValue`] if [`TimeSpanProgress`] is removed.
 #[allow(clippy::type_complexity)]
 pub fn sample_interpolations_system<I>(
     mut commands: Commands,
     query: Query<
-        (Entity, &I, &TimeSpanProgress),
+        (
+            Entity,
+            &I,
+            &TimeSpanProgress,
+            Option<&TweenInterpolationValue>,
+        ),
         Or<(Changed<I>, Changed<TimeSpanProgress>)>,
     >,
     mut removed: RemovedComponents<TimeSpanProgress>,
 ) where
     I: Interpolation + Component,
 {
-    query.iter().for_each(|(entity, interpolator, progress)| {
-        if progress.now_percentage.is_nan() {
-            return;
-        }
-        let value = interpolator.sample(progress.now_percentage.clamp(0., 1.));
+    query
+        .iter()
+        .for_each(|(entity, interpolator, progress, previous)| {
+            if progress.now_percentage.is_nan() {
+                return;
+            }
+            let value =
+                interpolator.sample(progress.now_percentage.clamp(0., 1.));
+            if previous.is_some_and(|previous| previous.0 == value) {
+                return;
+            }
 
-        commands
-            .entity(entity)
-            .insert(TweenInterpolationValue(value));
+            commands
+                .entity(entity)
+                .insert(TweenInterpolationValue(value));
+        });
+    removed.read().for_each(|entity| {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.remove::<TweenInterpolationValue>();
+        }
     });
+}
+
+/// Same as [`sample_interpolations_system`] but for [`EaseDirectional<I>`].
+/// The span is considered played backward when the [`TimeRunner`] playing
+/// it, found by [`TimeRunnerOf`], runs backward by its direction and time
+/// scale together, or when its progress decreased if there's none.
+#[allow(clippy::type_complexity)]
+pub fn sample_directional_interpolations_system<I>(
+    mut commands: Commands,
+    query: Query<
+        (
+            Entity,
+            &EaseDirectional<I>,
+            &TimeSpanProgress,
+            Option<&TimeRunnerOf>,
+            Option<&TweenInterpolationValue>,
+        ),
+        Or<(Changed<EaseDirectional<I>>, Changed<TimeSpanProgress>)>,
+    >,
+    q_time_runner: Query<&TimeRunner>,
+    mut removed: RemovedComponents<TimeSpanProgress>,
+) where
+    I: Interpolation + Send + Sync + 'static,
+{
+    query.iter().for_each(
+        |(entity, interpolator, progress, time_runner_of, previous)| {
+            if progress.now_percentage.is_nan() {
+                return;
+            }
+            let backward = match time_runner_of
+                .and_then(|of| q_time_runner.get(of.0).ok())
+            {
+                Some(time_runner) => {
+                    (time_runner.direction() == TimeDirection::Backward)
+                        != (time_runner.time_scale() < 0.)
+                }
+                None => progress.now_percentage < progress.previous_percentage,
+            };
+            let value = interpolator
+                .sample(progress.now_percentage.clamp(0., 1.), backward);
+            if previous.is_some_and(|previous| previous.0 == value) {
+                return;
+            }
+
+            commands
+                .entity(entity)
+                .insert(TweenInterpolationValue(value));
+        },
+    );
     removed.read().for_each(|entity| {
         if let Some(mut entity) = commands.get_entity(entity) {
             entity.remove::<TweenInterpolationValue>();
@@ -316,7 +1455,9 @@ pub fn sample_interpolations_system<I>(
 
 mod easing_functions {
     use bevy::math::prelude::*;
-    use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, PI};
+    use core::f32::consts::{
+        FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, PI,
+    };
     use ops::FloatPow;
 
     #[inline]
@@ -414,6 +1555,23 @@ mod easing_functions {
         -(ops::cos(PI * t) - 1.0) / 2.0
     }
 
+    #[inline]
+    pub(crate) fn half_sine_in(t: f32) -> f32 {
+        1.0 - half_sine_out(1.0 - t)
+    }
+    #[inline]
+    pub(crate) fn half_sine_out(t: f32) -> f32 {
+        ops::sin(t * FRAC_PI_4) / FRAC_1_SQRT_2
+    }
+    #[inline]
+    pub(crate) fn half_sine_in_out(t: f32) -> f32 {
+        if t < 0.5 {
+            half_sine_in(2.0 * t) / 2.0
+        } else {
+            (1.0 + half_sine_out(2.0 * t - 1.0)) / 2.0
+        }
+    }
+
     #[inline]
     pub(crate) fn circular_in(t: f32) -> f32 {
         1.0 - (1.0 - t.squared()).sqrt()
@@ -499,6 +1657,62 @@ mod easing_functions {
         }
     }
 
+    #[inline]
+    pub(crate) fn smooth_step(t: f32) -> f32 {
+        t.squared() * (3.0 - 2.0 * t)
+    }
+
+    #[inline]
+    pub(crate) fn smoother_step(t: f32) -> f32 {
+        t.cubed() * (t * (6.0 * t - 15.0) + 10.0)
+    }
+
+    #[inline]
+    pub(crate) fn pow_in(p: f32, t: f32) -> f32 {
+        ops::powf(t, p)
+    }
+    #[inline]
+    pub(crate) fn pow_out(p: f32, t: f32) -> f32 {
+        1.0 - ops::powf(1.0 - t, p)
+    }
+    #[inline]
+    pub(crate) fn pow_in_out(p: f32, t: f32) -> f32 {
+        if t < 0.5 {
+            pow_in(p, 2.0 * t) / 2.0
+        } else {
+            (1.0 + pow_out(p, 2.0 * t - 1.0)) / 2.0
+        }
+    }
+
+    #[inline]
+    pub(crate) fn exponential_base_in(base: f32, t: f32) -> f32 {
+        if (base - 1.0).abs() < f32::EPSILON || base <= 0.0 {
+            return t;
+        }
+        (ops::powf(base, t) - 1.0) / (base - 1.0)
+    }
+
+    pub(crate) fn elastic_out_with(amplitude: f32, period: f32, t: f32) -> f32 {
+        if t <= 0. {
+            return 0.;
+        }
+        if t >= 1. {
+            return 1.;
+        }
+        let amplitude = amplitude.max(1.);
+        let period = period.max(f32::EPSILON);
+        let shift = period / (2. * PI) * ops::asin(1. / amplitude);
+        amplitude
+            * ops::powf(2.0, -10.0 * t)
+            * ops::sin((t - shift) * 2. * PI / period)
+            + 1.0
+    }
+
+    #[inline]
+    pub(crate) fn back_in_with(overshoot: f32, t: f32) -> f32 {
+        (overshoot + 1.) * t.cubed() - overshoot * t.squared()
+    }
+
     #[inline]
     pub(crate) fn bounce_in(t: f32) -> f32 {
         1.0 - bounce_out(1.0 - t)
@@ -530,6 +1744,76 @@ mod easing_functions {
         (t * num_steps as f32).round() / num_steps.max(1) as f32
     }
 
+    pub(crate) fn jump_steps(
+        count: usize,
+        jump_term: super::JumpTerm,
+        t: f32,
+    ) -> f32 {
+        use super::JumpTerm;
+        let count = count.max(1);
+        let jumps = match jump_term {
+            JumpTerm::Start | JumpTerm::End => count,
+            JumpTerm::None => count.saturating_sub(1).max(1),
+            JumpTerm::Both => count + 1,
+        };
+        let mut step = (t * count as f32).floor();
+        if matches!(jump_term, JumpTerm::Start | JumpTerm::Both) {
+            step += 1.;
+        }
+        step.clamp(0., jumps as f32) / jumps as f32
+    }
+
+    /// Solve the curve parameter for `x` with Newton's method, falling back
+    /// to bisection when the slope is too flat, then evaluate `y`.
+    pub(crate) fn cubic_bezier(
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        x: f32,
+    ) -> f32 {
+        const EPSILON: f32 = 1e-6;
+        // Bernstein form of one axis with p0 = 0 and p3 = 1
+        let curve = |p1: f32, p2: f32, t: f32| {
+            let u = 1. - t;
+            3. * u * u * t * p1 + 3. * u * t * t * p2 + t * t * t
+        };
+        let slope = |p1: f32, p2: f32, t: f32| {
+            let u = 1. - t;
+            3. * u * u * p1 + 6. * u * t * (p2 - p1) + 3. * t * t * (1. - p2)
+        };
+
+        let x = x.clamp(0., 1.);
+        let mut t = x;
+        for _ in 0..8 {
+            let error = curve(x1, x2, t) - x;
+            if error.abs() < EPSILON {
+                return curve(y1, y2, t);
+            }
+            let d = slope(x1, x2, t);
+            if d.abs() < EPSILON {
+                break;
+            }
+            t -= error / d;
+        }
+
+        let (mut low, mut high) = (0., 1.);
+        t = x;
+        for _ in 0..32 {
+            let current = curve(x1, x2, t);
+            if (current - x).abs() < EPSILON {
+                break;
+            }
+            if current < x {
+                low = t;
+            } else {
+                high = t;
+            }
+            t = (low + high) * 0.5;
+        }
+        curve(y1, y2, t)
+    }
+
     #[inline]
     pub(crate) fn elastic(omega: f32, t: f32) -> f32 {
         1.0 - (1.0 - t).squared()
@@ -30,7 +30,9 @@ impl Plugin for BevyLookupCurveInterpolationPlugin {
                 // sample_interpolations_mut_system::<CurveCached>
                 //     .in_set(TweenSystemSet::UpdateInterpolationValue),
             ),
-        );
+        )
+        .register_type::<LookupCurveCache>()
+        .register_type::<LookupCurveHandle>();
     }
 }
 
@@ -39,31 +41,52 @@ impl Plugin for BevyLookupCurveInterpolationPlugin {
 #[reflect(Component)]
 pub struct LookupCurveCache(pub LookupCache);
 
-/// [`LookupCurve`] handle
+/// [`LookupCurve`] handle used as the interpolation of a span.
+///
+/// The curve is loaded like any other asset and the span is resampled when
+/// the asset is modified, such as by hot-reloading or an editor.
 #[derive(Clone, Debug, Component, Reflect)]
+#[reflect(Component)]
 pub struct LookupCurveHandle(pub Handle<LookupCurve>);
 
 /// Interpolation system for [`LookupCurveHandle`]
+///
+/// Samples when the handle or [`TimeSpanProgress`] changed, or when the
+/// [`LookupCurve`] asset is added or modified.
 #[allow(clippy::type_complexity)]
 pub fn sample_lookup_curve_system(
     mut commands: Commands,
-    mut query: Query<
-        (
-            Entity,
-            &LookupCurveHandle,
-            Option<&mut LookupCurveCache>,
-            &TimeSpanProgress,
-        ),
-        Or<(Changed<LookupCurveHandle>, Changed<TimeSpanProgress>)>,
-    >,
+    mut query: Query<(
+        Entity,
+        Ref<LookupCurveHandle>,
+        Option<&mut LookupCurveCache>,
+        Ref<TimeSpanProgress>,
+        Option<&TweenInterpolationValue>,
+    )>,
     mut removed: RemovedComponents<TimeSpanProgress>,
     lookup_curve: Res<Assets<LookupCurve>>,
+    mut asset_events: EventReader<AssetEvent<LookupCurve>>,
     mut last_handle_error: Local<HashSet<AssetId<LookupCurve>>>,
 ) {
+    let modified = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
     let mut handle_error = HashSet::new();
     query
         .iter_mut()
-        .for_each(|(entity, curve, cache, progress)| {
+        .for_each(|(entity, curve, cache, progress, previous)| {
+            if !curve.is_changed()
+                && !progress.is_changed()
+                && !modified.contains(&curve.0.id())
+            {
+                return;
+            }
             if progress.now_percentage.is_nan() {
                 return;
             }
@@ -89,6 +112,9 @@ pub fn sample_lookup_curve_system(
                 ),
                 None => curve.lookup(progress.now_percentage.clamp(0., 1.)),
             };
+            if previous.is_some_and(|previous| previous.0 == value) {
+                return;
+            }
 
             commands
                 .entity(entity)
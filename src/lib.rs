@@ -321,6 +321,28 @@
 //! );
 //! ```
 //!
+//! ## Deterministic playback
+//! Time runners are ticked using the default [`Time`](bevy::time::Time) of
+//! the schedule configured in [`TweenAppResource`].
+//! Run every tween systems in `FixedUpdate` to tick with `Time<Fixed>` so
+//! gameplay-critical animations become frame-rate independent:
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::prelude::*;
+//! use bevy_tween::{TweenAppResource, TweenCorePlugin};
+//!
+//! fn main() {
+//!     App::default()
+//!         .add_plugins((
+//!             DefaultPlugins,
+//!             DefaultTweenPlugins.set(TweenCorePlugin {
+//!                 app_resource: TweenAppResource::new(FixedUpdate),
+//!             }),
+//!         ))
+//!         .run();
+//! }
+//! ```
+//!
 //! ## Custom interpolator
 //!
 //! See these documentations for more details:
@@ -378,10 +400,27 @@ pub use bevy_time_runner;
 
 pub mod interpolate;
 pub mod interpolation;
+pub mod playback;
+pub mod span;
+pub mod timeline;
+pub mod transition;
 pub mod tween;
 pub mod tween_event;
 
+pub mod animator;
 pub mod combinator;
+pub mod diagnostic;
+
+#[cfg(feature = "bevy_animation")]
+pub mod animation_clip;
+#[cfg(feature = "tween_clip")]
+pub mod clip;
+#[cfg(feature = "bevy_gizmos")]
+pub mod gizmos;
+#[cfg(feature = "egui")]
+pub mod inspector;
+#[cfg(feature = "sprite_sheet")]
+pub mod sprite_sheet;
 
 /// Commonly used items
 pub mod prelude {
@@ -392,7 +431,11 @@ pub mod prelude {
 
     pub use crate::bevy_time_runner::{Repeat, RepeatStyle, TimeDirection};
 
-    pub use crate::combinator::{AnimationBuilderExt, TransformTargetStateExt};
+    pub use crate::combinator::{
+        AnimationBuilderExt, PlayAnimationExt, TransformTargetStateExt,
+    };
+
+    pub use crate::playback::TimeRunnerExt;
 
     pub use crate::tween::IntoTarget;
     pub use crate::tween_event::{TweenEvent, TweenEventData};
@@ -432,20 +475,70 @@ pub use tween_event::tween_event_system;
 /// - [`interpolate::DefaultInterpolatorsPlugin`]
 /// - [`interpolate::DefaultDynInterpolatorsPlugin`]
 /// - [`interpolation::EaseKindPlugin`]
+/// - [`interpolation::EaseCubicBezierPlugin`]
+/// - [`interpolation::EaseStepsPlugin`]
+/// - [`interpolation::EaseElasticPlugin`]
+/// - [`interpolation::EaseBackPlugin`]
+/// - [`interpolation::EaseSpringPlugin`]
+/// - [`interpolation::EaseHermitePlugin`]
+/// - [`interpolation::EaseLutPlugin`]
+/// - [`interpolation::EaseDirectionalPlugin`] of [`interpolation::EaseKind`]
+/// - [`interpolation::EaseModifiersPlugin`] of [`interpolation::EaseKind`]
+/// - [`playback::PlaybackPlugin`]
+/// - [`animator::AnimatorPlugin`]
+/// - [`span::SpanPlugin`]
+/// - [`timeline::TimelineEditPlugin`]
+/// - [`timeline::TimelineBakePlugin`] of [`Transform`]
+/// - [`tween::TargetPathPlugin`]
+/// - [`tween::TweenGroupPlugin`]
 /// - [`tween_event::DefaultTweenEventPlugins`]
+/// - `span::bevy_audio::AudioSpanPlugin` with the `bevy_audio` feature
+/// - `clip::TweenClipPlugin` with the `tween_clip` feature
+/// - `animation_clip::AnimationClipTweenPlugin` with the `bevy_animation`
+///   feature
+/// - `sprite_sheet::SpriteSheetPlugin` with the `sprite_sheet` feature
 pub struct DefaultTweenPlugins;
 
 impl PluginGroup for DefaultTweenPlugins {
     fn build(self) -> bevy::app::PluginGroupBuilder {
         #[allow(clippy::let_and_return)]
-        let group = PluginGroupBuilder::start::<DefaultTweenPlugins>()
-            .add(TweenCorePlugin::default())
-            .add(interpolate::DefaultInterpolatorsPlugin)
-            .add(interpolate::DefaultDynInterpolatorsPlugin)
-            .add(interpolation::EaseKindPlugin)
-            .add_group(tween_event::DefaultTweenEventPlugins);
+        let group =
+            PluginGroupBuilder::start::<DefaultTweenPlugins>()
+                .add(TweenCorePlugin::default())
+                .add(interpolate::DefaultInterpolatorsPlugin)
+                .add(interpolate::DefaultDynInterpolatorsPlugin)
+                .add(interpolation::EaseKindPlugin)
+                .add(interpolation::EaseCubicBezierPlugin)
+                .add(interpolation::EaseStepsPlugin)
+                .add(interpolation::EaseElasticPlugin)
+                .add(interpolation::EaseBackPlugin)
+                .add(interpolation::EaseSpringPlugin)
+                .add(interpolation::EaseHermitePlugin)
+                .add(interpolation::EaseLutPlugin)
+                .add(interpolation::EaseDirectionalPlugin::<
+                    interpolation::EaseKind,
+                >::default())
+                .add(interpolation::EaseModifiersPlugin::<
+                    interpolation::EaseKind,
+                >::default())
+                .add(playback::PlaybackPlugin)
+                .add(animator::AnimatorPlugin)
+                .add(span::SpanPlugin)
+                .add(timeline::TimelineEditPlugin)
+                .add(timeline::TimelineBakePlugin::<Transform>::default())
+                .add(tween::TargetPathPlugin)
+                .add(tween::TweenGroupPlugin)
+                .add_group(tween_event::DefaultTweenEventPlugins);
         #[cfg(feature = "bevy_lookup_curve")]
         let group = group.add(interpolation::bevy_lookup_curve::BevyLookupCurveInterpolationPlugin);
+        #[cfg(feature = "bevy_audio")]
+        let group = group.add(span::bevy_audio::AudioSpanPlugin);
+        #[cfg(feature = "tween_clip")]
+        let group = group.add(clip::TweenClipPlugin);
+        #[cfg(feature = "bevy_animation")]
+        let group = group.add(animation_clip::AnimationClipTweenPlugin);
+        #[cfg(feature = "sprite_sheet")]
+        let group = group.add(sprite_sheet::SpriteSheetPlugin);
         group
     }
 }
@@ -458,23 +551,48 @@ pub struct TweenAppResource {
     pub schedule: InternedScheduleLabel,
 }
 
-impl Default for TweenAppResource {
-    fn default() -> Self {
+impl TweenAppResource {
+    /// Create new [`TweenAppResource`] with tween systems in `schedule`.
+    pub fn new(schedule: impl ScheduleLabel) -> TweenAppResource {
         TweenAppResource {
-            schedule: PostUpdate.intern(),
+            schedule: schedule.intern(),
         }
     }
 }
 
+impl Default for TweenAppResource {
+    fn default() -> Self {
+        TweenAppResource::new(PostUpdate)
+    }
+}
+
 /// Configure [`TweenSystemSet`] and register types.
 ///
 /// [`TweenSystemSet`] configuration:
 /// - In schedule configured by [`TweenAppResource`]:
-///   1. [`UpdateInterpolationValue`],
-///   2. [`ApplyTween`],
+///   1. [`TickTimeRunner`],
+///   2. [`UpdateInterpolationValue`],
+///   3. [`ApplyTween`],
+///   4. [`BlendTween`],
+///   5. [`ApplyAdditiveTween`],
 ///
+///   [`TickTimeRunner`]: [`TweenSystemSet::TickTimeRunner`]
 ///   [`UpdateInterpolationValue`]: [`TweenSystemSet::UpdateInterpolationValue`]
 ///   [`ApplyTween`]: [`TweenSystemSet::ApplyTween`]
+///   [`BlendTween`]: [`TweenSystemSet::BlendTween`]
+///   [`ApplyAdditiveTween`]: [`TweenSystemSet::ApplyAdditiveTween`]
+///
+/// [`TweenSystemSet::TickTimeRunner`] is in `bevy_time_runner`'s
+/// [`TimeRunnerSet::TickTimer`] and [`playback::time_span_progress_system`],
+/// which uses the [`timeline::TimeSpanIndex`] maintained by
+/// [`timeline::time_span_index_system`], is in [`TimeRunnerSet::Progress`].
+/// These take the place of `bevy_time_runner`'s own systems. If
+/// [`bevy_time_runner::TimeRunnerPlugin`] is added as well, these are left out
+/// so time runners aren't ticked twice, and the playback components in
+/// [`playback`] such as [`playback::ManualTick`] have no effect.
+///
+/// [`TimeRunnerSet::TickTimer`]: bevy_time_runner::TimeRunnerSet::TickTimer
+/// [`TimeRunnerSet::Progress`]: bevy_time_runner::TimeRunnerSet::Progress
 #[derive(Default)]
 pub struct TweenCorePlugin {
     /// See [`TweenAppResource`]
@@ -483,23 +601,102 @@ pub struct TweenCorePlugin {
 
 impl Plugin for TweenCorePlugin {
     fn build(&self, app: &mut App) {
-        if !app.is_plugin_added::<bevy_time_runner::TimeRunnerPlugin>() {
-            app.add_plugins(bevy_time_runner::TimeRunnerPlugin {
-                schedule: self.app_resource.schedule,
-            });
+        if app.is_plugin_added::<bevy_time_runner::TimeRunnerPlugin>() {
+            app.insert_resource(TimeRunnerPluginAdded);
         }
         app.configure_sets(
             self.app_resource.schedule,
             (
-                TweenSystemSet::UpdateInterpolationValue,
-                TweenSystemSet::ApplyTween,
-            )
-                .chain()
-                .after(bevy_time_runner::TimeRunnerSet::Progress),
+                (
+                    bevy_time_runner::TimeRunnerSet::TickTimer,
+                    bevy_time_runner::TimeRunnerSet::Progress,
+                )
+                    .chain(),
+                TweenSystemSet::TickTimeRunner
+                    .in_set(bevy_time_runner::TimeRunnerSet::TickTimer),
+                (
+                    TweenSystemSet::UpdateInterpolationValue,
+                    TweenSystemSet::ResolveTarget,
+                    TweenSystemSet::ApplyTween,
+                    TweenSystemSet::BlendTween,
+                    TweenSystemSet::ApplyAdditiveTween,
+                )
+                    .chain()
+                    .after(bevy_time_runner::TimeRunnerSet::Progress),
+            ),
         )
+        .add_systems(
+            self.app_resource.schedule,
+            (
+                (
+                    playback::tick_time_runner_system,
+                    playback::nested_time_runner_system,
+                )
+                    .chain()
+                    .in_set(TweenSystemSet::TickTimeRunner)
+                    .run_if(not(resource_exists::<TimeRunnerPluginAdded>)),
+                (
+                    playback::time_span_progress_system,
+                    playback::settle_completed_time_runner_system,
+                )
+                    .chain()
+                    .in_set(bevy_time_runner::TimeRunnerSet::Progress)
+                    .run_if(not(resource_exists::<TimeRunnerPluginAdded>)),
+                timeline::time_span_index_system
+                    .after(tween::time_runner_of_system)
+                    .before(TweenSystemSet::TickTimeRunner),
+                tween::prepare_additive_tween_writes_system
+                    .after(TweenSystemSet::ResolveTarget)
+                    .before(TweenSystemSet::ApplyTween),
+                tween::prepare_tween_field_writes_system
+                    .after(TweenSystemSet::ResolveTarget)
+                    .before(TweenSystemSet::ApplyTween),
+                tween::time_runner_of_system
+                    .before(TweenSystemSet::TickTimeRunner),
+            ),
+        )
+        .init_resource::<tween::AdditiveTweenWrites>()
+        .init_resource::<tween::TweenFieldWrites>()
         .insert_resource(self.app_resource.clone())
+        .init_resource::<combinator::AnimationLibrary>()
         .register_type::<tween::AnimationTarget>()
-        .register_type::<tween::TweenInterpolationValue>();
+        .register_type::<tween::TweenInterpolationValue>()
+        .register_type::<playback::ManualTick>()
+        .register_type::<playback::MaxDelta>()
+        .register_type::<playback::PropagatePlayback>()
+        .register_type::<playback::NestedTimeRunner>()
+        .register_type::<playback::TimeRemap>()
+        .register_type::<playback::TimeWarp>()
+        .register_type::<tween::TargetLostPolicy>()
+        .register_type::<tween::ConflictPolicy>()
+        .register_type::<tween::ResolvedTargets>()
+        .register_type::<tween::TimeRunnerOf>()
+        .register_type::<tween::TimeRunnerSpans>()
+        .register_type::<tween::SkipTween>()
+        .register_type::<tween::Additive>()
+        .add_event::<tween::TweenTargetLost>()
+        .add_event::<bevy_time_runner::TimeRunnerEnded>()
+        .register_type::<bevy_time_runner::TimeRunner>()
+        .register_type::<bevy_time_runner::SkipTimeRunner>()
+        .register_type::<bevy_time_runner::TimeRunnerElasped>()
+        .register_type::<bevy_time_runner::TimeRunnerEnded>()
+        .register_type::<bevy_time_runner::TimeSpan>()
+        .register_type::<bevy_time_runner::TimeSpanProgress>()
+        .register_type::<bevy_time_runner::Repeat>()
+        .register_type::<bevy_time_runner::RepeatStyle>()
+        .register_type::<bevy_time_runner::TimeBound>()
+        .register_type::<bevy_time_runner::TimeDirection>();
+    }
+
+    fn finish(&self, app: &mut App) {
+        if app.is_plugin_added::<bevy_time_runner::TimeRunnerPlugin>() {
+            warn!(
+                "`TimeRunnerPlugin` is added next to `TweenCorePlugin` so it \
+                ticks time runners instead, without the playback components \
+                such as `ManualTick`"
+            );
+            app.insert_resource(TimeRunnerPluginAdded);
+        }
     }
 
     fn cleanup(&self, app: &mut App) {
@@ -507,14 +704,27 @@ impl Plugin for TweenCorePlugin {
     }
 }
 
+/// Inserted if [`bevy_time_runner::TimeRunnerPlugin`] is added next to
+/// [`TweenCorePlugin`], which then leaves ticking to it.
+#[derive(Resource)]
+struct TimeRunnerPluginAdded;
+
 /// Enum of SystemSet in this crate.
 /// See [`TweenCorePlugin`] for default system configuration.
 #[derive(Debug, SystemSet, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TweenSystemSet {
+    /// This set is for systems that responsible for ticking any
+    /// [`TimeRunner`](bevy_time_runner::TimeRunner) such as
+    /// [`playback::tick_time_runner_system`].
+    TickTimeRunner,
     /// This set is for systems that responsible for updating any
     /// [`tween::TweenInterpolationValue`] such as
     /// [`interpolation::sample_interpolations_system`].
     UpdateInterpolationValue,
+    /// This set is for systems that responsible for updating any
+    /// [`tween::ResolvedTargets`] such as
+    /// [`tween::resolve_target_marker_system`].
+    ResolveTarget,
     /// This set is for systems that responsible for actually executing any
     /// active tween and setting the value to its respective tweening item such
     /// as these systems:
@@ -525,6 +735,14 @@ pub enum TweenSystemSet {
     /// Events is not necessary related to tweening but their code is still working in the same area.
     /// - [`tween::tween_event_system`]
     ApplyTween,
+    /// This set is for systems that blend tweens played with
+    /// [`playback::BlendWeight`] such as
+    /// [`tween::blend_component_tween_system`].
+    BlendTween,
+    /// This set is for systems that apply [`tween::Additive`] tweens on top
+    /// of the values written in [`TweenSystemSet::ApplyTween`] such as
+    /// [`tween::additive_component_tween_system`].
+    ApplyAdditiveTween,
 }
 
 /// Helper trait to add systems by this crate to your app and avoid mistake
@@ -535,6 +753,18 @@ pub trait BevyTweenRegisterSystems {
         &mut self,
         tween_systems: impl IntoSystemConfigs<M>,
     ) -> &mut Self;
+
+    /// Register blend tween systems of the interpolator `I`
+    fn add_blend_tween_systems<I: 'static, M>(
+        &mut self,
+        tween_systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self;
+
+    /// Register additive tween systems
+    fn add_additive_tween_systems<M>(
+        &mut self,
+        tween_systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self;
 }
 
 impl BevyTweenRegisterSystems for App {
@@ -557,4 +787,48 @@ impl BevyTweenRegisterSystems for App {
             tween_systems.in_set(TweenSystemSet::ApplyTween),
         )
     }
+
+    /// Register blend tween systems of the interpolator `I` in schedule
+    /// configured in [`TweenAppResource`] in set
+    /// [`TweenSystemSet::BlendTween`] and insert
+    /// [`tween::BlendTweenRegistered<I>`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    fn add_blend_tween_systems<I: 'static, M>(
+        &mut self,
+        tween_systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        let app_resource = self
+            .world()
+            .get_resource::<TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        let schedule = app_resource.schedule;
+        self.init_resource::<tween::BlendTweenRegistered<I>>();
+        self.add_systems(
+            schedule,
+            tween_systems.in_set(TweenSystemSet::BlendTween),
+        )
+    }
+
+    /// Register additive tween systems in schedule configured in
+    /// [`TweenAppResource`] in set [`TweenSystemSet::ApplyAdditiveTween`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    fn add_additive_tween_systems<M>(
+        &mut self,
+        tween_systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        let app_resource = self
+            .world()
+            .get_resource::<TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        self.add_systems(
+            app_resource.schedule,
+            tween_systems.in_set(TweenSystemSet::ApplyAdditiveTween),
+        )
+    }
 }
@@ -0,0 +1,2061 @@
+//! Module containing playback control for [`TimeRunner`]
+//!
+//! # Playback
+//!
+//! **Plugins**:
+//! - [`PlaybackPlugin`]
+//!
+//! **Components**:
+//! - [`ManualTick`]
+//! - [`MaxDelta`]
+//! - [`PropagatePlayback`]
+//! - [`AnimationQueue`]
+//! - [`OnEndPlay`]
+//! - [`OnEndRunSystem`]
+//! - [`CompletionPolicy`]
+//! - [`EmitProgress`]
+//! - [`BlendWeight`]
+//! - [`BlendWeightFade`]
+//! - [`NestedTimeRunner`]
+//! - [`TimeRemap`]
+//! - [`TimeWarp`]
+//!
+//! **Commands**:
+//! - [`Crossfade`]
+//!
+//! **Systems**:
+//! - [`tick_time_runner_system`]
+//! - [`nested_time_runner_system`]
+//! - [`time_span_progress_system`]
+//! - [`animation_queue_system`]
+//! - [`on_end_play_system`]
+//! - [`on_end_run_system_system`]
+//! - [`completion_policy_system`]
+//! - [`time_span_ended_system`]
+//! - [`time_span_crossed_system`]
+//! - [`on_time_runner_ended_system`]
+//! - [`emit_progress_system`]
+//! - [`time_runner_lifecycle_system`]
+//! - [`blend_weight_fade_system`]
+//!
+//! **Events**:
+//! - [`TimeSpanEnded`]
+//! - [`TimeSpanCrossed`]
+//! - [`OnTimeRunnerEnded`]
+//! - [`TimeRunnerProgress`]
+//! - [`TimeRunnerStarted`]
+//! - [`TimeRunnerLooped`]
+//! - [`TimeRunnerPaused`]
+//! - [`TimeRunnerResumed`]
+//!
+//! **Extensions**:
+//! - [`TimeRunnerExt`]
+//!
+//! Every [`TimeRunner`] is ticked by this crate in
+//! [`TweenSystemSet::TickTimeRunner`](crate::TweenSystemSet::TickTimeRunner)
+//! in place of `bevy_time_runner`'s own ticking so that the components in this
+//! module can change how an animation progresses. The [`TimeSpanProgress`]
+//! of the spans is then updated by [`time_span_progress_system`] from the
+//! [`TimeSpanIndex`] of each time runner. Both run in
+//! [`TimeRunnerSet`](bevy_time_runner::TimeRunnerSet) so systems ordered
+//! against `bevy_time_runner` still work. If
+//! [`TimeRunnerPlugin`](bevy_time_runner::TimeRunnerPlugin) is added as well,
+//! it ticks the time runners instead and the components in this module have
+//! no effect.
+//!
+//! ## Manual ticking
+//!
+//! Insert [`ManualTick`] to the time runner entity or use
+//! [`AnimationBuilder::manual_tick`](crate::combinator::AnimationBuilder::manual_tick)
+//! then queue the time to advance it by with [`ManualTick::tick`].
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::playback::ManualTick;
+//!
+//! #[derive(Resource)]
+//! struct SnapshotDelta(f32);
+//!
+//! fn drive_from_snapshot(
+//!     snapshot: Res<SnapshotDelta>,
+//!     mut q_manual_tick: Query<&mut ManualTick>,
+//! ) {
+//!     for mut manual_tick in &mut q_manual_tick {
+//!         manual_tick.tick(snapshot.0);
+//!     }
+//! }
+//! ```
+//!
+//! ## Observing completion
+//!
+//! [`OnTimeRunnerEnded`] is triggered on the time runner entity so it can be
+//! observed right where the animation is spawned.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{combinator::forward, playback::OnTimeRunnerEnded, prelude::*};
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands
+//!         .animation()
+//!         .insert(forward(Duration::from_secs(1)))
+//!         .observe(|trigger: Trigger<OnTimeRunnerEnded>| {
+//!             if trigger.is_completed() {
+//!                 println!("done!");
+//!             }
+//!         });
+//! }
+//! ```
+//!
+//! ## Saving playback state
+//!
+//! [`TimeRunnerExt::snapshot`] captures the state of a playing time runner
+//! into a [`TimeRunnerSnapshot`], which is serializable with the `serde`
+//! feature, to be restored later with [`TimeRunnerExt::restore`].
+
+use std::{cmp::Ordering, time::Duration};
+
+#[cfg(feature = "bevy_state")]
+pub mod bevy_state;
+
+use bevy::{
+    ecs::{entity::MapEntities, reflect::ReflectMapEntities, system::SystemId},
+    prelude::*,
+    utils::{HashMap, HashSet, Parallel},
+};
+use bevy_time_runner::{
+    Repeat, RepeatStyle, SkipTimeRunner, TimeBound, TimeDirection, TimeRunner,
+    TimeRunnerEnded, TimeSpan, TimeSpanProgress,
+};
+
+use crate::{
+    interpolation::EaseKind,
+    timeline::TimeSpanIndex,
+    tween::{TimeRunnerOf, TimeRunnerSpans, TweenInterpolationValue},
+    TweenSystemSet,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Plugin for the playback components that react to a [`TimeRunner`]
+/// ending.
+///
+/// Systems are added after [`TweenSystemSet::ApplyTween`] so tweens are
+/// applied with their final value first.
+pub struct PlaybackPlugin;
+
+impl Plugin for PlaybackPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                (
+                    time_span_ended_system,
+                    time_span_crossed_system,
+                    on_time_runner_ended_system,
+                    emit_progress_system,
+                    time_runner_lifecycle_system,
+                )
+                    .in_set(TweenSystemSet::ApplyTween),
+                (
+                    animation_queue_system,
+                    on_end_play_system,
+                    on_end_run_system_system,
+                    completion_policy_system,
+                )
+                    .after(TweenSystemSet::ApplyTween),
+                blend_weight_fade_system.before(TweenSystemSet::TickTimeRunner),
+            ),
+        )
+        .add_event::<TimeSpanEnded>()
+        .add_event::<TimeSpanCrossed>()
+        .add_event::<OnTimeRunnerEnded>()
+        .add_event::<TimeRunnerProgress>()
+        .add_event::<TimeRunnerStarted>()
+        .add_event::<TimeRunnerLooped>()
+        .add_event::<TimeRunnerPaused>()
+        .add_event::<TimeRunnerResumed>()
+        .register_type::<AnimationQueue>()
+        .register_type::<OnEndPlay>()
+        .register_type::<BlendWeight>()
+        .register_type::<BlendWeightFade>()
+        .register_type::<CompletionPolicy>()
+        .register_type::<EmitProgress>()
+        .register_type::<TimeRunnerSnapshot>();
+    }
+}
+
+/// Tick this [`TimeRunner`] by the time queued with [`ManualTick::tick`]
+/// instead of the [`Time`] of the running schedule.
+///
+/// The queued time is ticked by [`tick_time_runner_system`] like any other
+/// delta so the time scale, [`MaxDelta`], and [`TimeRemap`] apply and
+/// [`TimeRunnerEnded`] is sent. Time queued while the time runner or an
+/// ancestor with [`PropagatePlayback`] is paused, or while it's completed,
+/// is dropped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct ManualTick {
+    /// Seconds queued since the last tick
+    delta: f32,
+}
+
+impl ManualTick {
+    /// Queue `secs` to be ticked in the next run of
+    /// [`tick_time_runner_system`]
+    pub fn tick(&mut self, secs: f32) {
+        self.delta += secs;
+    }
+}
+
+/// Limit how far [`tick_time_runner_system`] can advance a [`TimeRunner`]
+/// in a single tick so a long frame such as a loading hitch can't skip
+/// through an animation.
+///
+/// The limit is checked against the frame's delta before the time scale
+/// is applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Component)]
+pub struct MaxDelta {
+    /// Maximum delta allowed per tick
+    pub max: Duration,
+    /// What to do when the delta exceeds [`Self::max`]
+    pub mode: MaxDeltaMode,
+}
+
+impl MaxDelta {
+    /// Create new [`MaxDelta`] with [`MaxDeltaMode::Clamp`]
+    pub fn clamp(max: Duration) -> MaxDelta {
+        MaxDelta {
+            max,
+            mode: MaxDeltaMode::Clamp,
+        }
+    }
+
+    /// Create new [`MaxDelta`] with [`MaxDeltaMode::Skip`]
+    pub fn skip(max: Duration) -> MaxDelta {
+        MaxDelta {
+            max,
+            mode: MaxDeltaMode::Skip,
+        }
+    }
+
+    /// Returns the delta in seconds that should be used after applying this limit.
+    pub fn limit(&self, secs: f32) -> f32 {
+        let max = self.max.as_secs_f32();
+        if secs <= max {
+            return secs;
+        }
+        match self.mode {
+            MaxDeltaMode::Clamp => max,
+            MaxDeltaMode::Skip => 0.,
+        }
+    }
+}
+
+/// What [`MaxDelta`] does with a delta exceeding the limit
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum MaxDeltaMode {
+    /// Advance by the limit and drop the excess
+    #[default]
+    Clamp,
+    /// Drop the whole delta and don't advance in this tick
+    Skip,
+}
+
+/// Cascade the pause state and time scale of this entity's [`TimeRunner`]
+/// to every [`TimeRunner`] descendant.
+///
+/// A descendant doesn't advance while any propagating ancestor is paused,
+/// and its time scale is multiplied by every propagating ancestor's time
+/// scale. Only ticking by [`tick_time_runner_system`] is affected.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct PropagatePlayback;
+
+/// Drive this [`TimeRunner`] by its parent [`TimeRunner`]'s time within
+/// `span` instead of ticking it, to embed a reusable sub-timeline.
+///
+/// The nested time runner's time is the parent's time since the start of
+/// `span`, held at the span's bounds outside of it. If the nested time
+/// runner repeats, it loops within the span with its [`RepeatStyle`] for as
+/// many times as its [`Repeat`] allows then holds at its end.
+/// See also [`nested`](crate::combinator::nested).
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct NestedTimeRunner {
+    /// Span of the parent's time that drives this time runner
+    pub span: TimeSpan,
+    /// Iteration the time runner was at as of the last update
+    iteration: i32,
+}
+
+impl NestedTimeRunner {
+    /// Create new [`NestedTimeRunner`]
+    pub fn new(span: TimeSpan) -> NestedTimeRunner {
+        NestedTimeRunner { span, iteration: 0 }
+    }
+}
+
+/// Play the window of this span over `duration` instead of its own length,
+/// affecting every other span of the parent [`TimeRunner`] within it.
+///
+/// For example a window of 1 second with a `duration` of 2 seconds is a 2x
+/// slow motion region, and a zero length window such as `pos..=pos` with a
+/// `duration` of 1 second freezes the animation at `pos` for 1 second.
+///
+/// Applied by [`tick_time_runner_system`] only while the [`TimeRunner`] is
+/// playing forward.
+/// See also [`time_remap`](crate::combinator::time_remap) and
+/// [`freeze`](crate::combinator::freeze).
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TimeRemap {
+    /// Duration to play the window of the span over
+    pub duration: Duration,
+    /// Time held so far for a zero length window
+    held: f32,
+}
+
+impl TimeRemap {
+    /// Create new [`TimeRemap`]
+    pub fn new(duration: Duration) -> TimeRemap {
+        TimeRemap { duration, held: 0. }
+    }
+}
+
+/// Warp the whole timeline of this entity's [`TimeRunner`] by easing its
+/// elapsed time over its length before spans are evaluated, such as
+/// [`EaseKind::QuadraticIn`] for a global slow start.
+///
+/// The ease is applied to every iteration in the direction it's played, so
+/// a [`RepeatStyle::PingPong`] time runner plays back through the same warp.
+/// The ease should go from `0.` to `1.` without overshooting or going back.
+///
+/// Applied by [`time_span_progress_system`] and to the time of
+/// [`NestedTimeRunner`]s inside. The elapsed time of the time runner itself
+/// isn't warped so repeats, [`TimeRemap`] windows, and events are by the
+/// unwarped time.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct TimeWarp {
+    /// Ease applied to the elapsed time over the length of the time runner
+    pub ease: EaseKind,
+}
+
+impl TimeWarp {
+    /// Create new [`TimeWarp`]
+    pub fn new(ease: EaseKind) -> TimeWarp {
+        TimeWarp { ease }
+    }
+
+    fn warp(&self, secs: f32, length: f32) -> f32 {
+        if length <= 0. {
+            return secs;
+        }
+        self.ease.sample(secs / length).clamp(0., 1.) * length
+    }
+}
+
+/// Convert `delta` of real time into the delta of the time runner's time
+/// from `now` with the `(start, end, remap)` windows.
+fn remap_delta(
+    now: f32,
+    delta: f32,
+    remaps: &mut [(f32, f32, Mut<TimeRemap>)],
+) -> f32 {
+    for (start, _, remap) in remaps.iter_mut() {
+        if *start > now && remap.held != 0. {
+            remap.held = 0.;
+        }
+    }
+    let mut t = now;
+    let mut real = delta;
+    while real > 0. {
+        // Zero length window holding at `t`
+        if let Some((_, _, remap)) =
+            remaps.iter_mut().find(|(start, end, r)| {
+                *start == t && *end == t && r.held < r.duration.as_secs_f32()
+            })
+        {
+            let hold = remap.duration.as_secs_f32() - remap.held;
+            let take = real.min(hold);
+            remap.held += take;
+            real -= take;
+            continue;
+        }
+        // Window containing `t` played at the speed of its remap
+        if let Some((start, end, remap)) = remaps
+            .iter()
+            .find(|(start, end, _)| *start <= t && t < *end)
+        {
+            let duration = remap.duration.as_secs_f32();
+            if duration <= 0. {
+                t = *end;
+                continue;
+            }
+            let speed = (end - start) / duration;
+            let needed = (end - t) / speed;
+            if real >= needed {
+                t = *end;
+                real -= needed;
+            } else {
+                t += real * speed;
+                real = 0.;
+            }
+            continue;
+        }
+        // Play at normal speed until the next window
+        let next = remaps
+            .iter()
+            .filter(|(start, ..)| *start > t)
+            .map(|(start, ..)| *start)
+            .fold(f32::INFINITY, f32::min);
+        if real >= next - t {
+            real -= next - t;
+            t = next;
+        } else {
+            t += real;
+            real = 0.;
+        }
+    }
+    t - now
+}
+
+/// Weight of this [`TimeRunner`]'s tweens when blended with other
+/// [`TimeRunner`]s' tweens on the same target.
+///
+/// Tweens played by a [`TimeRunner`] with this component are applied by
+/// [`blend_component_tween_system`](crate::tween::blend_component_tween_system)
+/// instead of overwriting the target, so the interpolator must be
+/// registered with it.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct BlendWeight(pub f32);
+
+impl Default for BlendWeight {
+    fn default() -> Self {
+        BlendWeight(1.)
+    }
+}
+
+/// Fade this [`TimeRunner`]'s [`BlendWeight`] from `start` to `end` over
+/// `duration`. Removed when finished.
+///
+/// If `end` is `0.`, the [`TimeRunner`] is paused when the fade is finished.
+/// If `end` is `1.` or more, [`BlendWeight`] is removed as well so the
+/// tweens are applied without blending again.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct BlendWeightFade {
+    /// Weight at the start of the fade
+    pub start: f32,
+    /// Weight at the end of the fade
+    pub end: f32,
+    /// Duration of the fade
+    pub duration: Duration,
+    elapsed: Duration,
+}
+
+impl BlendWeightFade {
+    /// Create new [`BlendWeightFade`]
+    pub fn new(start: f32, end: f32, duration: Duration) -> BlendWeightFade {
+        BlendWeightFade {
+            start,
+            end,
+            duration,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Time elapsed since the fade started
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Crossfade from one [`TimeRunner`] to another by fading their
+/// [`BlendWeight`] with [`BlendWeightFade`].
+///
+/// The incoming time runner is unpaused and the outgoing one is paused once
+/// its weight reaches `0.`. Fading starts from the current [`BlendWeight`]
+/// of each if any so an interrupted crossfade continues smoothly, otherwise
+/// from `1.` for `from` and `0.` for `to`.
+///
+/// The tweens are only blended by their weight with a blend system of their
+/// interpolator, such as from
+/// [`BlendInterpolatorsPlugin`](crate::interpolate::BlendInterpolatorsPlugin).
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::{playback::crossfade, prelude::*};
+///
+/// fn switch(mut commands: Commands, walk: Entity, run: Entity) {
+///     commands.queue(crossfade(walk, run, Duration::from_millis(300)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crossfade {
+    /// Outgoing time runner
+    pub from: Entity,
+    /// Incoming time runner
+    pub to: Entity,
+    /// Duration of the crossfade
+    pub duration: Duration,
+}
+
+impl Command for Crossfade {
+    fn apply(self, world: &mut World) {
+        if let Ok(mut from) = world.get_entity_mut(self.from) {
+            let start = from.get::<BlendWeight>().map(|w| w.0).unwrap_or(1.);
+            from.insert((
+                BlendWeight(start),
+                BlendWeightFade::new(start, 0., self.duration),
+            ));
+        }
+        let Ok(mut to) = world.get_entity_mut(self.to) else {
+            return;
+        };
+        let start = to.get::<BlendWeight>().map(|w| w.0).unwrap_or(0.);
+        to.insert((
+            BlendWeight(start),
+            BlendWeightFade::new(start, 1., self.duration),
+        ));
+        if let Some(mut time_runner) = to.get_mut::<TimeRunner>() {
+            time_runner.set_paused(false);
+        }
+    }
+}
+
+/// Constructor for [`Crossfade`]
+pub fn crossfade(from: Entity, to: Entity, duration: Duration) -> Crossfade {
+    Crossfade { from, to, duration }
+}
+
+/// Play [`TimeRunner`]s strictly one after another.
+///
+/// Every time runner in the queue except the current one is paused.
+/// When the current time runner completes, the next one is restarted and
+/// unpaused by [`animation_queue_system`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct AnimationQueue {
+    runners: Vec<Entity>,
+    current: usize,
+}
+
+impl MapEntities for AnimationQueue {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.runners.map_entities(entity_mapper);
+    }
+}
+
+impl AnimationQueue {
+    /// Create new [`AnimationQueue`] starting from the first time runner.
+    pub fn new<I>(runners: I) -> AnimationQueue
+    where
+        I: IntoIterator<Item = Entity>,
+    {
+        AnimationQueue {
+            runners: runners.into_iter().collect(),
+            current: 0,
+        }
+    }
+
+    /// Add a time runner to the end of the queue.
+    pub fn push(&mut self, runner: Entity) -> &mut Self {
+        self.runners.push(runner);
+        self
+    }
+
+    /// Time runners in the queue.
+    pub fn runners(&self) -> &[Entity] {
+        &self.runners
+    }
+
+    /// The time runner that's currently playing. `None` if the queue is
+    /// finished.
+    pub fn current(&self) -> Option<Entity> {
+        self.runners.get(self.current).copied()
+    }
+
+    /// Returns true if every time runner in the queue has completed.
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.runners.len()
+    }
+}
+
+/// Restart and unpause another [`TimeRunner`] entity when the
+/// [`TimeRunner`] of this entity completes.
+///
+/// Chain multiple of these to build a simple animation graph such as
+/// intro, loop, then outro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct OnEndPlay(pub Entity);
+
+impl MapEntities for OnEndPlay {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// Fires when a [`TimeRunner`] reaches the end of a span in the direction
+/// it's currently playing, by [`time_span_ended_system`].
+///
+/// This event is also triggered on the span entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event, Reflect)]
+pub struct TimeSpanEnded {
+    /// The span entity that ended
+    pub span: Entity,
+    /// The time runner the span belongs to by its [`TimeRunnerOf`]
+    pub time_runner: Entity,
+    /// Direction the span was played in
+    pub direction: TimeDirection,
+}
+
+/// Fires when a [`TimeRunner`]'s elapsed time enters or leaves a span, by
+/// [`time_span_crossed_system`].
+///
+/// This event is also triggered on the span entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event, Reflect)]
+pub struct TimeSpanCrossed {
+    /// The span entity that was crossed
+    pub span: Entity,
+    /// The time runner the span belongs to by its [`TimeRunnerOf`]
+    pub time_runner: Entity,
+    /// Whether the span was entered or exited
+    pub crossing: SpanCrossing,
+    /// Direction the elapsed time was moving when crossing
+    pub direction: TimeDirection,
+}
+
+/// Kind of [`TimeSpanCrossed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SpanCrossing {
+    /// The elapsed time moved into the span
+    Entered,
+    /// The elapsed time moved out of the span
+    Exited,
+}
+
+/// Run a registered one-shot system when the [`TimeRunner`] of this entity
+/// completes.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::{combinator::forward, playback::OnEndRunSystem, prelude::*};
+///
+/// fn setup(mut commands: Commands) {
+///     let on_end = commands.register_system(|| println!("done!"));
+///     commands
+///         .animation()
+///         .insert(forward(Duration::from_secs(1)))
+///         .insert(OnEndRunSystem(on_end));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub struct OnEndRunSystem(pub SystemId);
+
+/// What to do with a [`TimeRunner`] entity once it completes, enforced by
+/// [`completion_policy_system`].
+///
+/// Use this for one-shot animations so finished entities don't pile up.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect,
+)]
+#[reflect(Component)]
+pub enum CompletionPolicy {
+    /// Leave the time runner as is
+    #[default]
+    Keep,
+    /// Pause the time runner
+    KeepPaused,
+    /// Remove the [`TimeRunner`] component so the animation stops being
+    /// processed while the entity and its spans stay
+    RemoveTimeRunner,
+    /// Remove [`TimeSpan`], [`TimeSpanProgress`], and
+    /// [`TweenInterpolationValue`] from every span of the time runner found
+    /// by its [`TimeRunnerSpans`] so its tweens stop being applied while the
+    /// entities stay. The tween and interpolation components of the spans
+    /// are kept.
+    StripSpans,
+    /// Despawn every span of the time runner found by its
+    /// [`TimeRunnerSpans`]. What's below a span that isn't one of them, such
+    /// as meshes or nested time runners, is moved up to the closest ancestor
+    /// that stays.
+    DespawnSpans,
+    /// Despawn the time runner entity and all of its descendants
+    DespawnRecursive,
+}
+
+/// Send [`TimeRunnerProgress`] whenever the progress of this entity's
+/// [`TimeRunner`] moved by at least [`Self::every`] since the last one,
+/// by [`emit_progress_system`].
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct EmitProgress {
+    /// Minimum change of progress between events, from `0.` to `1.`
+    pub every: f32,
+    last: Option<f32>,
+}
+
+impl EmitProgress {
+    /// Create new [`EmitProgress`]
+    pub fn new(every: f32) -> EmitProgress {
+        EmitProgress { every, last: None }
+    }
+}
+
+/// Fires with the progress of a [`TimeRunner`] with [`EmitProgress`]
+#[derive(Debug, Clone, Copy, PartialEq, Event, Reflect)]
+pub struct TimeRunnerProgress {
+    /// The time runner entity
+    pub time_runner: Entity,
+    /// Elapsed time over length of the time runner, from `0.` to `1.`
+    pub fraction: f32,
+}
+
+/// Fires when a [`TimeRunner`] first advances from its start, and again
+/// once it's played after completing or resting at its start, by
+/// [`time_runner_lifecycle_system`].
+///
+/// This event is also triggered on the time runner entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event, Reflect)]
+pub struct TimeRunnerStarted {
+    /// The time runner entity
+    pub time_runner: Entity,
+}
+
+/// Fires when a [`TimeRunner`] finishes an iteration and is going to
+/// repeat, by [`time_runner_lifecycle_system`].
+///
+/// This event is also triggered on the time runner entity.
+#[derive(Debug, Clone, Copy, PartialEq, Event, Reflect)]
+pub struct TimeRunnerLooped {
+    /// The time runner entity
+    pub time_runner: Entity,
+    /// Repeat state of the time runner before the loop
+    pub repeat: Option<Repeat>,
+}
+
+/// Fires when a [`TimeRunner`] gets paused, by
+/// [`time_runner_lifecycle_system`].
+///
+/// This event is also triggered on the time runner entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event, Reflect)]
+pub struct TimeRunnerPaused {
+    /// The time runner entity
+    pub time_runner: Entity,
+}
+
+/// Fires when a paused [`TimeRunner`] gets unpaused, by
+/// [`time_runner_lifecycle_system`].
+///
+/// This event is also triggered on the time runner entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event, Reflect)]
+pub struct TimeRunnerResumed {
+    /// The time runner entity
+    pub time_runner: Entity,
+}
+
+/// Fires whenever [`TimeRunnerEnded`] is sent, by
+/// [`on_time_runner_ended_system`].
+///
+/// This event is also triggered on the time runner entity.
+#[derive(Debug, Clone, Copy, PartialEq, Event, Reflect)]
+pub struct OnTimeRunnerEnded {
+    /// The time runner entity
+    pub time_runner: Entity,
+    /// Direction the time runner was playing in when it ended
+    pub current_direction: TimeDirection,
+    /// Repeat state of the time runner when it ended
+    pub with_repeat: Option<Repeat>,
+    /// Index of the iteration that ended counting from `0`, or `None` for
+    /// [`Repeat::Infinitely`] which doesn't count its repeats
+    pub iteration: Option<i32>,
+}
+
+impl OnTimeRunnerEnded {
+    /// Returns true if the time runner is not going to repeat anymore.
+    pub fn is_completed(&self) -> bool {
+        self.with_repeat
+            .map(|repeat| repeat.exhausted())
+            .unwrap_or(true)
+    }
+}
+
+/// Playback state of a [`TimeRunner`] from [`TimeRunnerExt::snapshot`] to be
+/// restored with [`TimeRunnerExt::restore`], such as across save and load.
+///
+/// The length is not included since it's determined by the spans.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct TimeRunnerSnapshot {
+    /// Elapsed time in seconds
+    pub elapsed: f32,
+    /// Playing backward
+    pub backward: bool,
+    #[allow(missing_docs)]
+    pub time_scale: f32,
+    #[allow(missing_docs)]
+    pub paused: bool,
+    #[allow(missing_docs)]
+    pub repeat: Option<RepeatSnapshot>,
+}
+
+/// Repeat state of a [`TimeRunnerSnapshot`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct RepeatSnapshot {
+    /// Times to repeat, `None` to repeat infinitely
+    pub times: Option<i32>,
+    /// Times already repeated, `None` if not counted
+    pub times_repeated: Option<i32>,
+    /// Ping pong instead of wrapping around
+    pub ping_pong: bool,
+}
+
+impl RepeatSnapshot {
+    /// Repeats remaining, `None` if repeating infinitely
+    pub fn remaining(&self) -> Option<i32> {
+        self.times
+            .map(|times| (times - self.times_repeated.unwrap_or(0)).max(0))
+    }
+}
+
+impl From<(Repeat, RepeatStyle)> for RepeatSnapshot {
+    fn from((repeat, repeat_style): (Repeat, RepeatStyle)) -> Self {
+        let (times, times_repeated) = match repeat {
+            Repeat::Infinitely => (None, None),
+            Repeat::InfinitelyCounted { times_repeated } => {
+                (None, Some(times_repeated))
+            }
+            Repeat::Times {
+                times,
+                times_repeated,
+            } => (Some(times), Some(times_repeated)),
+        };
+        RepeatSnapshot {
+            times,
+            times_repeated,
+            ping_pong: repeat_style == RepeatStyle::PingPong,
+        }
+    }
+}
+
+impl From<RepeatSnapshot> for (Repeat, RepeatStyle) {
+    fn from(snapshot: RepeatSnapshot) -> Self {
+        let repeat = match (snapshot.times, snapshot.times_repeated) {
+            (Some(times), times_repeated) => Repeat::Times {
+                times,
+                times_repeated: times_repeated.unwrap_or(0),
+            },
+            (None, Some(times_repeated)) => {
+                Repeat::InfinitelyCounted { times_repeated }
+            }
+            (None, None) => Repeat::Infinitely,
+        };
+        let repeat_style = if snapshot.ping_pong {
+            RepeatStyle::PingPong
+        } else {
+            RepeatStyle::WrapAround
+        };
+        (repeat, repeat_style)
+    }
+}
+
+/// Extension trait for [`TimeRunner`]
+pub trait TimeRunnerExt {
+    /// Rewind this time runner to the start of its current direction and
+    /// reset its repeat counter.
+    fn restart(&mut self);
+
+    /// Capture the elapsed time, direction, time scale, pause state, and
+    /// repeat counter of this time runner.
+    fn snapshot(&self) -> TimeRunnerSnapshot;
+
+    /// Restore the state captured by [`TimeRunnerExt::snapshot`].
+    fn restore(&mut self, snapshot: &TimeRunnerSnapshot);
+}
+
+impl TimeRunnerExt for TimeRunner {
+    fn restart(&mut self) {
+        let start = match self.direction() {
+            TimeDirection::Forward => 0.,
+            TimeDirection::Backward => self.length().as_secs_f32(),
+        };
+        self.set_tick(start);
+        if let Some((repeat, repeat_style)) = self.repeat() {
+            let repeat = match repeat {
+                Repeat::Infinitely => Repeat::Infinitely,
+                Repeat::InfinitelyCounted { .. } => {
+                    Repeat::infinitely_counted()
+                }
+                Repeat::Times { times, .. } => Repeat::times(times),
+            };
+            self.set_repeat(Some((repeat, repeat_style)));
+        }
+    }
+
+    fn snapshot(&self) -> TimeRunnerSnapshot {
+        TimeRunnerSnapshot {
+            elapsed: self.elasped().now(),
+            backward: self.direction() == TimeDirection::Backward,
+            time_scale: self.time_scale(),
+            paused: self.paused(),
+            repeat: self.repeat().map(RepeatSnapshot::from),
+        }
+    }
+
+    fn restore(&mut self, snapshot: &TimeRunnerSnapshot) {
+        let direction = if snapshot.backward {
+            TimeDirection::Backward
+        } else {
+            TimeDirection::Forward
+        };
+        self.set_direction(direction);
+        self.set_time_scale(snapshot.time_scale);
+        self.set_paused(snapshot.paused);
+        self.set_repeat(snapshot.repeat.map(Into::into));
+        self.set_tick(snapshot.elapsed);
+    }
+}
+
+/// Tick every [`TimeRunner`] that's not paused or completed using the default
+/// [`Time`] of the running schedule, or by the time queued in its
+/// [`ManualTick`]. Like `bevy_time_runner`, [`SkipTimeRunner`] doesn't stop
+/// the time from advancing, it only skips [`time_span_progress_system`].
+/// The delta is limited by [`MaxDelta`] if exists and is affected by
+/// ancestors with [`PropagatePlayback`].
+///
+/// [`TimeRunnerEnded`] is sent and triggered on the time runner entity for
+/// every repeat, even when a single tick crosses more than one, and when a
+/// time runner completes. The `with_repeat` of a repeat is the repeat state
+/// from before it so only the completion is
+/// [`is_completed`](TimeRunnerEnded::is_completed).
+///
+/// Time runners are ticked in parallel except the ones with spans that have
+/// [`TimeRemap`].
+#[allow(clippy::type_complexity)]
+pub fn tick_time_runner_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_time_runner: Query<
+        (
+            Entity,
+            &mut TimeRunner,
+            Option<&MaxDelta>,
+            Option<&mut ManualTick>,
+            Has<PropagatePlayback>,
+        ),
+        Without<NestedTimeRunner>,
+    >,
+    q_parent: Query<&Parent>,
+    mut q_remap: Query<(&mut TimeRemap, &TimeSpan, &TimeRunnerOf)>,
+    mut time_runner_ended: EventWriter<TimeRunnerEnded>,
+) {
+    let mut remaps = HashMap::<Entity, Vec<_>>::new();
+    for (remap, span, time_runner_of) in &mut q_remap {
+        remaps.entry(time_runner_of.0).or_default().push((
+            span.min().duration().as_secs_f32(),
+            span.max().duration().as_secs_f32(),
+            remap,
+        ));
+    }
+    let propagates = q_time_runner
+        .iter()
+        .filter(|(.., propagate)| *propagate)
+        .map(|(entity, time_runner, ..)| {
+            (entity, (time_runner.paused(), time_runner.time_scale()))
+        })
+        .collect::<HashMap<_, _>>();
+    let delta = time.delta_secs();
+
+    // Time runners with `TimeRemap` need mutable access to their spans so
+    // they're ticked serially after the others.
+    let mut ended = Parallel::<Vec<TimeRunnerEnded>>::default();
+    q_time_runner.par_iter_mut().for_each(
+        |(entity, time_runner, max_delta, manual_tick, _)| {
+            if remaps.contains_key(&entity) {
+                return;
+            }
+            let delta = take_delta(manual_tick, delta);
+            let Some(scale) =
+                propagated_time_scale(entity, &propagates, &q_parent)
+            else {
+                return;
+            };
+            ended.scope(|ended| {
+                tick_time_runner(
+                    entity,
+                    time_runner,
+                    max_delta,
+                    delta,
+                    scale,
+                    None,
+                    ended,
+                );
+            });
+        },
+    );
+    let mut remapped_ended = Vec::new();
+    for (entity, remaps) in remaps.iter_mut() {
+        let Ok((entity, time_runner, max_delta, manual_tick, _)) =
+            q_time_runner.get_mut(*entity)
+        else {
+            continue;
+        };
+        let delta = take_delta(manual_tick, delta);
+        let Some(scale) = propagated_time_scale(entity, &propagates, &q_parent)
+        else {
+            continue;
+        };
+        tick_time_runner(
+            entity,
+            time_runner,
+            max_delta,
+            delta,
+            scale,
+            Some(remaps),
+            &mut remapped_ended,
+        );
+    }
+    let ended = ended.drain().chain(remapped_ended).collect::<Vec<_>>();
+    for ended in &ended {
+        commands.trigger_targets(ended.clone(), ended.time_runner);
+    }
+    time_runner_ended.send_batch(ended);
+}
+
+/// Time queued in `manual_tick` if exists or else `delta`
+///
+/// Taken even if the time runner isn't ticked so time queued while an
+/// ancestor is paused is dropped like while the time runner is paused.
+fn take_delta(manual_tick: Option<Mut<ManualTick>>, delta: f32) -> f32 {
+    match manual_tick {
+        Some(mut manual_tick) if manual_tick.delta != 0. => {
+            std::mem::take(&mut manual_tick.delta)
+        }
+        Some(_) => 0.,
+        None => delta,
+    }
+}
+
+/// Time scale from the ancestors with [`PropagatePlayback`] or `None` if
+/// one of them is paused
+fn propagated_time_scale(
+    entity: Entity,
+    propagates: &HashMap<Entity, (bool, f32)>,
+    q_parent: &Query<&Parent>,
+) -> Option<f32> {
+    let mut time_scale = 1.;
+    if propagates.is_empty() {
+        return Some(time_scale);
+    }
+    for ancestor in q_parent.iter_ancestors(entity) {
+        let Some((paused, scale)) = propagates.get(&ancestor) else {
+            continue;
+        };
+        if *paused {
+            return None;
+        }
+        time_scale *= scale;
+    }
+    Some(time_scale)
+}
+
+fn tick_time_runner(
+    entity: Entity,
+    mut time_runner: Mut<TimeRunner>,
+    max_delta: Option<&MaxDelta>,
+    delta: f32,
+    propagated_time_scale: f32,
+    remaps: Option<&mut [(f32, f32, Mut<TimeRemap>)]>,
+    ended: &mut Vec<TimeRunnerEnded>,
+) {
+    if time_runner.paused() || time_runner.is_completed() {
+        return;
+    }
+    let time_scale = time_runner.time_scale() * propagated_time_scale;
+    let delta = match max_delta {
+        Some(max_delta) => max_delta.limit(delta),
+        None => delta,
+    };
+    if delta == 0. {
+        return;
+    }
+    let previous_direction = time_runner.direction();
+    let previous_repeat = time_runner.repeat();
+    let length = time_runner.length().as_secs_f32();
+    let now = time_runner.elasped().now();
+    // `TimeRunner::tick` applies the time scale again, `raw_tick` doesn't.
+    let delta = match remaps {
+        Some(remaps)
+            if previous_direction == TimeDirection::Forward
+                && time_scale > 0. =>
+        {
+            remap_delta(now, delta * time_scale, remaps)
+        }
+        _ => delta * time_scale,
+    };
+    time_runner.raw_tick(delta);
+
+    // Times the elapsed time crossed either end, which is what
+    // `TimeRunner::raw_tick` counts as repeats. Negative if it moved against
+    // its direction, such as by a negative time scale, so it crossed the
+    // start instead. Landing exactly on the start isn't one yet.
+    let crossed = if length > 0. {
+        match previous_direction {
+            TimeDirection::Forward => ((now + delta) / length).floor() as i32,
+            TimeDirection::Backward => -((now - delta) / length).floor() as i32,
+        }
+    } else {
+        1
+    };
+
+    // One event per repeat with the repeat state before it so only the
+    // completion is reported as completed. `raw_tick` clamps to the end
+    // instead of repeating only if there's no repeat left.
+    let mut repeated = false;
+    if let Some((mut repeat, repeat_style)) = previous_repeat {
+        let mut direction = previous_direction;
+        for _ in 0..crossed.abs() {
+            let with_repeat = repeat;
+            if repeat.advance_counter_by(crossed.signum()) == 0 {
+                break;
+            }
+            repeated = true;
+            if repeat_style == RepeatStyle::PingPong {
+                direction = match direction {
+                    TimeDirection::Forward => TimeDirection::Backward,
+                    TimeDirection::Backward => TimeDirection::Forward,
+                };
+            }
+            ended.push(TimeRunnerEnded {
+                time_runner: entity,
+                current_direction: direction,
+                with_repeat: Some(with_repeat),
+            });
+        }
+    }
+    if repeated {
+        return;
+    }
+
+    // Completed if it just reached the end it's heading to with no repeat
+    // left, including landing exactly on the start while going backward.
+    let reached_end = match previous_direction {
+        _ if length <= 0. => true,
+        TimeDirection::Forward => {
+            now < length && time_runner.elasped().now() >= length
+        }
+        TimeDirection::Backward => {
+            now > 0. && time_runner.elasped().now() <= 0.
+        }
+    };
+    let with_repeat = time_runner.repeat().map(|(repeat, _)| repeat);
+    let exhausted =
+        with_repeat.map(|repeat| repeat.exhausted()).unwrap_or(true);
+    if reached_end && exhausted {
+        ended.push(TimeRunnerEnded {
+            time_runner: entity,
+            current_direction: time_runner.direction(),
+            with_repeat,
+        });
+    }
+}
+
+/// Advance every [`AnimationQueue`] whose current [`TimeRunner`] has
+/// completed and keep every other time runner in the queue paused.
+pub fn animation_queue_system(
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    mut q_queue: Query<&mut AnimationQueue>,
+    mut q_time_runner: Query<&mut TimeRunner>,
+) {
+    let completed = time_runner_ended
+        .read()
+        .filter(|ended| ended.is_completed())
+        .map(|ended| ended.time_runner)
+        .collect::<HashSet<_>>();
+
+    q_queue.iter_mut().for_each(|mut queue| {
+        if queue
+            .current()
+            .map(|current| completed.contains(&current))
+            .unwrap_or(false)
+        {
+            queue.current += 1;
+            if let Some(next) = queue.current() {
+                if let Ok(mut time_runner) = q_time_runner.get_mut(next) {
+                    time_runner.restart();
+                }
+            }
+        }
+        if !queue.is_changed() {
+            return;
+        }
+        for (i, runner) in queue.runners.iter().enumerate() {
+            let Ok(mut time_runner) = q_time_runner.get_mut(*runner) else {
+                continue;
+            };
+            let paused = i != queue.current;
+            if time_runner.paused() != paused {
+                time_runner.set_paused(paused);
+            }
+        }
+    });
+}
+
+/// Set the time of every [`NestedTimeRunner`] from the [`TimeRunner`] in its
+/// [`TimeRunnerOf`], warped by its [`TimeWarp`] if exists. Outer time
+/// runners are updated before the time runners nested in them.
+///
+/// The repeat counter of a nested time runner follows the iteration it's at.
+/// [`TimeRunnerEnded`] is sent and triggered on the nested time runner
+/// entity for every repeat while its parent plays forward and when it
+/// reaches its end, like with [`tick_time_runner_system`].
+pub fn nested_time_runner_system(
+    mut commands: Commands,
+    mut q_nested: Query<(Entity, &mut NestedTimeRunner, &TimeRunnerOf)>,
+    mut q_time_runner: Query<&mut TimeRunner>,
+    q_time_warp: Query<&TimeWarp>,
+    mut time_runner_ended: EventWriter<TimeRunnerEnded>,
+) {
+    let mut nested = q_nested
+        .iter()
+        .map(|(entity, _, time_runner_of)| {
+            let depth = std::iter::successors(Some(time_runner_of.0), |curr| {
+                q_nested.get(*curr).ok().map(|(_, _, of)| of.0)
+            })
+            .count();
+            (depth, entity, time_runner_of.0)
+        })
+        .collect::<Vec<_>>();
+    nested.sort_by_key(|(depth, ..)| *depth);
+    let mut ended = Vec::new();
+    for (_, entity, parent_entity) in nested {
+        let Ok(parent) = q_time_runner.get(parent_entity) else {
+            continue;
+        };
+        let parent_now = match q_time_warp.get(parent_entity) {
+            Ok(time_warp) => time_warp
+                .warp(parent.elasped().now(), parent.length().as_secs_f32()),
+            Err(_) => parent.elasped().now(),
+        };
+        let Ok((_, mut nested, _)) = q_nested.get_mut(entity) else {
+            continue;
+        };
+        let Ok(mut time_runner) = q_time_runner.get_mut(entity) else {
+            continue;
+        };
+        let start = nested.span.min().duration().as_secs_f32();
+        let end = nested.span.max().duration().as_secs_f32();
+        let local = parent_now.clamp(start, end) - start;
+        let length = time_runner.length().as_secs_f32();
+        let was_completed = time_runner.is_completed();
+
+        let (iteration, now, end_local) = match time_runner.repeat() {
+            Some((repeat, style)) if length > 0. => {
+                // `local` at the end of an iteration is still in it
+                let iteration = ((local / length).ceil() as i32 - 1).max(0);
+                let (iteration, t, end_local) = match repeat {
+                    Repeat::Times { times, .. } => {
+                        let end_local = length * (times + 1) as f32;
+                        if iteration > times {
+                            (times, length, end_local)
+                        } else {
+                            let t = local - iteration as f32 * length;
+                            (iteration, t, end_local)
+                        }
+                    }
+                    _ => {
+                        let t = local - iteration as f32 * length;
+                        (iteration, t, f32::INFINITY)
+                    }
+                };
+                let now = match style {
+                    RepeatStyle::PingPong if iteration % 2 == 1 => length - t,
+                    _ => t,
+                };
+                (iteration, now, end_local)
+            }
+            _ => (0, local.min(length), length),
+        };
+
+        if let Some((repeat, style)) = time_runner.repeat() {
+            let direction_at = |iteration: i32| match style {
+                RepeatStyle::PingPong if iteration % 2 == 1 => {
+                    TimeDirection::Backward
+                }
+                RepeatStyle::PingPong => TimeDirection::Forward,
+                RepeatStyle::WrapAround => time_runner.direction(),
+            };
+            for repeated in nested.iteration..iteration {
+                ended.push(TimeRunnerEnded {
+                    time_runner: entity,
+                    current_direction: direction_at(repeated),
+                    with_repeat: Some(with_times_repeated(repeat, repeated)),
+                });
+            }
+            let direction = direction_at(iteration);
+            if time_runner.direction() != direction {
+                time_runner.set_direction(direction);
+            }
+            let repeat = with_times_repeated(repeat, iteration);
+            if time_runner.repeat() != Some((repeat, style)) {
+                time_runner.set_repeat(Some((repeat, style)));
+            }
+        }
+        if nested.iteration != iteration {
+            nested.iteration = iteration;
+        }
+        if time_runner.elasped().now() != now {
+            time_runner.set_tick(now);
+        }
+        if local >= end_local && !was_completed {
+            ended.push(TimeRunnerEnded {
+                time_runner: entity,
+                current_direction: time_runner.direction(),
+                with_repeat: time_runner.repeat().map(|(repeat, _)| repeat),
+            });
+        }
+    }
+    for ended in &ended {
+        commands.trigger_targets(ended.clone(), ended.time_runner);
+    }
+    time_runner_ended.send_batch(ended);
+}
+
+/// `repeat` with its counter set to `times_repeated`
+fn with_times_repeated(repeat: Repeat, times_repeated: i32) -> Repeat {
+    match repeat {
+        Repeat::Infinitely => Repeat::Infinitely,
+        Repeat::InfinitelyCounted { .. } => {
+            Repeat::InfinitelyCounted { times_repeated }
+        }
+        Repeat::Times { times, .. } => Repeat::Times {
+            times,
+            times_repeated,
+        },
+    }
+}
+
+/// Update [`TimeSpanProgress`] of the spans of every [`TimeRunner`] by the
+/// range of time it advanced since the last run, in place of
+/// `bevy_time_runner`'s own progress system. Spans are evaluated at the time
+/// warped by [`TimeWarp`] if the time runner has one.
+///
+/// Only the spans found by [`TimeSpanIndex::overlapping`] that range, which
+/// indexes [`TimeRunnerSpans`], and the spans that already have
+/// [`TimeSpanProgress`] are visited, or every span of the index if the time
+/// runner repeated. A time runner that stayed where its spans were already
+/// evaluated is skipped until its [`TimeSpanIndex`] or [`TimeWarp`] changes,
+/// and [`TimeSpanProgress`] is only written when it changes. Progress is
+/// removed from the spans of a time runner the run after it completes and
+/// once it's skipped by [`SkipTimeRunner`].
+#[allow(clippy::type_complexity)]
+pub fn time_span_progress_system(
+    mut commands: Commands,
+    q_time_runner: Query<
+        (
+            Entity,
+            &TimeRunner,
+            Option<Ref<TimeSpanIndex>>,
+            Option<Ref<TimeWarp>>,
+        ),
+        Without<SkipTimeRunner>,
+    >,
+    q_added_skip: Query<Entity, Added<SkipTimeRunner>>,
+    q_active: Query<(Entity, &TimeRunnerOf), With<TimeSpanProgress>>,
+    mut q_span: Query<(&TimeSpan, Option<&mut TimeSpanProgress>)>,
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    mut last_seen: Local<HashMap<Entity, (f32, bool)>>,
+) {
+    let repeated_runners = time_runner_ended
+        .read()
+        .filter(|ended| !ended.is_completed())
+        .map(|ended| ended.time_runner)
+        .collect::<HashSet<_>>();
+    last_seen.retain(|entity, _| q_time_runner.contains(*entity));
+
+    let mut active = HashMap::<Entity, Vec<Entity>>::new();
+    for (span, time_runner_of) in &q_active {
+        active.entry(time_runner_of.0).or_default().push(span);
+    }
+    for time_runner in &q_added_skip {
+        last_seen.remove(&time_runner);
+        for span in active.remove(&time_runner).into_iter().flatten() {
+            commands.entity(span).remove::<TimeSpanProgress>();
+        }
+    }
+
+    for (entity, time_runner, index, time_warp) in &q_time_runner {
+        let elasped = time_runner.elasped();
+        let seen = last_seen.get(&entity).copied();
+        // The spans of a completed time runner were evaluated at its end by
+        // the run it got there so their progress is removed, unless it was
+        // completed by the time it was first seen.
+        if time_runner.is_completed() {
+            if seen.is_some() {
+                last_seen.remove(&entity);
+                for span in active.remove(&entity).into_iter().flatten() {
+                    commands.entity(span).remove::<TimeSpanProgress>();
+                }
+            }
+            continue;
+        }
+        let repeated = if repeated_runners.contains(&entity) {
+            time_runner.repeat().map(|(_, style)| style)
+        } else {
+            None
+        };
+        // Spans are settled once they were evaluated with no time between
+        // their previous and current time, until spans or the warp change.
+        let changed = index.as_ref().is_some_and(|index| index.is_changed())
+            || time_warp.as_ref().is_some_and(|warp| warp.is_changed());
+        if repeated.is_none() && !changed && seen == Some((elasped.now(), true))
+        {
+            continue;
+        }
+        let raw_previous = match seen {
+            Some((seen, _)) => seen,
+            None => elasped.previous(),
+        };
+        last_seen.insert(
+            entity,
+            (
+                elasped.now(),
+                repeated.is_none() && raw_previous == elasped.now(),
+            ),
+        );
+
+        let runner_direction = time_runner.direction();
+        let length = time_runner.length().as_secs_f32();
+        let (now, previous) = match time_warp.as_deref() {
+            Some(time_warp) => (
+                time_warp.warp(elasped.now(), length),
+                time_warp.warp(raw_previous, length),
+            ),
+            None => (elasped.now(), raw_previous),
+        };
+        let direction = match repeated {
+            Some(_) => runner_direction,
+            None => match previous.total_cmp(&now) {
+                Ordering::Less => TimeDirection::Forward,
+                Ordering::Equal => runner_direction,
+                Ordering::Greater => TimeDirection::Backward,
+            },
+        };
+
+        let mut spans = match (index.as_deref(), repeated) {
+            (Some(index), None) => index
+                .overlapping(previous.min(now), previous.max(now))
+                .collect(),
+            (Some(index), Some(_)) => {
+                index.spans().map(|(span, ..)| span).collect()
+            }
+            (None, _) => Vec::new(),
+        };
+        if let Some(active) = active.get(&entity) {
+            spans.extend(active);
+            spans.sort_unstable();
+            spans.dedup();
+        }
+
+        let percentage = |secs: f32, length: f32| {
+            if length > 0. {
+                return secs / length;
+            }
+            match (secs.total_cmp(&0.), runner_direction) {
+                (Ordering::Greater, _)
+                | (Ordering::Equal, TimeDirection::Forward) => f32::INFINITY,
+                _ => f32::NEG_INFINITY,
+            }
+        };
+        for span_entity in spans {
+            let Ok((span, progress)) = q_span.get_mut(span_entity) else {
+                continue;
+            };
+            let Some(use_time) = span_in_range(
+                direction,
+                quotient(span, previous),
+                quotient(span, now),
+                repeated,
+            ) else {
+                if progress.is_some() {
+                    commands.entity(span_entity).remove::<TimeSpanProgress>();
+                }
+                continue;
+            };
+            let min = span.min().duration().as_secs_f32();
+            let length = span.max().duration().as_secs_f32() - min;
+            let span_now = match use_time {
+                UseTime::Current => now - min,
+                UseTime::Min => 0.,
+                UseTime::Max => length,
+            };
+            match progress {
+                Some(mut progress) => {
+                    let now_percentage = percentage(span_now, length);
+                    // Only write progress that changes so a span that stays
+                    // put isn't seen as changed every frame
+                    if progress.previous != progress.now
+                        || progress.previous_percentage
+                            != progress.now_percentage
+                        || progress.now != span_now
+                        || progress.now_percentage != now_percentage
+                    {
+                        progress.previous = progress.now;
+                        progress.previous_percentage = progress.now_percentage;
+                        progress.now = span_now;
+                        progress.now_percentage = now_percentage;
+                    }
+                }
+                None => {
+                    let span_previous = previous - min;
+                    commands.entity(span_entity).insert(TimeSpanProgress {
+                        now_percentage: percentage(span_now, length),
+                        now: span_now,
+                        previous_percentage: percentage(span_previous, length),
+                        previous: span_previous,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Make every [`TimeRunner`] that reached its end this run
+/// [completed](TimeRunner::is_completed), after
+/// [`time_span_progress_system`] evaluated its spans at the end.
+///
+/// A time runner is completed once its previous elapsed time caught up
+/// with the current one at the end, so it's ticked by nothing here.
+pub fn settle_completed_time_runner_system(
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    mut q_time_runner: Query<&mut TimeRunner>,
+) {
+    for ended in time_runner_ended
+        .read()
+        .filter(|ended| ended.is_completed())
+    {
+        let Ok(mut time_runner) = q_time_runner.get_mut(ended.time_runner)
+        else {
+            continue;
+        };
+        // A time runner of zero length is at its end by any time
+        let secs = if time_runner.length().is_zero() {
+            1.
+        } else {
+            0.
+        };
+        time_runner.raw_tick(secs);
+    }
+}
+
+/// Where a time is relative to a [`TimeSpan`]
+#[derive(Debug, Clone, Copy)]
+enum Quotient {
+    Before,
+    Inside,
+    After,
+}
+
+fn quotient(span: &TimeSpan, secs: f32) -> Quotient {
+    let after_min = match span.min() {
+        TimeBound::Inclusive(min) => secs >= min.as_secs_f32(),
+        TimeBound::Exclusive(min) => secs > min.as_secs_f32(),
+    };
+    let before_max = match span.max() {
+        TimeBound::Inclusive(max) => secs <= max.as_secs_f32(),
+        TimeBound::Exclusive(max) => secs < max.as_secs_f32(),
+    };
+    match (after_min, before_max) {
+        (true, true) => Quotient::Inside,
+        (true, false) => Quotient::After,
+        _ => Quotient::Before,
+    }
+}
+
+/// Time a span in range is progressed to
+enum UseTime {
+    Current,
+    Min,
+    Max,
+}
+
+/// Whether a span is in the range a time runner advanced over and where to
+/// progress it to, matching `bevy_time_runner`
+fn span_in_range(
+    direction: TimeDirection,
+    previous: Quotient,
+    now: Quotient,
+    repeated: Option<RepeatStyle>,
+) -> Option<UseTime> {
+    use Quotient::*;
+    use TimeDirection::*;
+
+    match (repeated, direction, previous, now) {
+        (None, _, Inside, Inside)
+        | (None, Forward, Before, Inside | After)
+        | (None, Forward, Inside, After)
+        | (None, Backward, After, Inside | Before)
+        | (None, Backward, Inside, Before) => Some(UseTime::Current),
+        (None, ..) => None,
+        (Some(RepeatStyle::WrapAround), Forward, Before | Inside, Before) => {
+            Some(UseTime::Max)
+        }
+        (Some(RepeatStyle::WrapAround), Forward, After, Before) => None,
+        (Some(RepeatStyle::WrapAround), Backward, After | Inside, After) => {
+            Some(UseTime::Min)
+        }
+        (Some(RepeatStyle::WrapAround), Backward, Before, After) => None,
+        (Some(RepeatStyle::PingPong), Forward, Before, Before)
+        | (Some(RepeatStyle::PingPong), Backward, After, After) => None,
+        (Some(_), ..) => Some(UseTime::Current),
+    }
+}
+
+/// Advance every [`BlendWeightFade`] and update its [`BlendWeight`].
+pub fn blend_weight_fade_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q_fade: Query<(
+        Entity,
+        &mut BlendWeightFade,
+        &mut BlendWeight,
+        Option<&mut TimeRunner>,
+    )>,
+) {
+    let delta = time.delta();
+    for (entity, mut fade, mut weight, time_runner) in &mut q_fade {
+        fade.elapsed = (fade.elapsed + delta).min(fade.duration);
+        let t = if fade.duration.is_zero() {
+            1.
+        } else {
+            fade.elapsed.as_secs_f32() / fade.duration.as_secs_f32()
+        };
+        weight.0 = fade.start.lerp(fade.end, t);
+        if fade.elapsed < fade.duration {
+            continue;
+        }
+        if fade.end <= 0. {
+            if let Some(mut time_runner) = time_runner {
+                time_runner.set_paused(true);
+            }
+        }
+        if fade.end >= 1. {
+            commands
+                .entity(entity)
+                .remove::<(BlendWeightFade, BlendWeight)>();
+        } else {
+            commands.entity(entity).remove::<BlendWeightFade>();
+        }
+    }
+}
+
+/// Restart and unpause the [`TimeRunner`] referenced by [`OnEndPlay`] when
+/// its owning time runner completes.
+pub fn on_end_play_system(
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    q_on_end_play: Query<&OnEndPlay>,
+    mut q_time_runner: Query<&mut TimeRunner>,
+) {
+    time_runner_ended
+        .read()
+        .filter(|ended| ended.is_completed())
+        .for_each(|ended| {
+            let Ok(on_end_play) = q_on_end_play.get(ended.time_runner) else {
+                return;
+            };
+            let Ok(mut time_runner) = q_time_runner.get_mut(on_end_play.0)
+            else {
+                return;
+            };
+            time_runner.restart();
+            time_runner.set_paused(false);
+        });
+}
+
+/// Fires [`TimeSpanEnded`] for every span whose [`TimeSpanProgress`] just
+/// crossed its end in the direction of playback.
+pub fn time_span_ended_system(
+    mut commands: Commands,
+    q_time_span: Query<(Entity, &TimeSpanProgress, &TimeRunnerOf)>,
+    mut event_writer: EventWriter<TimeSpanEnded>,
+) {
+    q_time_span.iter().for_each(
+        |(entity, progress, &TimeRunnerOf(time_runner))| {
+            let direction = if progress.previous_percentage < 1.
+                && progress.now_percentage >= 1.
+            {
+                TimeDirection::Forward
+            } else if progress.previous_percentage > 0.
+                && progress.now_percentage <= 0.
+            {
+                TimeDirection::Backward
+            } else {
+                return;
+            };
+            let event = TimeSpanEnded {
+                span: entity,
+                time_runner,
+                direction,
+            };
+            commands.trigger_targets(event, entity);
+            event_writer.send(event);
+        },
+    );
+}
+
+/// Fires [`TimeSpanCrossed`] whenever a span becomes active or inactive.
+///
+/// A span is active while its progress is in `[0, 1)` when playing
+/// forward or in `(0, 1]` when playing backward.
+pub fn time_span_crossed_system(
+    mut commands: Commands,
+    q_time_span: Query<(Entity, &TimeSpanProgress, &TimeRunnerOf)>,
+    mut removed_progress: RemovedComponents<TimeSpanProgress>,
+    mut active_spans: Local<HashMap<Entity, (Entity, TimeDirection)>>,
+    mut event_writer: EventWriter<TimeSpanCrossed>,
+) {
+    let mut send = |event: TimeSpanCrossed| {
+        commands.trigger_targets(event, event.span);
+        event_writer.send(event);
+    };
+
+    for span in removed_progress.read() {
+        if let Some((time_runner, direction)) = active_spans.remove(&span) {
+            send(TimeSpanCrossed {
+                span,
+                time_runner,
+                crossing: SpanCrossing::Exited,
+                direction,
+            });
+        }
+    }
+
+    q_time_span.iter().for_each(
+        |(entity, progress, &TimeRunnerOf(time_runner))| {
+            let direction = if progress.now_percentage
+                > progress.previous_percentage
+            {
+                TimeDirection::Forward
+            } else if progress.now_percentage < progress.previous_percentage {
+                TimeDirection::Backward
+            } else {
+                match active_spans.get(&entity) {
+                    Some((_, direction)) => *direction,
+                    None => TimeDirection::Forward,
+                }
+            };
+            let now = progress.now_percentage;
+            let active = match direction {
+                TimeDirection::Forward => (0. ..1.).contains(&now),
+                TimeDirection::Backward => now > 0. && now <= 1.,
+            };
+            let was_active = active_spans.contains_key(&entity);
+            let crossing = match (was_active, active) {
+                (false, true) => {
+                    active_spans.insert(entity, (time_runner, direction));
+                    SpanCrossing::Entered
+                }
+                (true, false) => {
+                    active_spans.remove(&entity);
+                    SpanCrossing::Exited
+                }
+                (true, true) => {
+                    active_spans.insert(entity, (time_runner, direction));
+                    return;
+                }
+                (false, false) => return,
+            };
+            send(TimeSpanCrossed {
+                span: entity,
+                time_runner,
+                crossing,
+                direction,
+            });
+        },
+    );
+}
+
+/// Run the system referenced by [`OnEndRunSystem`] when its owning
+/// [`TimeRunner`] completes.
+pub fn on_end_run_system_system(
+    mut commands: Commands,
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    q_on_end_run_system: Query<&OnEndRunSystem>,
+) {
+    time_runner_ended
+        .read()
+        .filter(|ended| ended.is_completed())
+        .for_each(|ended| {
+            if let Ok(on_end_run_system) =
+                q_on_end_run_system.get(ended.time_runner)
+            {
+                commands.run_system(on_end_run_system.0);
+            }
+        });
+}
+
+/// Send [`OnTimeRunnerEnded`] and trigger it on the entity of every ended
+/// [`TimeRunner`].
+pub fn on_time_runner_ended_system(
+    mut commands: Commands,
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    mut event_writer: EventWriter<OnTimeRunnerEnded>,
+) {
+    time_runner_ended.read().for_each(|ended| {
+        let event = OnTimeRunnerEnded {
+            time_runner: ended.time_runner,
+            current_direction: ended.current_direction,
+            with_repeat: ended.with_repeat,
+            iteration: match ended.with_repeat {
+                None => Some(0),
+                Some(Repeat::Infinitely) => None,
+                Some(
+                    Repeat::InfinitelyCounted { times_repeated }
+                    | Repeat::Times { times_repeated, .. },
+                ) => Some(times_repeated),
+            },
+        };
+        commands.trigger_targets(event, ended.time_runner);
+        event_writer.send(event);
+    });
+}
+
+/// Apply [`CompletionPolicy`] to every [`TimeRunner`] that completed.
+///
+/// The spans of a time runner are the ones in its [`TimeRunnerSpans`]
+/// except its [`NestedTimeRunner`]s and itself, so the time runner entity
+/// is left as is by the policies that only touch the spans.
+pub fn completion_policy_system(
+    mut commands: Commands,
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    mut q_time_runner: Query<(
+        &mut TimeRunner,
+        &CompletionPolicy,
+        Option<&TimeRunnerSpans>,
+    )>,
+    q_nested: Query<(), With<NestedTimeRunner>>,
+    q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
+) {
+    time_runner_ended
+        .read()
+        .filter(|ended| ended.is_completed())
+        .for_each(|ended| {
+            let Ok((mut time_runner, policy, time_runner_spans)) =
+                q_time_runner.get_mut(ended.time_runner)
+            else {
+                return;
+            };
+            let spans = time_runner_spans
+                .map(|spans| spans.0.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .copied()
+                .filter(|span| {
+                    *span != ended.time_runner && !q_nested.contains(*span)
+                })
+                .collect::<HashSet<_>>();
+            match policy {
+                CompletionPolicy::Keep => {}
+                CompletionPolicy::KeepPaused => {
+                    time_runner.set_paused(true);
+                }
+                CompletionPolicy::RemoveTimeRunner => {
+                    if let Some(mut entity) =
+                        commands.get_entity(ended.time_runner)
+                    {
+                        entity.remove::<TimeRunner>();
+                    }
+                }
+                CompletionPolicy::StripSpans => {
+                    for span in &spans {
+                        if let Some(mut entity) = commands.get_entity(*span) {
+                            entity.remove::<(
+                                TimeSpan,
+                                TimeSpanProgress,
+                                TweenInterpolationValue,
+                            )>();
+                        }
+                    }
+                }
+                CompletionPolicy::DespawnSpans => {
+                    for span in &spans {
+                        // Move what's below the span but isn't a span, such
+                        // as meshes or nested time runners, up to the
+                        // closest ancestor that stays
+                        let kept = q_children
+                            .get(*span)
+                            .into_iter()
+                            .flatten()
+                            .copied()
+                            .filter(|child| !spans.contains(child))
+                            .collect::<Vec<_>>();
+                        if kept.is_empty() {
+                            continue;
+                        }
+                        let Some(mut ancestor) = q_parent
+                            .iter_ancestors(*span)
+                            .find(|ancestor| !spans.contains(ancestor))
+                            .and_then(|ancestor| commands.get_entity(ancestor))
+                        else {
+                            continue;
+                        };
+                        ancestor.add_children(&kept);
+                    }
+                    for span in &spans {
+                        let is_top =
+                            q_parent.get(*span).map_or(true, |parent| {
+                                !spans.contains(&parent.get())
+                            });
+                        if !is_top {
+                            continue;
+                        }
+                        if let Some(entity) = commands.get_entity(*span) {
+                            entity.despawn_recursive();
+                        }
+                    }
+                }
+                CompletionPolicy::DespawnRecursive => {
+                    if let Some(entity) = commands.get_entity(ended.time_runner)
+                    {
+                        entity.despawn_recursive();
+                    }
+                }
+            }
+        });
+}
+
+/// Send [`TimeRunnerProgress`] for [`TimeRunner`]s with [`EmitProgress`]
+/// when the progress moved far enough or reached either end.
+pub fn emit_progress_system(
+    mut q_time_runner: Query<(Entity, &TimeRunner, &mut EmitProgress)>,
+    mut event_writer: EventWriter<TimeRunnerProgress>,
+) {
+    q_time_runner.iter_mut().for_each(
+        |(entity, time_runner, mut emit_progress)| {
+            let length = time_runner.length().as_secs_f32();
+            let fraction = if length > 0. {
+                (time_runner.elasped().now() / length).clamp(0., 1.)
+            } else {
+                1.
+            };
+            let should_emit = match emit_progress.last {
+                Some(last) => {
+                    last != fraction
+                        && ((fraction - last).abs() >= emit_progress.every
+                            || fraction == 0.
+                            || fraction == 1.)
+                }
+                None => true,
+            };
+            if !should_emit {
+                return;
+            }
+            emit_progress.last = Some(fraction);
+            event_writer.send(TimeRunnerProgress {
+                time_runner: entity,
+                fraction,
+            });
+        },
+    );
+}
+
+/// Send [`TimeRunnerStarted`], [`TimeRunnerLooped`], [`TimeRunnerPaused`],
+/// and [`TimeRunnerResumed`] by comparing every [`TimeRunner`] with its
+/// state from the previous run.
+///
+/// The elapsed time is compared with the one seen in the previous run
+/// since [`time_span_progress_system`] has already set the previous elapsed
+/// time of the time runner to the current one.
+#[allow(clippy::too_many_arguments)]
+pub fn time_runner_lifecycle_system(
+    mut commands: Commands,
+    q_time_runner: Query<(Entity, &TimeRunner)>,
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    mut removed_time_runner: RemovedComponents<TimeRunner>,
+    mut started_writer: EventWriter<TimeRunnerStarted>,
+    mut looped_writer: EventWriter<TimeRunnerLooped>,
+    mut paused_writer: EventWriter<TimeRunnerPaused>,
+    mut resumed_writer: EventWriter<TimeRunnerResumed>,
+    mut last_states: Local<HashMap<Entity, (bool, bool, f32)>>,
+) {
+    for time_runner in removed_time_runner.read() {
+        last_states.remove(&time_runner);
+    }
+
+    q_time_runner.iter().for_each(|(entity, time_runner)| {
+        let now = time_runner.elasped().now();
+        let start = match time_runner.direction() {
+            TimeDirection::Forward => 0.,
+            TimeDirection::Backward => time_runner.length().as_secs_f32(),
+        };
+        let paused = time_runner.paused();
+        let (last_paused, started, last_now) =
+            last_states.entry(entity).or_insert((paused, false, start));
+
+        if *last_paused != paused {
+            if paused {
+                let event = TimeRunnerPaused {
+                    time_runner: entity,
+                };
+                commands.trigger_targets(event, entity);
+                paused_writer.send(event);
+            } else {
+                let event = TimeRunnerResumed {
+                    time_runner: entity,
+                };
+                commands.trigger_targets(event, entity);
+                resumed_writer.send(event);
+            }
+            *last_paused = paused;
+        }
+
+        if !*started && now != *last_now {
+            *started = true;
+            let event = TimeRunnerStarted {
+                time_runner: entity,
+            };
+            commands.trigger_targets(event, entity);
+            started_writer.send(event);
+        }
+        // Started again once it's played from the start after completing
+        // or resting at the start
+        if *started
+            && (time_runner.is_completed()
+                || (now == start && now == *last_now))
+        {
+            *started = false;
+        }
+        *last_now = now;
+    });
+
+    time_runner_ended
+        .read()
+        .filter(|ended| !ended.is_completed())
+        .for_each(|ended| {
+            let event = TimeRunnerLooped {
+                time_runner: ended.time_runner,
+                repeat: ended.with_repeat,
+            };
+            commands.trigger_targets(event, ended.time_runner);
+            looped_writer.send(event);
+        });
+}
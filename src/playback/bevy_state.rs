@@ -0,0 +1,235 @@
+//! Playback support for [`bevy_state`](bevy::state)
+//!
+//! **Plugins**:
+//! - [`OnEndSetStatePlugin<S>`]
+//! - [`PlayOnStatePlugin<S>`]
+//!
+//! **Components**:
+//! - [`OnEndSetState<S>`]
+//! - [`OnEnterPlay<S>`]
+//! - [`OnExitPlay<S>`]
+//!
+//! **Systems**:
+//! - [`on_end_set_state_system`]
+//! - [`play_on_state_system`]
+
+use std::marker::PhantomData;
+
+use bevy::{
+    prelude::*,
+    state::state::{FreelyMutableState, StateTransitionEvent},
+};
+use bevy_time_runner::{TimeRunner, TimeRunnerEnded};
+
+use crate::{playback::TimeRunnerExt, TweenSystemSet};
+
+/// Plugin for [`OnEndSetState<S>`]. Add one for every state type used.
+pub struct OnEndSetStatePlugin<S>
+where
+    S: FreelyMutableState,
+{
+    marker: PhantomData<S>,
+}
+
+impl<S> Default for OnEndSetStatePlugin<S>
+where
+    S: FreelyMutableState,
+{
+    fn default() -> Self {
+        OnEndSetStatePlugin {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S> Plugin for OnEndSetStatePlugin<S>
+where
+    S: FreelyMutableState,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            on_end_set_state_system::<S>.after(TweenSystemSet::ApplyTween),
+        );
+    }
+}
+
+/// Set [`NextState<S>`] when the [`TimeRunner`](bevy_time_runner::TimeRunner)
+/// of this entity completes.
+///
+/// Requires [`OnEndSetStatePlugin<S>`].
+///
+/// ```no_run
+/// use bevy::{prelude::*, state::state::FreelyMutableState};
+/// use bevy_tween::{
+///     combinator::forward, playback::bevy_state::OnEndSetState, prelude::*,
+/// };
+///
+/// #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, States)]
+/// enum AppState {
+///     #[default]
+///     Splash,
+///     Menu,
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .animation()
+///         .insert(forward(Duration::from_secs(2)))
+///         .insert(OnEndSetState(AppState::Menu));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Component)]
+pub struct OnEndSetState<S>(pub S)
+where
+    S: FreelyMutableState;
+
+/// Set [`NextState<S>`] from [`OnEndSetState<S>`] when its owning
+/// time runner completes.
+pub fn on_end_set_state_system<S>(
+    mut time_runner_ended: EventReader<TimeRunnerEnded>,
+    q_on_end_set_state: Query<&OnEndSetState<S>>,
+    mut next_state: ResMut<NextState<S>>,
+) where
+    S: FreelyMutableState,
+{
+    time_runner_ended
+        .read()
+        .filter(|ended| ended.is_completed())
+        .for_each(|ended| {
+            if let Ok(on_end_set_state) =
+                q_on_end_set_state.get(ended.time_runner)
+            {
+                next_state.set(on_end_set_state.0.clone());
+            }
+        });
+}
+
+/// Plugin for [`OnEnterPlay<S>`] and [`OnExitPlay<S>`]. Add one for every
+/// state type used.
+pub struct PlayOnStatePlugin<S>
+where
+    S: States,
+{
+    marker: PhantomData<S>,
+}
+
+impl<S> Default for PlayOnStatePlugin<S>
+where
+    S: States,
+{
+    fn default() -> Self {
+        PlayOnStatePlugin {
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S> Plugin for PlayOnStatePlugin<S>
+where
+    S: States,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            play_on_state_system::<S>.before(TweenSystemSet::TickTimeRunner),
+        );
+    }
+}
+
+/// Restart and unpause the [`TimeRunner`] of this entity when the state is
+/// entered.
+///
+/// Requires [`PlayOnStatePlugin<S>`].
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::{
+///     combinator::forward,
+///     playback::bevy_state::{OnEnterPlay, OnExitPlay},
+///     prelude::*,
+/// };
+///
+/// #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, States)]
+/// enum AppState {
+///     #[default]
+///     Game,
+///     Menu,
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .animation()
+///         .paused(true)
+///         .insert(forward(Duration::from_millis(300)))
+///         .insert(OnEnterPlay(AppState::Menu));
+///     commands
+///         .animation()
+///         .paused(true)
+///         .insert(forward(Duration::from_millis(300)))
+///         .insert(OnExitPlay(AppState::Menu));
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Component)]
+pub struct OnEnterPlay<S>(pub S)
+where
+    S: States;
+
+/// Restart and unpause the [`TimeRunner`] of this entity when the state is
+/// exited.
+///
+/// Requires [`PlayOnStatePlugin<S>`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Component)]
+pub struct OnExitPlay<S>(pub S)
+where
+    S: States;
+
+/// Play the time runners with [`OnEnterPlay<S>`] or [`OnExitPlay<S>`]
+/// matching every [`StateTransitionEvent<S>`].
+#[allow(clippy::type_complexity)]
+pub fn play_on_state_system<S>(
+    mut state_transition: EventReader<StateTransitionEvent<S>>,
+    mut q_time_runner: Query<
+        (
+            &mut TimeRunner,
+            Option<&OnEnterPlay<S>>,
+            Option<&OnExitPlay<S>>,
+        ),
+        Or<(With<OnEnterPlay<S>>, With<OnExitPlay<S>>)>,
+    >,
+) where
+    S: States,
+{
+    for transition in state_transition.read() {
+        for (mut time_runner, on_enter, on_exit) in &mut q_time_runner {
+            let entered = on_enter.is_some_and(|on_enter| {
+                transition.entered.as_ref() == Some(&on_enter.0)
+            });
+            let exited = on_exit.is_some_and(|on_exit| {
+                transition.exited.as_ref() == Some(&on_exit.0)
+            });
+            if entered || exited {
+                time_runner.restart();
+                time_runner.set_paused(false);
+            }
+        }
+    }
+}
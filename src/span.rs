@@ -0,0 +1,231 @@
+//! Module containing spans that act on the world when a time runner
+//! crosses them instead of interpolating a value.
+//!
+//! # Span
+//!
+//! **Plugins**:
+//! - [`SpanPlugin`]
+//! - `bevy_audio::AudioSpanPlugin` with the `bevy_audio` feature
+//!
+//! **Components**:
+//! - [`SpawnSpan`]
+//! - [`VisibilitySpan`] with the `bevy_render` feature
+//!
+//! **Systems**:
+//! - [`spawn_span_system`]
+//! - [`visibility_span_system`] with the `bevy_render` feature
+//!
+//! Spans in this module react to [`TimeSpanCrossed`] so reversing and
+//! restarting a time runner behaves the same as playing it forward.
+
+use std::sync::Arc;
+
+#[cfg(feature = "bevy_render")]
+use bevy::ecs::{entity::MapEntities, reflect::ReflectMapEntities};
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::{
+    playback::{self, SpanCrossing, TimeSpanCrossed},
+    TweenSystemSet,
+};
+
+#[cfg(feature = "bevy_audio")]
+pub mod bevy_audio;
+
+/// Plugin for the spans in this module
+///
+/// Requires [`PlaybackPlugin`](crate::playback::PlaybackPlugin)
+pub struct SpanPlugin;
+
+impl Plugin for SpanPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let schedule = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world")
+            .schedule;
+        app.add_systems(
+            schedule,
+            spawn_span_system
+                .in_set(TweenSystemSet::ApplyTween)
+                .after(playback::time_span_crossed_system),
+        );
+        #[cfg(feature = "bevy_render")]
+        app.add_systems(
+            schedule,
+            visibility_span_system
+                .in_set(TweenSystemSet::ApplyTween)
+                .after(playback::time_span_crossed_system),
+        )
+        .register_type::<VisibilitySpan>();
+    }
+}
+
+/// Spawn an entity when a time runner enters this span and despawn it
+/// recursively when the time runner leaves the span in either direction.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_time_runner::TimeSpan;
+/// use bevy_tween::{
+///     combinator::AnimationCommands, prelude::*, span::SpawnSpan,
+/// };
+///
+/// #[derive(Clone, Component)]
+/// struct Prop;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.animation().insert(|a: &mut AnimationCommands, pos: &mut Duration| {
+///         a.spawn((
+///             TimeSpan::try_from(Duration::from_secs(1)..Duration::from_secs(3)).unwrap(),
+///             SpawnSpan::new(Prop),
+///         ));
+///     });
+/// }
+/// ```
+#[derive(Clone, Component)]
+pub struct SpawnSpan {
+    spawner: Arc<dyn Fn(&mut Commands) -> Entity + Send + Sync + 'static>,
+}
+
+impl SpawnSpan {
+    /// Create new [`SpawnSpan`] that spawns a clone of `bundle`.
+    pub fn new<B>(bundle: B) -> SpawnSpan
+    where
+        B: Bundle + Clone,
+    {
+        SpawnSpan::with(move |commands| commands.spawn(bundle.clone()).id())
+    }
+
+    /// Create new [`SpawnSpan`] with a custom spawner returning the spawned
+    /// entity.
+    pub fn with<F>(spawner: F) -> SpawnSpan
+    where
+        F: Fn(&mut Commands) -> Entity + Send + Sync + 'static,
+    {
+        SpawnSpan {
+            spawner: Arc::new(spawner),
+        }
+    }
+}
+
+impl std::fmt::Debug for SpawnSpan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpawnSpan").finish_non_exhaustive()
+    }
+}
+
+/// Spawn and despawn entities of [`SpawnSpan`] according to
+/// [`TimeSpanCrossed`].
+pub fn spawn_span_system(
+    mut commands: Commands,
+    mut time_span_crossed: EventReader<TimeSpanCrossed>,
+    q_spawn_span: Query<&SpawnSpan>,
+    mut spawned: Local<HashMap<Entity, Entity>>,
+) {
+    for crossed in time_span_crossed.read() {
+        match crossed.crossing {
+            SpanCrossing::Entered => {
+                let Ok(spawn_span) = q_spawn_span.get(crossed.span) else {
+                    continue;
+                };
+                let entity = (spawn_span.spawner)(&mut commands);
+                if let Some(previous) = spawned.insert(crossed.span, entity) {
+                    if let Some(previous) = commands.get_entity(previous) {
+                        previous.despawn_recursive();
+                    }
+                }
+            }
+            SpanCrossing::Exited => {
+                let Some(entity) = spawned.remove(&crossed.span) else {
+                    continue;
+                };
+                if let Some(entity) = commands.get_entity(entity) {
+                    entity.despawn_recursive();
+                }
+            }
+        }
+    }
+}
+
+/// Set [`Visibility`] of the target entity while a time runner is inside
+/// this span and restore the previous visibility once it leaves.
+#[cfg(feature = "bevy_render")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct VisibilitySpan {
+    /// Entity to set the visibility of
+    pub target: Entity,
+    /// Visibility while the span is active
+    pub visibility: Visibility,
+}
+
+#[cfg(feature = "bevy_render")]
+impl MapEntities for VisibilitySpan {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.target = entity_mapper.map_entity(self.target);
+    }
+}
+
+#[cfg(feature = "bevy_render")]
+impl VisibilitySpan {
+    /// Show `target` while the span is active
+    pub fn visible(target: Entity) -> VisibilitySpan {
+        VisibilitySpan {
+            target,
+            visibility: Visibility::Visible,
+        }
+    }
+
+    /// Hide `target` while the span is active
+    pub fn hidden(target: Entity) -> VisibilitySpan {
+        VisibilitySpan {
+            target,
+            visibility: Visibility::Hidden,
+        }
+    }
+}
+
+/// Set and restore target's [`Visibility`] of [`VisibilitySpan`] according
+/// to [`TimeSpanCrossed`].
+#[cfg(feature = "bevy_render")]
+pub fn visibility_span_system(
+    mut time_span_crossed: EventReader<TimeSpanCrossed>,
+    q_visibility_span: Query<&VisibilitySpan>,
+    mut q_visibility: Query<&mut Visibility>,
+    mut restore: Local<HashMap<Entity, (Entity, Visibility)>>,
+) {
+    for crossed in time_span_crossed.read() {
+        match crossed.crossing {
+            SpanCrossing::Entered => {
+                let Ok(visibility_span) = q_visibility_span.get(crossed.span)
+                else {
+                    continue;
+                };
+                let Ok(mut visibility) =
+                    q_visibility.get_mut(visibility_span.target)
+                else {
+                    continue;
+                };
+                restore
+                    .entry(crossed.span)
+                    .or_insert((visibility_span.target, *visibility));
+                *visibility = visibility_span.visibility;
+            }
+            SpanCrossing::Exited => {
+                let Some((target, previous)) = restore.remove(&crossed.span)
+                else {
+                    continue;
+                };
+                if let Ok(mut visibility) = q_visibility.get_mut(target) {
+                    *visibility = previous;
+                }
+            }
+        }
+    }
+}
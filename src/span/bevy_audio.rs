@@ -0,0 +1,145 @@
+//! Audio cue spans for [`bevy_audio`](bevy::audio)
+//!
+//! **Plugins**:
+//! - [`AudioSpanPlugin`]
+//!
+//! **Components**:
+//! - [`AudioSpan`]
+//!
+//! **Systems**:
+//! - [`audio_span_system`]
+
+use bevy::{
+    audio::{PlaybackSettings, Volume},
+    prelude::*,
+    utils::HashMap,
+};
+use bevy_time_runner::TimeDirection;
+
+use crate::{
+    playback::{self, SpanCrossing, TimeSpanCrossed},
+    tween::SkipTween,
+    TweenSystemSet,
+};
+
+/// Plugin for [`AudioSpan`]
+///
+/// Requires [`PlaybackPlugin`](crate::playback::PlaybackPlugin)
+pub struct AudioSpanPlugin;
+
+impl Plugin for AudioSpanPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            audio_span_system
+                .in_set(TweenSystemSet::ApplyTween)
+                .after(playback::time_span_crossed_system),
+        )
+        .register_type::<AudioSpan>();
+    }
+}
+
+/// Play an audio source when a time runner enters this span while playing
+/// forward.
+///
+/// Like the other spans, it reacts to [`TimeSpanCrossed`] so a span that's
+/// skipped over within a single tick isn't entered.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_time_runner::TimeSpan;
+/// use bevy_tween::{
+///     combinator::AnimationCommands, prelude::*, span::bevy_audio::AudioSpan,
+/// };
+///
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     let footstep = asset_server.load("footstep.ogg");
+///     commands.animation().insert(|a: &mut AnimationCommands, pos: &mut Duration| {
+///         a.spawn((
+///             TimeSpan::try_from(*pos..*pos + Duration::from_millis(100))
+///                 .unwrap(),
+///             AudioSpan::new(footstep),
+///         ));
+///     });
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct AudioSpan {
+    /// Audio to play
+    pub source: Handle<AudioSource>,
+    /// Linear volume of the audio
+    pub volume: f32,
+    /// Stop the audio if the time runner crosses back over the start of
+    /// this span while playing backward
+    pub interrupt_on_reverse: bool,
+}
+
+impl AudioSpan {
+    /// Create new [`AudioSpan`] with full volume that doesn't get
+    /// interrupted.
+    pub fn new(source: Handle<AudioSource>) -> AudioSpan {
+        AudioSpan {
+            source,
+            volume: 1.,
+            interrupt_on_reverse: false,
+        }
+    }
+
+    /// Set the linear volume
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    /// Stop the audio when crossed backward
+    pub fn interrupt_on_reverse(mut self, interrupt: bool) -> Self {
+        self.interrupt_on_reverse = interrupt;
+        self
+    }
+}
+
+/// Play [`AudioSpan`]s entered forward and stop the interruptible ones
+/// exited backward according to [`TimeSpanCrossed`].
+pub fn audio_span_system(
+    mut commands: Commands,
+    mut time_span_crossed: EventReader<TimeSpanCrossed>,
+    q_audio_span: Query<&AudioSpan, Without<SkipTween>>,
+    mut playing: Local<HashMap<Entity, Entity>>,
+) {
+    playing.retain(|_, audio| commands.get_entity(*audio).is_some());
+    for crossed in time_span_crossed.read() {
+        match (crossed.crossing, crossed.direction) {
+            (SpanCrossing::Entered, TimeDirection::Forward) => {
+                let Ok(audio_span) = q_audio_span.get(crossed.span) else {
+                    continue;
+                };
+                let audio = commands
+                    .spawn((
+                        AudioPlayer::new(audio_span.source.clone()),
+                        PlaybackSettings::DESPAWN
+                            .with_volume(Volume::new(audio_span.volume)),
+                    ))
+                    .id();
+                if audio_span.interrupt_on_reverse {
+                    playing.insert(crossed.span, audio);
+                }
+            }
+            (SpanCrossing::Exited, TimeDirection::Backward) => {
+                if let Some(audio) = playing.remove(&crossed.span) {
+                    commands.entity(audio).despawn();
+                }
+            }
+            _ => {}
+        }
+    }
+}
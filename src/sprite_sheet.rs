@@ -0,0 +1,450 @@
+//! Module containing the [`SpriteSheet`] asset to play flipbook animations
+//! exported from Aseprite or described in a generic frame-tag format.
+//!
+//! # Sprite sheet
+//!
+//! **Plugins**:
+//! - [`SpriteSheetPlugin`]
+//!
+//! **Assets**:
+//! - [`SpriteSheet`] loaded by [`SpriteSheetLoader`] from `.aseprite.json`
+//!   or `.sheet.json` files
+//!
+//! **Combinators**:
+//! - [`flipbook`]
+//!
+//! `.aseprite.json` is the JSON data exported by Aseprite with either the
+//! hash or the array frame layout and with frame tags included. `.sheet.json`
+//! is [`SpriteSheet`] itself:
+//!
+//! ```json
+//! {
+//!     "size": [128, 32],
+//!     "frames": [
+//!         { "rect": { "min": [0, 0], "max": [32, 32] }, "duration": 0.1 },
+//!         { "rect": { "min": [32, 0], "max": [64, 32] }, "duration": 0.1 },
+//!         { "rect": { "min": [64, 0], "max": [96, 32] }, "duration": 0.2 },
+//!         { "rect": { "min": [96, 0], "max": [128, 32] }, "duration": 0.1 }
+//!     ],
+//!     "tags": [
+//!         { "name": "walk", "from": 0, "to": 3, "direction": "PingPong" }
+//!     ]
+//! }
+//! ```
+//!
+//! A tag is played with [`flipbook`] as [`SpriteAtlasIndex`] tweens holding
+//! each frame for its own duration. They are regular tweens so they can be
+//! put in the same animation as any other tween:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{
+//!     combinator::{parallel, tween},
+//!     prelude::*,
+//!     sprite_sheet::{flipbook, SpriteSheet},
+//! };
+//!
+//! fn walk(
+//!     commands: &mut Commands,
+//!     sheet: &SpriteSheet,
+//!     mut layouts: ResMut<Assets<TextureAtlasLayout>>,
+//!     image: Handle<Image>,
+//! ) {
+//!     let layout = layouts.add(sheet.texture_atlas_layout());
+//!     let player = commands
+//!         .spawn(Sprite::from_atlas_image(
+//!             image,
+//!             TextureAtlas { layout, index: 0 },
+//!         ))
+//!         .id();
+//!     let target = player.into_target();
+//!     let Some(walk) = flipbook(sheet, "walk", target.clone()) else {
+//!         return;
+//!     };
+//!     commands
+//!         .entity(player)
+//!         .animation()
+//!         .repeat(Repeat::infinitely())
+//!         .insert(parallel((
+//!             walk,
+//!             tween(
+//!                 Duration::from_secs(1),
+//!                 EaseKind::Linear,
+//!                 target.with(interpolate::translation(
+//!                     Vec3::ZERO,
+//!                     Vec3::X * 100.,
+//!                 )),
+//!             ),
+//!         )));
+//! }
+//! ```
+//!
+//! [`SpriteAtlasIndex`]: crate::interpolate::SpriteAtlasIndex
+
+use std::time::Duration;
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    combinator::{tween, AnimationCommands},
+    interpolate::{sprite_atlas_index, SpriteAtlasIndex},
+    interpolation::EaseKind,
+    tween::TargetComponent,
+};
+
+/// Plugin for [`SpriteSheet`]
+pub struct SpriteSheetPlugin;
+
+impl Plugin for SpriteSheetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<SpriteSheet>()
+            .init_asset_loader::<SpriteSheetLoader>();
+    }
+}
+
+/// Frames and frame tags of a sprite sheet. See the [module docs](self) for
+/// the formats.
+#[derive(
+    Debug, Default, Clone, PartialEq, Asset, TypePath, Serialize, Deserialize,
+)]
+pub struct SpriteSheet {
+    /// Size of the sprite sheet image
+    #[serde(default)]
+    pub size: UVec2,
+    /// Frames in atlas index order
+    pub frames: Vec<SpriteSheetFrame>,
+    /// Named ranges of frames
+    #[serde(default)]
+    pub tags: Vec<FrameTag>,
+}
+
+impl SpriteSheet {
+    /// Parse the JSON data exported by Aseprite
+    pub fn from_aseprite_json(
+        bytes: &[u8],
+    ) -> Result<SpriteSheet, serde_json::Error> {
+        let aseprite: AsepriteJson = serde_json::from_slice(bytes)?;
+        Ok(SpriteSheet {
+            size: UVec2::new(aseprite.meta.size.w, aseprite.meta.size.h),
+            frames: aseprite
+                .frames
+                .into_iter()
+                .map(|frame| SpriteSheetFrame {
+                    rect: URect::new(
+                        frame.frame.x,
+                        frame.frame.y,
+                        frame.frame.x + frame.frame.w,
+                        frame.frame.y + frame.frame.h,
+                    ),
+                    duration: frame.duration as f32 / 1000.,
+                })
+                .collect(),
+            tags: aseprite
+                .meta
+                .frame_tags
+                .into_iter()
+                .map(|tag| FrameTag {
+                    name: tag.name,
+                    from: tag.from,
+                    to: tag.to,
+                    direction: match tag.direction.as_str() {
+                        "reverse" => FrameDirection::Reverse,
+                        "pingpong" => FrameDirection::PingPong,
+                        "pingpong_reverse" => FrameDirection::PingPongReverse,
+                        _ => FrameDirection::Forward,
+                    },
+                })
+                .collect(),
+        })
+    }
+
+    /// Get the tag named `name`
+    pub fn tag(&self, name: &str) -> Option<&FrameTag> {
+        self.tags.iter().find(|tag| tag.name == name)
+    }
+
+    /// Create a [`TextureAtlasLayout`] with a texture per frame
+    pub fn texture_atlas_layout(&self) -> TextureAtlasLayout {
+        let mut layout = TextureAtlasLayout::new_empty(self.size);
+        for frame in &self.frames {
+            layout.add_texture(frame.rect);
+        }
+        layout
+    }
+
+    /// [`SpriteAtlasIndex`] for each run of frames of the tag named `name`
+    /// in play order, paired with the duration of the run. A ping-pong tag
+    /// has two runs.
+    ///
+    /// Returns `None` if there's no such tag or it's out of bounds.
+    pub fn tag_runs(
+        &self,
+        name: &str,
+    ) -> Option<Vec<(Duration, SpriteAtlasIndex)>> {
+        let tag = self.tag(name)?;
+        let (from, to) = (tag.from.min(tag.to), tag.from.max(tag.to));
+        if to >= self.frames.len() {
+            return None;
+        }
+        let run = |start: usize, end: usize| {
+            let frames = if start <= end {
+                (start..=end).collect::<Vec<_>>()
+            } else {
+                (end..=start).rev().collect()
+            };
+            let holds = frames
+                .iter()
+                .map(|i| self.frames[*i].duration)
+                .collect::<Vec<_>>();
+            let duration = holds.iter().sum::<f32>().max(0.);
+            (
+                Duration::from_secs_f32(duration),
+                sprite_atlas_index(start, end).with_holds(holds),
+            )
+        };
+        let runs = match tag.direction {
+            FrameDirection::Forward => vec![run(from, to)],
+            FrameDirection::Reverse => vec![run(to, from)],
+            FrameDirection::PingPong if to - from >= 2 => {
+                vec![run(from, to), run(to - 1, from + 1)]
+            }
+            FrameDirection::PingPong => vec![run(from, to)],
+            FrameDirection::PingPongReverse if to - from >= 2 => {
+                vec![run(to, from), run(from + 1, to - 1)]
+            }
+            FrameDirection::PingPongReverse => vec![run(to, from)],
+        };
+        Some(runs)
+    }
+}
+
+/// A frame of [`SpriteSheet`]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpriteSheetFrame {
+    /// Area of the frame in the sprite sheet image
+    #[serde(default)]
+    pub rect: URect,
+    /// Duration of the frame in seconds
+    pub duration: f32,
+}
+
+/// Named range of frames of [`SpriteSheet`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameTag {
+    #[allow(missing_docs)]
+    pub name: String,
+    /// First frame index
+    pub from: usize,
+    /// Last frame index (inclusive)
+    pub to: usize,
+    #[allow(missing_docs)]
+    #[serde(default)]
+    pub direction: FrameDirection,
+}
+
+/// Direction frames of a [`FrameTag`] are played in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum FrameDirection {
+    /// From `from` to `to`
+    #[default]
+    Forward,
+    /// From `to` to `from`
+    Reverse,
+    /// From `from` to `to` then back to the frame after `from`
+    PingPong,
+    /// From `to` to `from` then back to the frame before `to`
+    PingPongReverse,
+}
+
+/// Combinator to play the tag named `name` of `sheet` on `target` as
+/// [`SpriteAtlasIndex`] tweens. See [`SpriteSheet::tag_runs`].
+///
+/// Returns `None` if there's no such tag or it's out of bounds.
+///
+/// Starts from last position and lasts for the total duration of the
+/// frames played.
+///
+/// Position is shifted to the last frame's end.
+pub fn flipbook(
+    sheet: &SpriteSheet,
+    name: &str,
+    target: TargetComponent,
+) -> Option<impl FnOnce(&mut AnimationCommands, &mut Duration)> {
+    Some(flipbook_runs(sheet.tag_runs(name)?, target))
+}
+
+fn flipbook_runs(
+    runs: Vec<(Duration, SpriteAtlasIndex)>,
+    target: TargetComponent,
+) -> impl FnOnce(&mut AnimationCommands, &mut Duration) {
+    move |a, pos| {
+        for (duration, index) in runs {
+            tween(duration, EaseKind::Linear, target.with(index))(a, pos);
+        }
+    }
+}
+
+/// [`AssetLoader`] for [`SpriteSheet`] in Aseprite JSON or in the generic
+/// format
+#[derive(Default)]
+pub struct SpriteSheetLoader;
+
+/// Error from [`SpriteSheetLoader`]
+#[derive(Debug)]
+pub enum SpriteSheetLoaderError {
+    /// Failed to read the file
+    Io(std::io::Error),
+    /// Failed to parse JSON
+    Json(serde_json::Error),
+}
+
+impl core::error::Error for SpriteSheetLoaderError {}
+
+impl core::fmt::Display for SpriteSheetLoaderError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SpriteSheetLoaderError::Io(e) => {
+                write!(f, "Could not read sprite sheet: {e}")
+            }
+            SpriteSheetLoaderError::Json(e) => {
+                write!(f, "Could not parse sprite sheet: {e}")
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for SpriteSheetLoaderError {
+    fn from(e: std::io::Error) -> Self {
+        SpriteSheetLoaderError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for SpriteSheetLoaderError {
+    fn from(e: serde_json::Error) -> Self {
+        SpriteSheetLoaderError::Json(e)
+    }
+}
+
+impl AssetLoader for SpriteSheetLoader {
+    type Asset = SpriteSheet;
+    type Settings = ();
+    type Error = SpriteSheetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<SpriteSheet, SpriteSheetLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let is_aseprite = load_context
+            .path()
+            .to_string_lossy()
+            .ends_with(".aseprite.json");
+        if is_aseprite {
+            Ok(SpriteSheet::from_aseprite_json(&bytes)?)
+        } else {
+            Ok(serde_json::from_slice(&bytes)?)
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["aseprite.json", "sheet.json"]
+    }
+}
+
+#[derive(Deserialize)]
+struct AsepriteJson {
+    #[serde(deserialize_with = "aseprite_frames")]
+    frames: Vec<AsepriteFrame>,
+    meta: AsepriteMeta,
+}
+
+#[derive(Deserialize)]
+struct AsepriteFrame {
+    frame: AsepriteRect,
+    duration: u32,
+}
+
+#[derive(Deserialize)]
+struct AsepriteRect {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct AsepriteSize {
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct AsepriteMeta {
+    size: AsepriteSize,
+    #[serde(rename = "frameTags", default)]
+    frame_tags: Vec<AsepriteFrameTag>,
+}
+
+#[derive(Deserialize)]
+struct AsepriteFrameTag {
+    name: String,
+    from: usize,
+    to: usize,
+    #[serde(default)]
+    direction: String,
+}
+
+/// Frames are either an array or an object keyed by file name. The order
+/// of the object is kept as it's the atlas index order.
+fn aseprite_frames<'de, D>(
+    deserializer: D,
+) -> Result<Vec<AsepriteFrame>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct FramesVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for FramesVisitor {
+        type Value = Vec<AsepriteFrame>;
+
+        fn expecting(
+            &self,
+            f: &mut core::fmt::Formatter<'_>,
+        ) -> core::fmt::Result {
+            f.write_str("an array or a map of frames")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            let mut frames = Vec::new();
+            while let Some(frame) = seq.next_element()? {
+                frames.push(frame);
+            }
+            Ok(frames)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut frames = Vec::new();
+            while let Some((_, frame)) =
+                map.next_entry::<serde::de::IgnoredAny, _>()?
+            {
+                frames.push(frame);
+            }
+            Ok(frames)
+        }
+    }
+
+    deserializer.deserialize_any(FramesVisitor)
+}
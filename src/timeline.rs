@@ -0,0 +1,949 @@
+//! Module containing commands to edit the spans of a playing [`TimeRunner`],
+//! baking of time runners, and an index of their spans.
+//!
+//! # Timeline
+//!
+//! **Plugins**:
+//! - [`TimelineEditPlugin`]
+//! - [`TimelineBakePlugin`]
+//!
+//! **Components**:
+//! - [`SettleSpan`]
+//! - [`DespawnSettled`]
+//! - [`BakeTimeline`]
+//! - [`BakedTimeline`]
+//! - [`TimeSpanIndex`]
+//!
+//! **Commands**:
+//! - [`InsertSpan`]
+//! - [`RemoveSpan`]
+//! - [`ShiftSpan`]
+//!
+//! **Systems**:
+//! - [`settle_span_system`]
+//! - [`despawn_settled_span_system`]
+//! - [`bake_timeline_system`]
+//! - [`baked_timeline_system`]
+//! - [`time_span_index_system`]
+//!
+//! **Events**:
+//! - [`TimelineBaked`]
+//!
+//! ## Editing
+//!
+//! Spawning, despawning, or moving the children of a time runner while it's
+//! playing leaves the targets at whatever value the tweens wrote last. A
+//! span that's removed halfway freezes its target halfway, and a span moved
+//! or inserted behind the playhead never reaches its end value.
+//!
+//! The commands in this module settle the affected spans instead: a span
+//! that ends up entirely behind the playhead is applied once at its end, a
+//! span that ends up entirely ahead of it is applied once at its start, and
+//! a removed span follows its [`SpanRemovalPolicy`].
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_time_runner::TimeSpan;
+//! use bevy_tween::{
+//!     prelude::*,
+//!     timeline::{insert_span, remove_span, shift_span, SpanRemovalPolicy},
+//! };
+//!
+//! fn edit(
+//!     mut commands: Commands,
+//!     time_runner: Entity,
+//!     old_span: Entity,
+//!     late_span: Entity,
+//!     target: Entity,
+//! ) {
+//!     commands.queue(remove_span(old_span, SpanRemovalPolicy::SnapToEnd));
+//!     commands.queue(shift_span(late_span, -0.5));
+//!     commands.queue(insert_span(
+//!         time_runner,
+//!         TimeSpan::try_from(Duration::ZERO..Duration::from_secs(1)).unwrap(),
+//!         (
+//!             EaseKind::Linear,
+//!             target.into_target().with(interpolate::scale(Vec3::ONE, Vec3::ZERO)),
+//!         ),
+//!     ));
+//! }
+//! ```
+//!
+//! ## Baking
+//!
+//! Insert [`BakeTimeline<C>`] to a time runner to sample a component of its
+//! targets at a fixed rate over the time runner played through once. The
+//! samples are taken in one pass over a clone of the time runner, so it
+//! isn't played or ticked meanwhile. The result is [`BakedTimeline<C>`],
+//! which plays the samples back by indexing with the elapsed time of a time
+//! runner instead of evaluating spans, eases, and interpolators.
+//!
+//! The samples are shared, so [`BakedTimeline::with_targets`] is cheap and
+//! the same bake can drive any number of time runners that have no spans of
+//! their own.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_time_runner::TimeRunner;
+//! use bevy_tween::{
+//!     prelude::*,
+//!     timeline::{BakeTimeline, TimelineBaked},
+//! };
+//!
+//! #[derive(Component)]
+//! struct Decoration;
+//!
+//! fn setup(mut commands: Commands) {
+//!     let source = commands.spawn(Transform::default()).id();
+//!     commands
+//!         .animation()
+//!         .insert_tween_here(
+//!             Duration::from_secs(2),
+//!             EaseKind::SineInOut,
+//!             source
+//!                 .into_target()
+//!                 .with(interpolate::translation(Vec3::ZERO, Vec3::Y * 10.)),
+//!         )
+//!         .insert(BakeTimeline::<Transform>::new(60.).with_target(source))
+//!         .observe(
+//!             |trigger: Trigger<TimelineBaked<Transform>>,
+//!              mut commands: Commands,
+//!              q_decoration: Query<Entity, With<Decoration>>| {
+//!                 for decoration in &q_decoration {
+//!                     commands.spawn((
+//!                         TimeRunner::new(Duration::from_secs(2)),
+//!                         trigger.baked.with_targets(vec![decoration]),
+//!                     ));
+//!                 }
+//!             },
+//!         );
+//! }
+//! ```
+//!
+//! ## Indexing
+//!
+//! Every [`TimeRunner`] gets a [`TimeSpanIndex`] of its spans sorted by
+//! start. It's only rebuilt when its [`TimeRunnerSpans`] or one of the
+//! [`TimeSpan`]s change, so finding the spans overlapping a time doesn't need
+//! to visit every span. [`time_span_progress_system`] uses it to only update the
+//! spans overlapping the time a time runner advanced over each frame.
+//!
+//! [`time_span_progress_system`]: crate::playback::time_span_progress_system
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_time_runner::TimeRunner;
+//! use bevy_tween::timeline::TimeSpanIndex;
+//!
+//! fn active_spans(q_time_runner: Query<(&TimeRunner, &TimeSpanIndex)>) {
+//!     for (time_runner, index) in &q_time_runner {
+//!         for span in index.at(time_runner.elasped().now()) {
+//!             info!("{span} is active");
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+use std::any::TypeId;
+
+use bevy::{
+    ecs::system::SystemState, prelude::*, reflect::TypeRegistry, utils::HashSet,
+};
+use bevy_time_runner::{
+    TimeBound, TimeDirection, TimeRunner, TimeSpan, TimeSpanProgress,
+};
+
+use crate::{
+    interpolation::{Interpolation, ReflectInterpolation},
+    playback::TimeRunnerExt,
+    tween::{
+        resolve_tween_targets, AnimationTarget, ReflectComponentTween,
+        ResolvedTargets, SkipTween, TimeRunnerOf, TimeRunnerSpans,
+    },
+    TweenSystemSet,
+};
+
+/// Plugin for [`SettleSpan`]
+pub struct TimelineEditPlugin;
+
+impl Plugin for TimelineEditPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                settle_span_system
+                    .after(bevy_time_runner::TimeRunnerSet::Progress)
+                    .before(TweenSystemSet::UpdateInterpolationValue),
+                despawn_settled_span_system
+                    .after(TweenSystemSet::ApplyAdditiveTween),
+            ),
+        )
+        .register_type::<SettleSpan>()
+        .register_type::<SpanRemovalPolicy>();
+    }
+}
+
+/// Apply this span once at `progress`, from `0.` at its start to `1.` at its
+/// end, regardless of the time runner's elapsed time. Removed by
+/// [`settle_span_system`].
+///
+/// If `despawn` is true, the span is despawned after it's applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct SettleSpan {
+    #[allow(missing_docs)]
+    pub progress: f32,
+    #[allow(missing_docs)]
+    pub despawn: bool,
+}
+
+/// Marker for settled spans to be despawned by
+/// [`despawn_settled_span_system`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Component)]
+pub struct DespawnSettled;
+
+/// What happens to the target of a span removed by [`RemoveSpan`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SpanRemovalPolicy {
+    /// Keep the value the span wrote last
+    #[default]
+    Hold,
+    /// Apply the span at its start before removing it
+    SnapToStart,
+    /// Apply the span at its end before removing it
+    SnapToEnd,
+}
+
+fn span_secs(span: &TimeSpan) -> (f32, f32) {
+    (
+        span.min().duration().as_secs_f32(),
+        span.max().duration().as_secs_f32(),
+    )
+}
+
+fn time_runner_now(world: &World, span: Entity) -> Option<f32> {
+    let TimeRunnerOf(time_runner) = *world.get::<TimeRunnerOf>(span)?;
+    world
+        .get::<TimeRunner>(time_runner)
+        .map(|time_runner| time_runner.elasped().now())
+}
+
+/// Progress to settle a span at if it's entirely behind or ahead of `now`
+fn settle_progress(span: &TimeSpan, now: f32) -> Option<f32> {
+    let (start, end) = span_secs(span);
+    if end <= now {
+        Some(1.)
+    } else if start > now {
+        Some(0.)
+    } else {
+        None
+    }
+}
+
+/// Spawn a span as a child of a playing time runner. It's applied at its end
+/// right away if it's already behind the playhead.
+pub struct InsertSpan<B> {
+    /// The time runner
+    pub time_runner: Entity,
+    #[allow(missing_docs)]
+    pub span: TimeSpan,
+    /// Components of the span such as the interpolation and the tween
+    pub bundle: B,
+}
+
+impl<B> Command for InsertSpan<B>
+where
+    B: Bundle,
+{
+    fn apply(self, world: &mut World) {
+        let Some(now) = world
+            .get::<TimeRunner>(self.time_runner)
+            .map(|time_runner| time_runner.elasped().now())
+        else {
+            return;
+        };
+        let mut span = world.spawn((self.span, self.bundle));
+        if settle_progress(&self.span, now) == Some(1.) {
+            span.insert(SettleSpan {
+                progress: 1.,
+                despawn: false,
+            });
+        }
+        let span = span.id();
+        world.entity_mut(self.time_runner).add_child(span);
+    }
+}
+
+/// Constructor for [`InsertSpan`]
+pub fn insert_span<B>(
+    time_runner: Entity,
+    span: TimeSpan,
+    bundle: B,
+) -> InsertSpan<B>
+where
+    B: Bundle,
+{
+    InsertSpan {
+        time_runner,
+        span,
+        bundle,
+    }
+}
+
+/// Despawn a span of a playing time runner according to `policy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoveSpan {
+    #[allow(missing_docs)]
+    pub span: Entity,
+    #[allow(missing_docs)]
+    pub policy: SpanRemovalPolicy,
+}
+
+impl Command for RemoveSpan {
+    fn apply(self, world: &mut World) {
+        let progress = match self.policy {
+            SpanRemovalPolicy::Hold => {
+                if let Ok(span) = world.get_entity_mut(self.span) {
+                    span.despawn_recursive();
+                }
+                return;
+            }
+            SpanRemovalPolicy::SnapToStart => 0.,
+            SpanRemovalPolicy::SnapToEnd => 1.,
+        };
+        if let Ok(mut span) = world.get_entity_mut(self.span) {
+            span.insert(SettleSpan {
+                progress,
+                despawn: true,
+            });
+        }
+    }
+}
+
+/// Constructor for [`RemoveSpan`]
+pub fn remove_span(span: Entity, policy: SpanRemovalPolicy) -> RemoveSpan {
+    RemoveSpan { span, policy }
+}
+
+/// Move a span of a playing time runner by `offset` seconds, negative to
+/// move it earlier. Bounds are clamped to `0`. An offset that isn't finite
+/// or moves a bound past [`Duration::MAX`] is logged as an error and the
+/// span is left as is.
+///
+/// If the span was reached by the playhead and is moved entirely ahead of
+/// it, it's applied at its start. If it's moved entirely behind the
+/// playhead, it's applied at its end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShiftSpan {
+    #[allow(missing_docs)]
+    pub span: Entity,
+    /// Offset in seconds
+    pub offset: f32,
+}
+
+impl Command for ShiftSpan {
+    fn apply(self, world: &mut World) {
+        let Some(old) = world.get::<TimeSpan>(self.span).copied() else {
+            return;
+        };
+        let shift = |bound: TimeBound| {
+            let secs = (bound.duration().as_secs_f32() + self.offset).max(0.);
+            let duration = Duration::try_from_secs_f32(secs).ok()?;
+            Some(match bound {
+                TimeBound::Inclusive(_) => TimeBound::Inclusive(duration),
+                TimeBound::Exclusive(_) => TimeBound::Exclusive(duration),
+            })
+        };
+        // `f32::max` would turn a NaN offset into `0`
+        let shifted = if self.offset.is_finite() {
+            shift(old.min()).zip(shift(old.max()))
+        } else {
+            None
+        };
+        let Some((min, max)) = shifted else {
+            error!(
+                "Cannot shift span {} by invalid offset {}",
+                self.span, self.offset
+            );
+            return;
+        };
+        let Ok(new) = TimeSpan::new(min, max) else {
+            return;
+        };
+        let now = time_runner_now(world, self.span);
+        let mut span = world.entity_mut(self.span);
+        span.insert(new);
+        let Some(now) = now else {
+            return;
+        };
+        let reached = span_secs(&old).0 <= now;
+        match settle_progress(&new, now) {
+            Some(progress) if progress == 1. || reached => {
+                span.insert(SettleSpan {
+                    progress,
+                    despawn: false,
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Constructor for [`ShiftSpan`]
+pub fn shift_span(span: Entity, offset: f32) -> ShiftSpan {
+    ShiftSpan { span, offset }
+}
+
+/// Set [`TimeSpanProgress`] of every [`SettleSpan`] so the span is applied
+/// at the settled progress this frame.
+pub fn settle_span_system(
+    mut commands: Commands,
+    q_settle: Query<(Entity, &SettleSpan, &TimeSpan)>,
+) {
+    for (entity, settle, span) in &q_settle {
+        let (start, end) = span_secs(span);
+        let progress = settle.progress.clamp(0., 1.);
+        let now = start + (end - start) * progress;
+        let mut entity = commands.entity(entity);
+        entity.remove::<SettleSpan>().insert(TimeSpanProgress {
+            now_percentage: progress,
+            now,
+            previous_percentage: progress,
+            previous: now,
+        });
+        if settle.despawn {
+            entity.insert(DespawnSettled);
+        }
+    }
+}
+
+/// Despawn spans removed by [`RemoveSpan`] once they're settled
+pub fn despawn_settled_span_system(
+    mut commands: Commands,
+    q_settled: Query<Entity, With<DespawnSettled>>,
+) {
+    for entity in &q_settled {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Plugin for [`BakeTimeline<C>`] and [`BakedTimeline<C>`]
+pub struct TimelineBakePlugin<C>(PhantomData<C>);
+
+impl<C> Default for TimelineBakePlugin<C> {
+    fn default() -> Self {
+        TimelineBakePlugin(PhantomData)
+    }
+}
+
+impl<C> Plugin for TimelineBakePlugin<C>
+where
+    C: Component + Clone + PartialEq,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            (
+                baked_timeline_system::<C>.in_set(TweenSystemSet::ApplyTween),
+                bake_timeline_system::<C>
+                    .after(TweenSystemSet::ApplyAdditiveTween)
+                    .run_if(any_with_component::<BakeTimeline<C>>),
+            ),
+        );
+    }
+}
+
+/// Sample `C` of the targets over this [`TimeRunner`] played through once
+/// from the start, then trigger [`TimelineBaked<C>`] on it.
+///
+/// Sampled by [`bake_timeline_system`] in one pass without playing the time
+/// runner, and removed once baked.
+#[derive(Debug, Clone, Component)]
+pub struct BakeTimeline<C>
+where
+    C: Component + Clone,
+{
+    /// Samples per second
+    pub sample_rate: f32,
+    /// Entities to sample
+    pub targets: Vec<Entity>,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C> BakeTimeline<C>
+where
+    C: Component + Clone,
+{
+    /// Create new [`BakeTimeline`] sampling at `sample_rate` samples per
+    /// second
+    pub fn new(sample_rate: f32) -> BakeTimeline<C> {
+        BakeTimeline {
+            sample_rate,
+            targets: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Add a target entity to sample
+    pub fn with_target(mut self, entity: Entity) -> Self {
+        self.targets.push(entity);
+        self
+    }
+}
+
+/// Samples of `C` from [`BakeTimeline<C>`], one track per target.
+///
+/// Plays back on the [`TimeRunner`] it's inserted to by writing the sample
+/// nearest to the elapsed time to each target.
+#[derive(Debug, Clone, Component)]
+pub struct BakedTimeline<C>
+where
+    C: Component + Clone,
+{
+    /// Samples per second
+    pub sample_rate: f32,
+    /// Entities written by the tracks in order
+    pub targets: Vec<Entity>,
+    tracks: Arc<[Vec<C>]>,
+}
+
+impl<C> BakedTimeline<C>
+where
+    C: Component + Clone,
+{
+    /// The same samples written to other entities
+    pub fn with_targets(&self, targets: Vec<Entity>) -> Self {
+        BakedTimeline {
+            sample_rate: self.sample_rate,
+            targets,
+            tracks: self.tracks.clone(),
+        }
+    }
+
+    /// Samples of the track at `index`
+    pub fn track(&self, index: usize) -> Option<&[C]> {
+        self.tracks.get(index).map(Vec::as_slice)
+    }
+
+    /// Number of tracks
+    pub fn track_count(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// Index of the sample nearest to `secs`
+    pub fn sample_index(&self, secs: f32) -> usize {
+        let last = self
+            .tracks
+            .first()
+            .map(|track| track.len().saturating_sub(1))
+            .unwrap_or(0);
+        ((secs.max(0.) * self.sample_rate).round() as usize).min(last)
+    }
+}
+
+/// Triggered on the time runner when [`BakeTimeline<C>`] is done
+#[derive(Debug, Clone, Event)]
+pub struct TimelineBaked<C>
+where
+    C: Component + Clone,
+{
+    /// The time runner that was baked
+    pub time_runner: Entity,
+    #[allow(missing_docs)]
+    pub baked: BakedTimeline<C>,
+}
+
+type BakeTimelineQueries<'w, 's, C> = (
+    Query<'w, 's, (Entity, &'static BakeTimeline<C>, &'static TimeRunner)>,
+    Query<'w, 's, &'static TimeRunnerSpans>,
+    Query<'w, 's, &'static Children>,
+    Query<'w, 's, &'static TimeSpan, Without<SkipTween>>,
+    Query<'w, 's, (Option<&'static Parent>, Has<AnimationTarget>)>,
+    Query<'w, 's, &'static ResolvedTargets>,
+);
+
+/// Sample `C` of the targets of every [`BakeTimeline<C>`] in one pass over a
+/// clone of its time runner, then trigger [`TimelineBaked<C>`].
+///
+/// The spans are evaluated from their reflected interpolation and
+/// [`ComponentTween`](crate::tween::ComponentTween)s with
+/// [`ReflectInterpolation`] and
+/// [`ReflectComponentTween`], which are registered for the built-in ones.
+/// Spans that have neither, such as of nested time runners, are left out.
+pub fn bake_timeline_system<C>(
+    world: &mut World,
+    state: &mut SystemState<BakeTimelineQueries<C>>,
+) where
+    C: Component + Clone,
+{
+    let baked = {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let (
+            q_bake,
+            q_time_runner_spans,
+            q_children,
+            q_time_span,
+            q_animation_target,
+            q_resolved_targets,
+        ) = state.get(world);
+        q_bake
+            .iter()
+            .map(|(entity, bake, time_runner)| {
+                let spans = match q_time_runner_spans.get(entity) {
+                    Ok(spans) => spans.0.clone(),
+                    Err(_) => q_children
+                        .get(entity)
+                        .map(|children| children.to_vec())
+                        .unwrap_or_default(),
+                };
+                let mut spans = spans
+                    .into_iter()
+                    .filter_map(|span| {
+                        let time_span = q_time_span.get(span).ok()?;
+                        BakeSpan::new::<C>(
+                            world,
+                            &registry,
+                            span,
+                            time_span,
+                            &bake.targets,
+                            &q_animation_target,
+                            &q_resolved_targets,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                spans.sort_by(|a, b| {
+                    a.start.total_cmp(&b.start).then(a.entity.cmp(&b.entity))
+                });
+                let baked = BakedTimeline {
+                    sample_rate: bake.sample_rate,
+                    targets: bake.targets.clone(),
+                    tracks: bake_tracks(world, bake, time_runner, &spans)
+                        .into(),
+                };
+                (entity, baked)
+            })
+            .collect::<Vec<_>>()
+    };
+    for (entity, baked) in baked {
+        world.entity_mut(entity).remove::<BakeTimeline<C>>();
+        world.trigger_targets(
+            TimelineBaked {
+                time_runner: entity,
+                baked,
+            },
+            entity,
+        );
+    }
+}
+
+/// Span evaluated by [`bake_timeline_system`]
+struct BakeSpan {
+    entity: Entity,
+    start: f32,
+    end: f32,
+    interpolation: (ReflectComponent, ReflectInterpolation),
+    /// Tweens of the span and the indices of the baked targets they write
+    tweens: Vec<(ReflectComponent, ReflectComponentTween, Vec<usize>)>,
+}
+
+impl BakeSpan {
+    fn new<C: Component>(
+        world: &World,
+        registry: &TypeRegistry,
+        entity: Entity,
+        span: &TimeSpan,
+        targets: &[Entity],
+        q_animation_target: &Query<(Option<&Parent>, Has<AnimationTarget>)>,
+        q_resolved_targets: &Query<&ResolvedTargets>,
+    ) -> Option<BakeSpan> {
+        let entity_ref = world.get_entity(entity).ok()?;
+        let registrations = entity_ref
+            .archetype()
+            .components()
+            .filter_map(|id| world.components().get_info(id)?.type_id())
+            .filter_map(|type_id| registry.get(type_id))
+            .collect::<Vec<_>>();
+        let interpolation = registrations.iter().find_map(|registration| {
+            Some((
+                registration.data::<ReflectComponent>()?.clone(),
+                registration.data::<ReflectInterpolation>()?.clone(),
+            ))
+        })?;
+        let tweens = registrations
+            .iter()
+            .filter_map(|registration| {
+                let component = registration.data::<ReflectComponent>()?;
+                let tween = registration.data::<ReflectComponentTween>()?;
+                if tween.item() != TypeId::of::<C>() {
+                    return None;
+                }
+                let target = tween.target(component.reflect(entity_ref)?)?;
+                let indices = resolve_tween_targets::<C>(
+                    entity,
+                    target,
+                    q_animation_target,
+                    q_resolved_targets,
+                    None,
+                )?
+                .iter()
+                .filter_map(|target| targets.iter().position(|t| t == target))
+                .collect::<Vec<_>>();
+                Some((component.clone(), tween.clone(), indices))
+            })
+            .collect::<Vec<_>>();
+        if tweens.is_empty() {
+            return None;
+        }
+        let (start, end) = span_secs(span);
+        Some(BakeSpan {
+            entity,
+            start,
+            end,
+            interpolation,
+            tweens,
+        })
+    }
+
+    /// Apply the span to `values` if it's reached between `previous` and
+    /// `now`, at its end if it's passed over.
+    fn apply<C: Component>(
+        &self,
+        world: &World,
+        previous: f32,
+        now: f32,
+        values: &mut [Option<C>],
+    ) {
+        if self.start > now || self.end < previous {
+            return;
+        }
+        let progress = if self.end > self.start {
+            ((now - self.start) / (self.end - self.start)).clamp(0., 1.)
+        } else {
+            1.
+        };
+        let Ok(entity_ref) = world.get_entity(self.entity) else {
+            return;
+        };
+        let (component, interpolation) = &self.interpolation;
+        let Some(value) = component
+            .reflect(entity_ref)
+            .and_then(|reflect| interpolation.get(reflect))
+            .map(|interpolation| interpolation.sample(progress))
+        else {
+            return;
+        };
+        for (component, tween, indices) in &self.tweens {
+            let Some(reflect) = component.reflect(entity_ref) else {
+                continue;
+            };
+            for index in indices {
+                if let Some(item) = values[*index].as_mut() {
+                    tween.interpolate(reflect, item, value);
+                }
+            }
+        }
+    }
+}
+
+/// Step a clone of `time_runner` forward from the start by the sample rate
+/// of `bake` and record `C` of the targets with `spans` applied at each step.
+fn bake_tracks<C>(
+    world: &World,
+    bake: &BakeTimeline<C>,
+    time_runner: &TimeRunner,
+    spans: &[BakeSpan],
+) -> Vec<Vec<C>>
+where
+    C: Component + Clone,
+{
+    let mut values = bake
+        .targets
+        .iter()
+        .map(|target| world.get::<C>(*target).cloned())
+        .collect::<Vec<_>>();
+    let mut tracks = vec![Vec::new(); bake.targets.len()];
+    let mut time_runner = time_runner.clone();
+    time_runner
+        .set_direction(TimeDirection::Forward)
+        .set_repeat(None)
+        .restart();
+    let length = time_runner.length().as_secs_f32();
+    loop {
+        let elasped = time_runner.elasped();
+        for span in spans {
+            span.apply(world, elasped.previous(), elasped.now(), &mut values);
+        }
+        for (value, track) in values.iter().zip(&mut tracks) {
+            if let Some(value) = value {
+                track.push(value.clone());
+            }
+        }
+        let now = elasped.now();
+        if now >= length || bake.sample_rate <= 0. {
+            return tracks;
+        }
+        // `TimeRunner::tick` would apply the time scale to the step as well
+        time_runner.raw_tick((1. / bake.sample_rate).min(length - now));
+    }
+}
+
+/// Write the sample of every [`BakedTimeline<C>`] at the elapsed time of its
+/// [`TimeRunner`] to the targets that aren't at it already.
+pub fn baked_timeline_system<C>(
+    q_baked: Query<(&TimeRunner, &BakedTimeline<C>)>,
+    mut q_component: Query<&mut C>,
+) where
+    C: Component + Clone + PartialEq,
+{
+    for (time_runner, baked) in &q_baked {
+        let index = baked.sample_index(time_runner.elasped().now());
+        for (target, track) in baked.targets.iter().zip(baked.tracks.iter()) {
+            let (Some(sample), Ok(mut component)) =
+                (track.get(index), q_component.get_mut(*target))
+            else {
+                continue;
+            };
+            component.set_if_neq(sample.clone());
+        }
+    }
+}
+
+/// Spans in [`TimeRunnerSpans`] of a [`TimeRunner`] sorted by start,
+/// maintained by [`time_span_index_system`]
+#[derive(Debug, Default, Clone, PartialEq, Component)]
+pub struct TimeSpanIndex {
+    /// Start, end, and entity of each span sorted by start
+    spans: Vec<(f32, f32, Entity)>,
+    /// Running maximum of the ends of `spans`
+    max_end: Vec<f32>,
+}
+
+impl TimeSpanIndex {
+    /// Create new [`TimeSpanIndex`] from spans in any order
+    pub fn new(
+        spans: impl IntoIterator<Item = (Entity, TimeSpan)>,
+    ) -> TimeSpanIndex {
+        let mut spans = spans
+            .into_iter()
+            .map(|(entity, span)| {
+                let (start, end) = span_secs(&span);
+                (start, end, entity)
+            })
+            .collect::<Vec<_>>();
+        spans.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.2.cmp(&b.2)));
+        let max_end = spans
+            .iter()
+            .scan(f32::NEG_INFINITY, |max_end, (_, end, _)| {
+                *max_end = max_end.max(*end);
+                Some(*max_end)
+            })
+            .collect();
+        TimeSpanIndex { spans, max_end }
+    }
+
+    /// Number of spans
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Returns true if there's no span
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns true if `span` is in this index
+    pub fn contains(&self, span: Entity) -> bool {
+        self.spans.iter().any(|(_, _, entity)| *entity == span)
+    }
+
+    /// Every span with its start and end in seconds, sorted by start
+    pub fn spans(&self) -> impl Iterator<Item = (Entity, f32, f32)> + '_ {
+        self.spans
+            .iter()
+            .map(|(start, end, entity)| (*entity, *start, *end))
+    }
+
+    /// Spans overlapping `start..=end` in seconds, sorted by start
+    pub fn overlapping(
+        &self,
+        start: f32,
+        end: f32,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        let upper = self.spans.partition_point(|(s, _, _)| *s <= end);
+        let lower = self.max_end[..upper].partition_point(|e| *e < start);
+        self.spans[lower..upper]
+            .iter()
+            .filter(move |(_, e, _)| *e >= start)
+            .map(|(_, _, entity)| *entity)
+    }
+
+    /// Spans containing `secs`, sorted by start
+    pub fn at(&self, secs: f32) -> impl Iterator<Item = Entity> + '_ {
+        self.overlapping(secs, secs)
+    }
+}
+
+/// Insert [`TimeSpanIndex`] to every [`TimeRunner`] and rebuild it when
+/// [`TimeRunnerSpans`] of the time runner or [`TimeSpan`] of one of its
+/// spans changes. Registered by [`TweenCorePlugin`](crate::TweenCorePlugin).
+#[allow(clippy::type_complexity)]
+pub fn time_span_index_system(
+    mut commands: Commands,
+    q_time_runner: Query<(Entity, Option<&TimeRunnerSpans>), With<TimeRunner>>,
+    q_changed_time_runner: Query<
+        Entity,
+        (
+            With<TimeRunner>,
+            Or<(
+                Added<TimeRunner>,
+                Changed<TimeRunnerSpans>,
+                Without<TimeSpanIndex>,
+            )>,
+        ),
+    >,
+    q_changed_span: Query<&TimeRunnerOf, Changed<TimeSpan>>,
+    q_time_span: Query<&TimeSpan>,
+) {
+    let dirty = q_changed_time_runner
+        .iter()
+        .chain(q_changed_span.iter().map(|of| of.0))
+        .collect::<HashSet<_>>();
+    for time_runner in dirty {
+        let Ok((entity, spans)) = q_time_runner.get(time_runner) else {
+            continue;
+        };
+        let index = TimeSpanIndex::new(
+            spans
+                .into_iter()
+                .flat_map(|spans| &spans.0)
+                .filter_map(|span| {
+                    q_time_span
+                        .get(*span)
+                        .ok()
+                        .map(|time_span| (*span, *time_span))
+                }),
+        );
+        commands.entity(entity).insert(index);
+    }
+}
@@ -0,0 +1,399 @@
+//! Module containing CSS-like transitions that tween to a declared value
+//! whenever it changes.
+//!
+//! # Transition
+//!
+//! **Plugins**:
+//! - [`TransitionPlugin`]
+//! - [`InteractionTweenPlugin`] with the `bevy_ui` feature
+//!
+//! **Components**:
+//! - [`Transitions`]
+//! - [`InteractionTween`] with the `bevy_ui` feature
+//!
+//! **Systems**:
+//! - [`transition_system`]
+//! - [`interaction_tween_system`] with the `bevy_ui` feature
+//!
+//! Implement [`Transition`] for a component holding the desired value, then
+//! insert it with [`Transitions`] to the target. Every time the desired
+//! value changes, a tween from the current value to it is spawned. A running
+//! transition is replaced by the new one so retargeting halfway starts from
+//! wherever the target is at.
+//!
+//! Changes are found by change detection, so a desired value that's written
+//! every frame should be set with
+//! [`set_if_neq`](bevy::ecs::change_detection::DetectChangesMut::set_if_neq).
+//!
+//! The tween system of [`Transition::Interpolator`] must be registered, which
+//! is already done for the built-in interpolators by
+//! [`DefaultTweenPlugins`](crate::DefaultTweenPlugins).
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{
+//!     interpolate::SpriteColor,
+//!     prelude::*,
+//!     transition::{Transition, TransitionPlugin, Transitions},
+//! };
+//!
+//! #[derive(Component, PartialEq)]
+//! struct DesiredColor(Color);
+//!
+//! impl Transition for DesiredColor {
+//!     type Interpolator = SpriteColor;
+//!
+//!     fn interpolator(&self, current: &Sprite) -> SpriteColor {
+//!         interpolate::sprite_color(current.color, self.0)
+//!     }
+//! }
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn((
+//!         Sprite::default(),
+//!         DesiredColor(Color::WHITE),
+//!         Transitions::<DesiredColor>::new(
+//!             Duration::from_millis(200),
+//!             EaseKind::QuadraticOut,
+//!         ),
+//!     ));
+//! }
+//!
+//! fn hover(mut q_desired: Query<&mut DesiredColor>) {
+//!     for mut desired in &mut q_desired {
+//!         // Only change it when it's different or a transition would be
+//!         // spawned every frame
+//!         desired.set_if_neq(DesiredColor(Color::srgb(1., 0.5, 0.)));
+//!     }
+//! }
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((
+//!             DefaultPlugins,
+//!             DefaultTweenPlugins,
+//!             TransitionPlugin::<DesiredColor>::default(),
+//!         ))
+//!         .add_systems(Startup, setup)
+//!         .add_systems(Update, hover)
+//!         .run();
+//! }
+//! ```
+//!
+//! ## Interaction
+//!
+//! [`InteractionTween`] sets the desired value for each [`Interaction`] state
+//! so hover and press feedback transitions automatically.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_tween::{
+//!     interpolate::Scale,
+//!     prelude::*,
+//!     transition::{InteractionTween, InteractionTweenPlugin, Transition},
+//! };
+//!
+//! #[derive(Clone, Component)]
+//! struct ButtonScale(Vec3);
+//!
+//! impl Transition for ButtonScale {
+//!     type Interpolator = Scale;
+//!
+//!     fn interpolator(&self, current: &Transform) -> Scale {
+//!         interpolate::scale(current.scale, self.0)
+//!     }
+//! }
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands.spawn((
+//!         Button,
+//!         InteractionTween::new(
+//!             ButtonScale(Vec3::ONE),
+//!             ButtonScale(Vec3::splat(1.1)),
+//!             ButtonScale(Vec3::splat(0.95)),
+//!             Duration::from_millis(150),
+//!         )
+//!         .with_pressed_duration(Duration::from_millis(50))
+//!         .bundle(EaseKind::QuadraticOut),
+//!     ));
+//! }
+//!
+//! fn main() {
+//!     App::new()
+//!         .add_plugins((
+//!             DefaultPlugins,
+//!             DefaultTweenPlugins,
+//!             InteractionTweenPlugin::<ButtonScale>::default(),
+//!         ))
+//!         .add_systems(Startup, setup)
+//!         .run();
+//! }
+//! ```
+
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::{
+    combinator::AnimationBuilderExt, interpolate::Interpolator,
+    interpolation::EaseKind, playback::CompletionPolicy, tween::IntoTarget,
+    TweenSystemSet,
+};
+
+/// Plugin for [`Transitions<C>`]
+pub struct TransitionPlugin<C>(PhantomData<C>);
+
+impl<C> Default for TransitionPlugin<C> {
+    fn default() -> Self {
+        TransitionPlugin(PhantomData)
+    }
+}
+
+impl<C> Plugin for TransitionPlugin<C>
+where
+    C: Transition,
+    <C::Interpolator as Interpolator>::Item: Component,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            transition_system::<C>.before(TweenSystemSet::TickTimeRunner),
+        );
+    }
+}
+
+/// Component holding a desired value to transition to
+pub trait Transition: Component {
+    /// Interpolator tweening the target toward the desired value
+    type Interpolator: Interpolator + Send + Sync + 'static;
+
+    /// Create the interpolator from the `current` value of the target to
+    /// this desired value
+    fn interpolator(
+        &self,
+        current: &<Self::Interpolator as Interpolator>::Item,
+    ) -> Self::Interpolator;
+}
+
+/// Tween this entity whenever `C` changes, by [`transition_system`]
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct Transitions<C> {
+    /// Duration of a transition
+    pub duration: Duration,
+    /// Easing of a transition
+    pub ease: EaseKind,
+    time_runner: Option<Entity>,
+    marker: PhantomData<fn() -> C>,
+}
+
+impl<C> Transitions<C> {
+    /// Create new [`Transitions`]
+    pub fn new(duration: Duration, ease: EaseKind) -> Transitions<C> {
+        Transitions {
+            duration,
+            ease,
+            time_runner: None,
+            marker: PhantomData,
+        }
+    }
+
+    /// Time runner of the running transition if exists
+    pub fn time_runner(&self) -> Option<Entity> {
+        self.time_runner
+    }
+}
+
+/// Spawn a transition for every changed `C`, replacing the running one.
+///
+/// The value `C` is inserted with is not transitioned to. The time runner
+/// of a transition is despawned once it completes.
+#[allow(clippy::type_complexity)]
+pub fn transition_system<C>(
+    mut commands: Commands,
+    mut q_transition: Query<
+        (
+            Entity,
+            Ref<C>,
+            &mut Transitions<C>,
+            &<C::Interpolator as Interpolator>::Item,
+        ),
+        Changed<C>,
+    >,
+) where
+    C: Transition,
+    <C::Interpolator as Interpolator>::Item: Component,
+{
+    for (entity, desired, mut transitions, current) in &mut q_transition {
+        if desired.is_added() {
+            continue;
+        }
+        if let Some(time_runner) = transitions.time_runner {
+            if let Some(time_runner) = commands.get_entity(time_runner) {
+                time_runner.despawn_recursive();
+            }
+        }
+        let time_runner = commands
+            .animation()
+            .insert_tween_here(
+                transitions.duration,
+                transitions.ease,
+                entity.into_target().with(desired.interpolator(current)),
+            )
+            .insert(CompletionPolicy::DespawnRecursive)
+            .id();
+        transitions.time_runner = Some(time_runner);
+    }
+}
+
+/// Plugin for [`InteractionTween<C>`], also adds [`TransitionPlugin<C>`] if
+/// not already added
+#[cfg(feature = "bevy_ui")]
+pub struct InteractionTweenPlugin<C>(PhantomData<C>);
+
+#[cfg(feature = "bevy_ui")]
+impl<C> Default for InteractionTweenPlugin<C> {
+    fn default() -> Self {
+        InteractionTweenPlugin(PhantomData)
+    }
+}
+
+#[cfg(feature = "bevy_ui")]
+impl<C> Plugin for InteractionTweenPlugin<C>
+where
+    C: Transition + Clone,
+    <C::Interpolator as Interpolator>::Item: Component,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<TransitionPlugin<C>>() {
+            app.add_plugins(TransitionPlugin::<C>::default());
+        }
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            interaction_tween_system::<C>.before(transition_system::<C>),
+        );
+    }
+}
+
+/// Desired value `C` and transition duration for each [`Interaction`]
+/// state, applied by [`interaction_tween_system`]
+#[cfg(feature = "bevy_ui")]
+#[derive(Debug, Clone, PartialEq, Component)]
+#[require(Interaction)]
+pub struct InteractionTween<C> {
+    /// Value for [`Interaction::None`]
+    pub none: C,
+    /// Value for [`Interaction::Hovered`]
+    pub hovered: C,
+    /// Value for [`Interaction::Pressed`]
+    pub pressed: C,
+    /// Duration of the transition to [`Interaction::None`]
+    pub none_duration: Duration,
+    /// Duration of the transition to [`Interaction::Hovered`]
+    pub hovered_duration: Duration,
+    /// Duration of the transition to [`Interaction::Pressed`]
+    pub pressed_duration: Duration,
+}
+
+#[cfg(feature = "bevy_ui")]
+impl<C> InteractionTween<C>
+where
+    C: Transition + Clone,
+{
+    /// Create new [`InteractionTween`] transitioning to every state in
+    /// `duration`
+    pub fn new(
+        none: C,
+        hovered: C,
+        pressed: C,
+        duration: Duration,
+    ) -> InteractionTween<C> {
+        InteractionTween {
+            none,
+            hovered,
+            pressed,
+            none_duration: duration,
+            hovered_duration: duration,
+            pressed_duration: duration,
+        }
+    }
+
+    /// Set the duration of the transition to [`Interaction::None`]
+    pub fn with_none_duration(mut self, duration: Duration) -> Self {
+        self.none_duration = duration;
+        self
+    }
+
+    /// Set the duration of the transition to [`Interaction::Hovered`]
+    pub fn with_hovered_duration(mut self, duration: Duration) -> Self {
+        self.hovered_duration = duration;
+        self
+    }
+
+    /// Set the duration of the transition to [`Interaction::Pressed`]
+    pub fn with_pressed_duration(mut self, duration: Duration) -> Self {
+        self.pressed_duration = duration;
+        self
+    }
+
+    /// Value and transition duration for `interaction`
+    pub fn get(&self, interaction: Interaction) -> (&C, Duration) {
+        match interaction {
+            Interaction::None => (&self.none, self.none_duration),
+            Interaction::Hovered => (&self.hovered, self.hovered_duration),
+            Interaction::Pressed => (&self.pressed, self.pressed_duration),
+        }
+    }
+
+    /// This with the starting value `C` and [`Transitions<C>`] using `ease`
+    pub fn bundle(self, ease: EaseKind) -> (C, Transitions<C>, Self) {
+        (
+            self.none.clone(),
+            Transitions::new(self.none_duration, ease),
+            self,
+        )
+    }
+}
+
+/// Set `C` to the value of the current [`Interaction`] of every
+/// [`InteractionTween<C>`] so [`transition_system`] tweens to it
+#[cfg(feature = "bevy_ui")]
+#[allow(clippy::type_complexity)]
+pub fn interaction_tween_system<C>(
+    mut q_interaction: Query<
+        (
+            &Interaction,
+            &InteractionTween<C>,
+            &mut C,
+            &mut Transitions<C>,
+        ),
+        Changed<Interaction>,
+    >,
+) where
+    C: Transition + Clone,
+{
+    for (interaction, interaction_tween, mut desired, mut transitions) in
+        &mut q_interaction
+    {
+        let (value, duration) = interaction_tween.get(*interaction);
+        transitions.duration = duration;
+        *desired = value.clone();
+    }
+}
@@ -2,15 +2,43 @@
 //!
 //! # Tween
 //!
+//! **Plugins**:
+//! - [`TargetMarkerPlugin`]
+//! - [`TargetPathPlugin`]
+//! - [`TweenGroupPlugin`]
+//!
 //! **Components**:
 //! - [`Tween<T, I>`]
 //! - [`SkipTween`]
+//! - [`Additive`]
 //! - [`TweenInterpolationValue`]
+//! - [`TargetLostPolicy`]
+//! - [`ConflictPolicy`]
+//! - [`ResolvedTargets`]
+//! - [`TimeRunnerOf`]
+//! - [`TimeRunnerSpans`]
+//! - [`TargetMarker`]
+//! - [`TargetName`]
+//! - [`TargetPath`]
+//! - [`TweenGroup`]
+//!
+//! **Resources**:
+//! - [`TweenGroups`]
+//! - [`BlendTweenRegistered`]
+//! - [`TweenFieldWrites`]
+//!
+//! **Events**:
+//! - [`TweenTargetLost`]
 //!
 //! **Systems**
 //! - [`component_tween_system`]
+//! - [`relative_component_tween_system`]
+//! - [`blend_component_tween_system`]
+//! - [`additive_component_tween_system`]
+//! - [`component_tween_from_current_system`]
 //! - [`resource_tween_system`]
 //! - [`asset_tween_system`]
+//! - [`time_runner_of_system`]
 //!
 //! **Targets**:
 //! - [`TargetComponent`]
@@ -211,30 +239,79 @@
 //! [`DefaultInterpolatorsPlugin`]: crate::interpolate::DefaultInterpolatorsPlugin
 //! [`DefaultDynInterpolatorsPlugin`]: crate::interpolate::DefaultDynInterpolatorsPlugin
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::{
+        entity::MapEntities, reflect::ReflectMapEntities, system::SystemParam,
+    },
+    prelude::*,
+    reflect::{FromType, GetTypeRegistration, TypePath},
+    utils::{HashMap, HashSet},
+};
+use std::any::{Any, TypeId};
+
+use bevy_time_runner::{TimeRunner, TimeSpan};
+use std::time::Duration;
 
 use crate::combinator::TargetState;
-use crate::interpolate::Interpolator;
+use crate::interpolate::{Blend, CaptureStart, FromCurrent, Interpolator};
+use crate::playback::{BlendWeight, NestedTimeRunner};
 
+mod group;
+mod resolve;
 mod systems;
+pub use group::{
+    tween_group_system, MutedByGroup, TweenGroup, TweenGroupPlugin, TweenGroups,
+};
+#[cfg(feature = "bevy_animation")]
+pub use resolve::{resolve_target_joint_system, TargetJoint};
+pub use resolve::{
+    resolve_target_marker_system, resolve_target_path_system, TargetMarker,
+    TargetMarkerPlugin, TargetName, TargetPath, TargetPathPlugin,
+};
+pub use systems::{
+    additive_component_tween_system, apply_additive_component_tween_system,
+    prepare_additive_tween_writes_system, AdditiveTweenWrites,
+};
 #[cfg(feature = "bevy_asset")]
 pub use systems::{
     apply_asset_tween_system, asset_dyn_tween_system, asset_tween_system,
 };
+pub use systems::{
+    apply_blend_component_tween_system, blend_component_tween_system,
+    BlendTweenRegistered,
+};
 pub use systems::{
     apply_component_tween_system, component_dyn_tween_system,
-    component_tween_system,
+    component_tween_system, prepare_tween_field_writes_system,
+    TweenFieldWrites, PARALLEL_APPLY_MIN_TARGETS,
+};
+pub use systems::{
+    apply_relative_component_tween_system, relative_component_tween_system,
 };
 pub use systems::{
     apply_resource_tween_system, resource_dyn_tween_system,
     resource_tween_system,
 };
+pub use systems::{
+    capture_start_component_tween_system, component_tween_from_current_system,
+};
 
 /// Skip a tween from tweening.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
 #[reflect(Component)]
 pub struct SkipTween;
 
+/// Mark a tween with a relative interpolator such as
+/// [`TranslationBy`](crate::interpolate::TranslationBy) as additive.
+///
+/// Additive tweens are applied by [`additive_component_tween_system`] in
+/// [`TweenSystemSet::ApplyAdditiveTween`](crate::TweenSystemSet::ApplyAdditiveTween)
+/// on top of whatever was written to the target that frame, such as a base
+/// animation or gameplay code, instead of accumulating on the target.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct Additive;
+
 /// Automatically managed by an [`Interpolation`] such as [`EaseKind`] and
 /// [`EaseClosure`] when a tween has the component [`TimeSpanProgress`](bevy_time_runner::TimeSpanProgress).
 /// See [`sample_interpolations_system`]
@@ -248,6 +325,10 @@ pub struct SkipTween;
 pub struct TweenInterpolationValue(pub f32);
 
 /// Containing `target` and `interpolator`
+///
+/// Entities in `target` are remapped when spawned from a scene if
+/// [`ReflectMapEntities`] is registered for the tween, see
+/// [`RegisterComponentTween`]. The built-in [`ComponentTween`]s have it.
 #[derive(
     Debug, Default, Component, Clone, Copy, PartialEq, Eq, Hash, Reflect,
 )]
@@ -320,6 +401,107 @@ pub type ComponentTween<I> = Tween<TargetComponent, I>;
 pub type ComponentDynTween<C> =
     Tween<TargetComponent, Box<dyn Interpolator<Item = C>>>;
 
+/// Convenient alias for [`Tween`] that [`TargetComponent`] with
+/// [`ReflectField`](crate::interpolate::ReflectField).
+pub type ReflectTween =
+    Tween<TargetComponent, crate::interpolate::ReflectField>;
+
+impl<T, I> MapEntities for Tween<T, I>
+where
+    T: MapEntities,
+{
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.target.map_entities(entity_mapper);
+    }
+}
+
+/// Register a [`ComponentTween`] for reflection along with
+/// [`ReflectMapEntities`] so its target entities are remapped when spawned
+/// from a scene, and [`ReflectComponentTween`].
+pub trait RegisterComponentTween {
+    /// Register [`ComponentTween<I>`], its [`ReflectMapEntities`] and
+    /// [`ReflectComponentTween`]
+    fn register_component_tween<I>(&mut self) -> &mut Self
+    where
+        I: Interpolator,
+        I::Item: 'static,
+        ComponentTween<I>: GetTypeRegistration + Reflect + TypePath + Component;
+}
+
+impl RegisterComponentTween for App {
+    fn register_component_tween<I>(&mut self) -> &mut Self
+    where
+        I: Interpolator,
+        I::Item: 'static,
+        ComponentTween<I>: GetTypeRegistration + Reflect + TypePath + Component,
+    {
+        self.register_type::<ComponentTween<I>>()
+            .register_type_data::<ComponentTween<I>, ReflectMapEntities>()
+            .register_type_data::<ComponentTween<I>, ReflectComponentTween>()
+    }
+}
+
+/// Type data to apply a reflected [`ComponentTween`] without its system,
+/// such as by [`BakeTimeline`](crate::timeline::BakeTimeline). Registered
+/// by [`RegisterComponentTween`].
+#[derive(Clone)]
+pub struct ReflectComponentTween {
+    item: TypeId,
+    target: fn(&dyn Reflect) -> Option<&TargetComponent>,
+    interpolate: fn(&dyn Reflect, &mut dyn Any, f32),
+}
+
+impl ReflectComponentTween {
+    /// [`TypeId`] of the item the interpolator writes
+    pub fn item(&self) -> TypeId {
+        self.item
+    }
+
+    /// Target of `tween`
+    pub fn target<'a>(
+        &self,
+        tween: &'a dyn Reflect,
+    ) -> Option<&'a TargetComponent> {
+        (self.target)(tween)
+    }
+
+    /// Interpolate `item` with the interpolator of `tween`. Does nothing if
+    /// either of them isn't of the registered type.
+    pub fn interpolate(
+        &self,
+        tween: &dyn Reflect,
+        item: &mut dyn Any,
+        value: f32,
+    ) {
+        (self.interpolate)(tween, item, value)
+    }
+}
+
+impl<I> FromType<ComponentTween<I>> for ReflectComponentTween
+where
+    I: Interpolator,
+    I::Item: 'static,
+{
+    fn from_type() -> Self {
+        ReflectComponentTween {
+            item: TypeId::of::<I::Item>(),
+            target: |tween| {
+                tween
+                    .downcast_ref::<ComponentTween<I>>()
+                    .map(|tween| &tween.target)
+            },
+            interpolate: |tween, item, value| {
+                if let (Some(tween), Some(item)) = (
+                    tween.downcast_ref::<ComponentTween<I>>(),
+                    item.downcast_mut::<I::Item>(),
+                ) {
+                    tween.interpolator.interpolate(item, value);
+                }
+            },
+        }
+    }
+}
+
 /// Tell the tween what component of what entity to tween.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
 pub enum TargetComponent {
@@ -332,6 +514,20 @@ pub enum TargetComponent {
     Entities(Vec<Entity>),
 }
 
+impl MapEntities for TargetComponent {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        match self {
+            TargetComponent::Entity(entity) => {
+                *entity = entity_mapper.map_entity(*entity);
+            }
+            TargetComponent::Entities(entities) => {
+                entities.map_entities(entity_mapper);
+            }
+            TargetComponent::Marker => {}
+        }
+    }
+}
+
 impl TargetComponent {
     /// Navigate up the parent chain for entity with [`AnimationTarget`] marker component
     pub fn marker() -> TargetComponent {
@@ -351,6 +547,16 @@ impl TargetComponent {
         TargetComponent::from_iter(entities)
     }
 
+    /// Target no entity until they're resolved into this tween's
+    /// [`ResolvedTargets`] component by a resolver such as [`TargetMarker`].
+    ///
+    /// A tween targeting [`TargetComponent::Entities`] with
+    /// [`ResolvedTargets`] targets the resolved entities in their place, so
+    /// this is only an empty [`TargetComponent::Entities`].
+    pub fn resolved() -> TargetComponent {
+        TargetComponent::Entities(Vec::new())
+    }
+
     /// Create a new [`TargetState`] with the initial value out of this target.
     pub fn state<V>(&self, value: V) -> TargetState<Self, V> {
         TargetState::new(self.clone(), value)
@@ -430,12 +636,473 @@ impl<const N: usize> From<&[Entity; N]> for TargetComponent {
     }
 }
 
+/// Entities targeted by a tween in place of its
+/// [`TargetComponent::Entities`], see [`TargetComponent::resolved`].
+///
+/// Updated in [`TweenSystemSet::ResolveTarget`] by resolvers such as
+/// [`TargetMarker`].
+///
+/// [`TweenSystemSet::ResolveTarget`]: crate::TweenSystemSet::ResolveTarget
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct ResolvedTargets(pub Vec<Entity>);
+
+impl MapEntities for ResolvedTargets {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0.map_entities(entity_mapper);
+    }
+}
+
+/// Returns the entities targeted by the tween `entity` with `target` if it
+/// targets a list of entities, that is [`TargetComponent::Entities`] or its
+/// [`ResolvedTargets`] in place of them.
+pub(crate) fn target_entities<'a>(
+    entity: Entity,
+    target: &'a TargetComponent,
+    q_resolved_targets: &'a Query<&ResolvedTargets>,
+) -> Option<&'a [Entity]> {
+    match target {
+        TargetComponent::Entities(targets) => Some(
+            q_resolved_targets
+                .get(entity)
+                .map(|resolved| resolved.0.as_slice())
+                .unwrap_or(targets),
+        ),
+        TargetComponent::Marker | TargetComponent::Entity(_) => None,
+    }
+}
+
 /// [`ComponentTween`]'s system will navigate up the parent chain
 /// for this marker component while using [`TargetComponent::Marker`].
 #[derive(Debug, Component, Reflect)]
 #[reflect(Component)]
 pub struct AnimationTarget;
 
+/// Navigate up the parent chain starting from `entity` for an entity with
+/// [`AnimationTarget`] marker component.
+pub(crate) fn find_animation_target(
+    entity: Entity,
+    q_animation_target: &Query<(Option<&Parent>, Has<AnimationTarget>)>,
+) -> Option<Entity> {
+    let mut curr = entity;
+    loop {
+        let (parent, has_marker) = q_animation_target.get(curr).ok()?;
+        if has_marker {
+            return Some(curr);
+        }
+        curr = parent?.get();
+    }
+}
+
+/// Entities targeted by a tween, see [`resolve_tween_targets`]
+pub(crate) enum TweenTargets<'a> {
+    One(Entity),
+    Many(&'a [Entity]),
+}
+
+impl std::ops::Deref for TweenTargets<'_> {
+    type Target = [Entity];
+
+    fn deref(&self) -> &[Entity] {
+        match self {
+            TweenTargets::One(target) => std::slice::from_ref(target),
+            TweenTargets::Many(targets) => targets,
+        }
+    }
+}
+
+/// Resolve the entities targeted by the tween `entity` with `target`.
+///
+/// Returns `None` if the [`AnimationTarget`] marker can't be found, which is
+/// reported to `errors` if given.
+pub(crate) fn resolve_tween_targets<'a, I>(
+    entity: Entity,
+    target: &'a TargetComponent,
+    q_animation_target: &Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_resolved_targets: &'a Query<&ResolvedTargets>,
+    errors: Option<&mut TweenTargetErrors>,
+) -> Option<TweenTargets<'a>> {
+    match target {
+        TargetComponent::Marker => {
+            let found = find_animation_target(entity, q_animation_target);
+            if found.is_none() {
+                if let Some(errors) = errors {
+                    errors.report(entity, || {
+                        format!(
+                            "Tween {:?} {} cannot find AnimationTarget marker",
+                            entity,
+                            std::any::type_name::<I>(),
+                        )
+                    });
+                }
+            }
+            found.map(TweenTargets::One)
+        }
+        TargetComponent::Entity(target) => Some(TweenTargets::One(*target)),
+        target => Some(TweenTargets::Many(
+            target_entities(entity, target, q_resolved_targets)
+                .unwrap_or_default(),
+        )),
+    }
+}
+
+/// Errors met by a tween system with the tween or target entity they're
+/// about, each only logged if it wasn't met in the previous run.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct TweenTargetErrors {
+    last: HashSet<Entity>,
+    current: HashSet<Entity>,
+}
+
+impl TweenTargetErrors {
+    /// Log `message` about `entity` unless it was logged in the last run
+    pub(crate) fn report(
+        &mut self,
+        entity: Entity,
+        message: impl FnOnce() -> String,
+    ) {
+        if !self.last.contains(&entity) && !self.current.contains(&entity) {
+            error!("{}", message());
+        }
+        self.current.insert(entity);
+    }
+
+    /// Report that `I` failed to get the component `C` of `target`
+    pub(crate) fn query_error<I, C>(
+        &mut self,
+        target: Entity,
+        e: impl std::fmt::Display,
+    ) {
+        self.report(target, || {
+            format!(
+                "{} attempted to tween {} component but got query error: {e}",
+                std::any::type_name::<I>(),
+                std::any::type_name::<C>()
+            )
+        });
+    }
+
+    /// Forget the errors of the previous run, called once every run
+    pub(crate) fn end_run(&mut self) {
+        self.last = std::mem::take(&mut self.current);
+    }
+}
+
+/// The [`TimeRunner`] entity playing this span, maintained on every entity
+/// with [`TimeSpan`] and every [`NestedTimeRunner`] by
+/// [`time_runner_of_system`].
+///
+/// Lets the spans of a time runner be found from the span side without
+/// walking up the hierarchy past children that are not spans. A
+/// [`NestedTimeRunner`] is played by the closest time runner above it.
+/// See [`TimeRunnerSpans`] for the other side.
+///
+/// [`NestedTimeRunner`]: crate::playback::NestedTimeRunner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct TimeRunnerOf(pub Entity);
+
+impl MapEntities for TimeRunnerOf {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// Every entity whose [`TimeRunnerOf`] is this [`TimeRunner`], maintained by
+/// [`time_runner_of_system`].
+///
+/// Lets the spans of a time runner be visited without scanning its
+/// children that are not spans.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct TimeRunnerSpans(pub Vec<Entity>);
+
+impl MapEntities for TimeRunnerSpans {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0.map_entities(entity_mapper);
+    }
+}
+
+/// Insert or update [`TimeRunnerOf`] of the spans whose hierarchy changed,
+/// including spans below a newly inserted or removed [`TimeRunner`], and
+/// remove it from spans no longer played by any time runner.
+/// [`TimeRunnerSpans`] of the time runners involved is updated to match.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn time_runner_of_system(
+    mut commands: Commands,
+    q_reparented: Query<
+        Entity,
+        Or<(Changed<Parent>, Added<TimeRunner>, Added<NestedTimeRunner>)>,
+    >,
+    q_added_span: Query<Entity, Added<TimeSpan>>,
+    mut removed_parent: RemovedComponents<Parent>,
+    mut removed_time_runner: RemovedComponents<TimeRunner>,
+    mut removed_span: RemovedComponents<TimeSpan>,
+    q_children: Query<&Children>,
+    q_ancestor: Query<(Option<&Parent>, Has<TimeRunner>)>,
+    q_span: Query<
+        (Option<&TimeRunnerOf>, Has<NestedTimeRunner>),
+        Or<(With<TimeSpan>, With<NestedTimeRunner>)>,
+    >,
+    q_time_runner_spans: Query<(Entity, &TimeRunnerSpans)>,
+) {
+    let removed_time_runners =
+        removed_time_runner.read().collect::<HashSet<_>>();
+    for time_runner in &removed_time_runners {
+        if let Some(mut time_runner) = commands.get_entity(*time_runner) {
+            time_runner.remove::<TimeRunnerSpans>();
+        }
+    }
+    let mut time_runner_spans = HashMap::<Entity, Vec<Entity>>::new();
+
+    let removed_spans = removed_span
+        .read()
+        .filter(|span| !q_span.contains(*span))
+        .collect::<HashSet<_>>();
+    if !removed_spans.is_empty() {
+        for (time_runner, spans) in &q_time_runner_spans {
+            if spans.0.iter().any(|span| removed_spans.contains(span)) {
+                time_runner_spans.insert(
+                    time_runner,
+                    spans
+                        .0
+                        .iter()
+                        .copied()
+                        .filter(|span| !removed_spans.contains(span))
+                        .collect(),
+                );
+            }
+        }
+        for span in &removed_spans {
+            if let Some(mut span) = commands.get_entity(*span) {
+                span.remove::<TimeRunnerOf>();
+            }
+        }
+    }
+
+    let roots = q_reparented
+        .iter()
+        .chain(removed_parent.read())
+        .chain(removed_time_runners.iter().copied());
+    let mut spans = q_added_span.iter().collect::<Vec<_>>();
+    for root in roots {
+        spans.push(root);
+        spans.extend(q_children.iter_descendants(root));
+    }
+    spans.sort_unstable();
+    spans.dedup();
+    for span in spans {
+        let Ok((current, is_nested)) = q_span.get(span) else {
+            continue;
+        };
+        let mut time_runner = None;
+        let mut curr = span;
+        // A nested time runner is played by the time runner above it
+        let mut skip_self = is_nested;
+        while let Ok((parent, is_time_runner)) = q_ancestor.get(curr) {
+            if is_time_runner && !skip_self {
+                time_runner = Some(curr);
+                break;
+            }
+            skip_self = false;
+            let Some(parent) = parent else {
+                break;
+            };
+            curr = parent.get();
+        }
+        let current = current.map(|of| of.0);
+        if current == time_runner {
+            continue;
+        }
+        if let Some(current) = current {
+            spans_of(&mut time_runner_spans, &q_time_runner_spans, current)
+                .retain(|other| *other != span);
+        }
+        match time_runner {
+            Some(time_runner) => {
+                spans_of(
+                    &mut time_runner_spans,
+                    &q_time_runner_spans,
+                    time_runner,
+                )
+                .push(span);
+                commands.entity(span).insert(TimeRunnerOf(time_runner));
+            }
+            None => {
+                commands.entity(span).remove::<TimeRunnerOf>();
+            }
+        }
+    }
+
+    for (time_runner, spans) in time_runner_spans {
+        if removed_time_runners.contains(&time_runner) {
+            continue;
+        }
+        if let Some(mut time_runner) = commands.get_entity(time_runner) {
+            time_runner.try_insert(TimeRunnerSpans(spans));
+        }
+    }
+}
+
+/// Pending [`TimeRunnerSpans`] of `time_runner`, starting from its current
+/// one
+fn spans_of<'a>(
+    time_runner_spans: &'a mut HashMap<Entity, Vec<Entity>>,
+    q_time_runner_spans: &Query<(Entity, &TimeRunnerSpans)>,
+    time_runner: Entity,
+) -> &'a mut Vec<Entity> {
+    time_runner_spans.entry(time_runner).or_insert_with(|| {
+        q_time_runner_spans
+            .get(time_runner)
+            .map(|(_, spans)| spans.0.clone())
+            .unwrap_or_default()
+    })
+}
+
+/// [`SystemParam`] to look up the [`TimeRunner`] playing a tween and how the
+/// tween should be applied by its [`BlendWeight`] and [`ConflictPolicy`].
+#[derive(SystemParam)]
+pub struct TweenPlayers<'w, 's> {
+    q_time_runner: Query<'w, 's, (Option<&'static Parent>, Has<TimeRunner>)>,
+    q_time_runner_of: Query<'w, 's, &'static TimeRunnerOf>,
+    q_blend_weight: Query<'w, 's, &'static BlendWeight>,
+    q_conflict_policy: Query<'w, 's, &'static ConflictPolicy>,
+    q_time_span: Query<'w, 's, &'static TimeSpan>,
+}
+
+impl TweenPlayers<'_, '_> {
+    /// [`TimeRunnerOf`] of `tween` if exists or else navigate up the parent
+    /// chain starting from `tween` for the [`TimeRunner`] playing it.
+    pub fn time_runner(&self, tween: Entity) -> Option<Entity> {
+        if let Ok(TimeRunnerOf(time_runner)) = self.q_time_runner_of.get(tween)
+        {
+            if matches!(self.q_time_runner.get(*time_runner), Ok((_, true))) {
+                return Some(*time_runner);
+            }
+        }
+        let mut curr = tween;
+        loop {
+            let (parent, is_time_runner) = self.q_time_runner.get(curr).ok()?;
+            if is_time_runner {
+                return Some(curr);
+            }
+            curr = parent?.get();
+        }
+    }
+
+    /// Returns true if no [`BlendWeight`] or [`ConflictPolicy`] exists so
+    /// every tween is applied as is.
+    pub fn is_trivial(&self) -> bool {
+        self.q_blend_weight.is_empty() && self.q_conflict_policy.is_empty()
+    }
+
+    /// [`ConflictPolicy`] of `tween` or else of the [`TimeRunner`] playing it
+    pub fn conflict_policy(&self, tween: Entity) -> ConflictPolicy {
+        if self.q_conflict_policy.is_empty() {
+            return ConflictPolicy::Ignore;
+        }
+        self.q_conflict_policy
+            .get(tween)
+            .ok()
+            .or_else(|| {
+                self.q_conflict_policy.get(self.time_runner(tween)?).ok()
+            })
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Weight of `tween` to be blended with. `None` if the tween isn't
+    /// blended.
+    ///
+    /// This is the [`BlendWeight`] of the [`TimeRunner`] playing it, or `1.`
+    /// with [`ConflictPolicy::Blend`].
+    pub fn blend_weight(&self, tween: Entity) -> Option<f32> {
+        if self.is_trivial() {
+            return None;
+        }
+        let time_runner = self.time_runner(tween);
+        if let Some(weight) =
+            time_runner.and_then(|e| self.q_blend_weight.get(e).ok())
+        {
+            return Some(weight.0);
+        }
+        (self.conflict_policy(tween) == ConflictPolicy::Blend).then_some(1.)
+    }
+
+    /// Start of the span of `tween`
+    pub fn span_start(&self, tween: Entity) -> Duration {
+        self.q_time_span
+            .get(tween)
+            .map(|span| span.min().duration())
+            .unwrap_or_default()
+    }
+}
+
+/// Fires when a [`ComponentTween`]'s target entity has been despawned or
+/// no longer has the tweened component.
+///
+/// Sent once each time the target becomes lost and also triggered on the
+/// tween entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Event, Reflect)]
+pub struct TweenTargetLost {
+    /// The tween entity
+    pub tween: Entity,
+    /// The lost target entity
+    pub target: Entity,
+}
+
+/// What to do with a tween once its target is lost. See [`TweenTargetLost`].
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect,
+)]
+#[reflect(Component)]
+pub enum TargetLostPolicy {
+    /// Keep the tween as is
+    #[default]
+    Ignore,
+    /// Insert [`SkipTween`] to the tween entity
+    Skip,
+    /// Despawn the tween entity and all of its descendants
+    Despawn,
+}
+
+/// What to do when multiple tweens with the same interpolator type apply to
+/// the same target in the same frame.
+///
+/// Can be inserted to a tween entity or to the [`TimeRunner`] playing it.
+/// Once any [`ConflictPolicy`] exists, every tween is applied in order of
+/// the start of its span so the outcome is deterministic.
+///
+/// [`ConflictPolicy::Error`] also logs tweens of different interpolators
+/// writing the same field of the same target, such as
+/// [`Translation`](crate::interpolate::Translation) and
+/// [`TranslationPerAxis`](crate::interpolate::TranslationPerAxis), if both
+/// interpolators report the field with [`Interpolator::fields`]. These are
+/// checked across every [`apply_component_tween_system`] with
+/// [`TweenFieldWrites`] but only ordered within one interpolator type, so
+/// whichever system runs last wins. Tweens applied by other systems, such
+/// as [`relative_component_tween_system`], are meant to stack and aren't
+/// checked.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component, Reflect,
+)]
+#[reflect(Component)]
+pub enum ConflictPolicy {
+    /// Apply in an unspecified order
+    #[default]
+    Ignore,
+    /// Log an error then apply like [`ConflictPolicy::LastSpanWins`]
+    Error,
+    /// The tween whose span starts last wins
+    LastSpanWins,
+    /// Blend the tweens equally like with [`BlendWeight`] of `1.` which
+    /// requires the interpolator to be registered with
+    /// [`blend_component_tween_system`], otherwise they're applied in order
+    /// of the start of their span
+    Blend,
+}
+
 impl<I> ComponentTween<I>
 where
     I: Interpolator,
@@ -0,0 +1,161 @@
+use super::*;
+use bevy::utils::HashSet;
+
+use crate::TweenSystemSet;
+
+/// Plugin for [`TweenGroup`] and [`TweenGroups`]
+pub struct TweenGroupPlugin;
+
+impl Plugin for TweenGroupPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            tween_group_system.before(TweenSystemSet::ApplyTween),
+        )
+        .init_resource::<TweenGroups>()
+        .register_type::<TweenGroup>()
+        .register_type::<MutedByGroup>();
+    }
+}
+
+/// Label the spans of this entity and of its descendants into a group that
+/// can be muted or soloed with [`TweenGroups`].
+///
+/// The closest [`TweenGroup`] going up from a span is used, so it can be
+/// inserted to a single span or to a whole [`TimeRunner`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::tween::{TweenGroup, TweenGroups};
+///
+/// fn debug_camera_only(mut groups: ResMut<TweenGroups>) {
+///     groups.solo("camera");
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(TweenGroup::new("camera"));
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Component, Reflect)]
+#[reflect(Component)]
+pub struct TweenGroup(pub String);
+
+impl TweenGroup {
+    /// Create new [`TweenGroup`]
+    pub fn new(name: impl Into<String>) -> TweenGroup {
+        TweenGroup(name.into())
+    }
+}
+
+/// Muted and soloed [`TweenGroup`]s.
+///
+/// Spans of a muted group are skipped with [`SkipTween`]. Once any group is
+/// soloed, spans not in a soloed group, including spans without a group,
+/// are skipped as well.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Resource)]
+pub struct TweenGroups {
+    muted: HashSet<String>,
+    soloed: HashSet<String>,
+}
+
+impl TweenGroups {
+    /// Mute a group
+    pub fn mute(&mut self, group: impl Into<String>) {
+        self.muted.insert(group.into());
+    }
+
+    /// Unmute a group
+    pub fn unmute(&mut self, group: &str) {
+        self.muted.remove(group);
+    }
+
+    /// Solo a group
+    pub fn solo(&mut self, group: impl Into<String>) {
+        self.soloed.insert(group.into());
+    }
+
+    /// Unsolo a group
+    pub fn unsolo(&mut self, group: &str) {
+        self.soloed.remove(group);
+    }
+
+    /// Unmute and unsolo every group
+    pub fn clear(&mut self) {
+        self.muted.clear();
+        self.soloed.clear();
+    }
+
+    /// Returns true if the group is muted
+    pub fn is_muted(&self, group: &str) -> bool {
+        self.muted.contains(group)
+    }
+
+    /// Returns true if the group is soloed
+    pub fn is_soloed(&self, group: &str) -> bool {
+        self.soloed.contains(group)
+    }
+
+    /// Returns true if spans in `group` are played
+    pub fn is_audible(&self, group: Option<&str>) -> bool {
+        match group {
+            Some(group) => {
+                !self.muted.contains(group)
+                    && (self.soloed.is_empty() || self.soloed.contains(group))
+            }
+            None => self.soloed.is_empty(),
+        }
+    }
+}
+
+/// Marker for [`SkipTween`] inserted by [`tween_group_system`] so it's only
+/// removed by it. Spans that are already skipped are left alone.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub struct MutedByGroup;
+
+/// Insert or remove [`SkipTween`] on spans according to [`TweenGroups`].
+///
+/// Every span is checked when [`TweenGroups`] or any [`TweenGroup`] has
+/// changed, otherwise only newly added spans are.
+#[allow(clippy::type_complexity)]
+pub fn tween_group_system(
+    mut commands: Commands,
+    groups: Res<TweenGroups>,
+    q_changed_group: Query<(), Changed<TweenGroup>>,
+    mut removed_group: RemovedComponents<TweenGroup>,
+    q_span: Query<(Entity, Ref<TimeSpan>, Has<SkipTween>, Has<MutedByGroup>)>,
+    q_group: Query<&TweenGroup>,
+    q_parent: Query<&Parent>,
+) {
+    let check_all = groups.is_changed()
+        || !q_changed_group.is_empty()
+        || removed_group.read().count() > 0;
+    for (entity, span, skipped, muted) in &q_span {
+        if !check_all && !span.is_added() {
+            continue;
+        }
+        let group = std::iter::once(entity)
+            .chain(q_parent.iter_ancestors(entity))
+            .find_map(|e| q_group.get(e).ok())
+            .map(|group| group.0.as_str());
+        let audible = groups.is_audible(group);
+        if audible && muted {
+            commands
+                .entity(entity)
+                .remove::<(SkipTween, MutedByGroup)>();
+        } else if !audible && !skipped {
+            commands.entity(entity).insert((SkipTween, MutedByGroup));
+        }
+    }
+}
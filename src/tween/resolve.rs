@@ -0,0 +1,328 @@
+use super::*;
+use std::marker::PhantomData;
+
+use crate::TweenSystemSet;
+
+/// Plugin for [`TargetMarker<M>`]
+pub struct TargetMarkerPlugin<M>(PhantomData<M>);
+
+impl<M> Default for TargetMarkerPlugin<M> {
+    fn default() -> Self {
+        TargetMarkerPlugin(PhantomData)
+    }
+}
+
+impl<M> Plugin for TargetMarkerPlugin<M>
+where
+    M: Component,
+{
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        app.add_systems(
+            app_resource.schedule,
+            resolve_target_marker_system::<M>
+                .in_set(TweenSystemSet::ResolveTarget),
+        );
+    }
+}
+
+/// Target every entity that currently has the component `M`, resolved each
+/// frame into [`ResolvedTargets`]. Use with [`TargetComponent::resolved`] and
+/// add [`TargetMarkerPlugin<M>`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_tween::prelude::*;
+/// use bevy_tween::tween::{TargetComponent, TargetMarker, TargetMarkerPlugin};
+///
+/// #[derive(Component)]
+/// struct MenuElement;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.animation().insert_tween_here(
+///         Duration::from_secs(1),
+///         EaseKind::Linear,
+///         (
+///             TargetComponent::resolved()
+///                 .with(interpolate::opacity(1., 0.)),
+///             TargetMarker::<MenuElement>::default(),
+///         ),
+///     );
+/// }
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((
+///             DefaultPlugins,
+///             DefaultTweenPlugins,
+///             TargetMarkerPlugin::<MenuElement>::default(),
+///         ))
+///         .add_systems(Startup, setup)
+///         .run();
+/// }
+/// # use std::time::Duration;
+/// ```
+#[derive(Component)]
+#[require(ResolvedTargets)]
+pub struct TargetMarker<M>(PhantomData<M>)
+where
+    M: Component;
+
+impl<M> Default for TargetMarker<M>
+where
+    M: Component,
+{
+    fn default() -> Self {
+        TargetMarker(PhantomData)
+    }
+}
+
+/// Update [`ResolvedTargets`] of every tween with [`TargetMarker<M>`] to
+/// every entity that has the component `M`.
+pub fn resolve_target_marker_system<M>(
+    mut q_tween: Query<&mut ResolvedTargets, With<TargetMarker<M>>>,
+    q_marked: Query<Entity, With<M>>,
+) where
+    M: Component,
+{
+    if q_tween.is_empty() {
+        return;
+    }
+    let marked = q_marked.iter().collect::<Vec<_>>();
+    q_tween.iter_mut().for_each(|mut resolved| {
+        if resolved.0 != marked {
+            resolved.0.clone_from(&marked);
+        }
+    });
+}
+
+/// Plugin for [`TargetName`], [`TargetPath`], and `TargetJoint` with the
+/// `bevy_animation` feature
+pub struct TargetPathPlugin;
+
+impl Plugin for TargetPathPlugin {
+    /// # Panics
+    ///
+    /// Panics if [`TweenAppResource`] does not exist in world.
+    ///
+    /// [`TweenAppResource`]: crate::TweenAppResource
+    fn build(&self, app: &mut App) {
+        let app_resource = app
+            .world()
+            .get_resource::<crate::TweenAppResource>()
+            .expect("`TweenAppResource` to be is inserted to world");
+        let schedule = app_resource.schedule;
+        app.add_systems(
+            schedule,
+            resolve_target_path_system.in_set(TweenSystemSet::ResolveTarget),
+        )
+        .register_type::<TargetName>()
+        .register_type::<TargetPath>();
+
+        #[cfg(feature = "bevy_animation")]
+        app.add_systems(
+            schedule,
+            resolve_target_joint_system.in_set(TweenSystemSet::ResolveTarget),
+        )
+        .register_type::<TargetJoint>();
+    }
+}
+
+/// Target the first entity with this [`Name`] under the root of the
+/// animation. Use with [`TargetComponent::resolved`].
+///
+/// The root is the parent of the nearest [`TimeRunner`] ancestor of the
+/// tween, or the time runner itself if it has no parent.
+///
+/// Resolved into [`ResolvedTargets`] by [`resolve_target_path_system`] and
+/// cached until the hierarchy or any [`Name`] changes.
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+#[require(ResolvedTargets)]
+pub struct TargetName(pub String);
+
+/// Target the entity at this path of [`Name`]s separated by `/` such as
+/// `"Rig/ArmL/Hand"`, starting from the children of the root of the
+/// animation. Use with [`TargetComponent::resolved`].
+///
+/// The root is the parent of the nearest [`TimeRunner`] ancestor of the
+/// tween, or the time runner itself if it has no parent.
+///
+/// Resolved into [`ResolvedTargets`] by [`resolve_target_path_system`] and
+/// cached until the hierarchy or any [`Name`] changes.
+///
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+#[require(ResolvedTargets)]
+pub struct TargetPath(pub String);
+
+/// Update [`ResolvedTargets`] of every tween with [`TargetName`] or
+/// [`TargetPath`] when the hierarchy or any [`Name`] changes.
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn resolve_target_path_system(
+    mut q_tween: Query<
+        (
+            Entity,
+            Option<Ref<TargetName>>,
+            Option<Ref<TargetPath>>,
+            &mut ResolvedTargets,
+        ),
+        Or<(With<TargetName>, With<TargetPath>)>,
+    >,
+    q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
+    q_name: Query<&Name>,
+    q_time_runner: Query<(), With<bevy_time_runner::TimeRunner>>,
+    q_hierarchy_changed: Query<
+        (),
+        Or<(Changed<Parent>, Changed<Children>, Changed<Name>)>,
+    >,
+    mut removed_parent: RemovedComponents<Parent>,
+    mut removed_name: RemovedComponents<Name>,
+) {
+    // Not short-circuiting so the removed components are always drained.
+    let hierarchy_changed = !q_hierarchy_changed.is_empty()
+        | (removed_parent.read().count() > 0)
+        | (removed_name.read().count() > 0);
+    q_tween
+        .iter_mut()
+        .for_each(|(entity, name, path, mut resolved)| {
+            let target_changed = name.as_ref().is_some_and(|n| n.is_changed())
+                || path.as_ref().is_some_and(|p| p.is_changed());
+            if !hierarchy_changed && !target_changed {
+                return;
+            }
+            let Some(time_runner) = std::iter::once(entity)
+                .chain(q_parent.iter_ancestors(entity))
+                .find(|e| q_time_runner.contains(*e))
+            else {
+                return;
+            };
+            let root = q_parent
+                .get(time_runner)
+                .map(|parent| parent.get())
+                .unwrap_or(time_runner);
+            let has_name = |entity: &Entity, name: &str| {
+                q_name.get(*entity).is_ok_and(|n| n.as_str() == name)
+            };
+            let target = match (name, path) {
+                (Some(name), _) => q_children
+                    .iter_descendants(root)
+                    .find(|e| has_name(e, &name.0)),
+                (None, Some(path)) => path
+                    .0
+                    .split('/')
+                    .filter(|s| !s.is_empty())
+                    .try_fold(root, |parent, segment| {
+                        q_children
+                            .get(parent)
+                            .ok()?
+                            .iter()
+                            .copied()
+                            .find(|child| has_name(child, segment))
+                    }),
+                (None, None) => None,
+            };
+            let targets = target.into_iter().collect::<Vec<_>>();
+            if resolved.0 != targets {
+                resolved.0 = targets;
+            }
+        });
+}
+
+/// Target the skeletal joint with this [`AnimationTargetId`] under the root
+/// of the animation. Use with [`TargetComponent::resolved`].
+///
+/// Joints are the entities with Bevy's
+/// [`AnimationTarget`](bevy::animation::AnimationTarget) component, usually
+/// spawned from an imported glTF scene. The root is the parent of the nearest
+/// [`TimeRunner`] ancestor of the tween, or the time runner itself if it has
+/// no parent.
+///
+/// Bevy's animations are applied in [`PostUpdate`], so tweens layered on top
+/// of them should use a relative interpolator such as
+/// [`RotationBy`](crate::interpolate::RotationBy) and run after
+/// [`Animation`](bevy::animation::Animation).
+///
+/// Resolved into [`ResolvedTargets`] by [`resolve_target_joint_system`].
+///
+/// [`AnimationTargetId`]: bevy::animation::AnimationTargetId
+/// [`TimeRunner`]: bevy_time_runner::TimeRunner
+#[cfg(feature = "bevy_animation")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+#[require(ResolvedTargets)]
+pub struct TargetJoint(pub bevy::animation::AnimationTargetId);
+
+#[cfg(feature = "bevy_animation")]
+impl TargetJoint {
+    /// Target the joint at this path of bone names from the root of the
+    /// skeleton such as `["Armature", "Spine", "Head"]`.
+    pub fn from_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        let names = names
+            .into_iter()
+            .map(|name| Name::new(name.to_owned()))
+            .collect::<Vec<_>>();
+        TargetJoint(bevy::animation::AnimationTargetId::from_names(
+            names.iter(),
+        ))
+    }
+}
+
+/// Update [`ResolvedTargets`] of every tween with [`TargetJoint`] when it or
+/// any joint changes.
+#[cfg(feature = "bevy_animation")]
+#[allow(clippy::type_complexity)]
+pub fn resolve_target_joint_system(
+    mut q_tween: Query<(Entity, Ref<TargetJoint>, &mut ResolvedTargets)>,
+    q_parent: Query<&Parent>,
+    q_time_runner: Query<(), With<bevy_time_runner::TimeRunner>>,
+    q_joint: Query<(Entity, Ref<bevy::animation::AnimationTarget>)>,
+    mut removed_joint: RemovedComponents<bevy::animation::AnimationTarget>,
+) {
+    // Not short-circuiting so the removed components are always drained.
+    let joints_changed = q_joint.iter().any(|(_, joint)| joint.is_changed())
+        | (removed_joint.read().count() > 0);
+    q_tween
+        .iter_mut()
+        .for_each(|(entity, target_joint, mut resolved)| {
+            if !joints_changed && !target_joint.is_changed() {
+                return;
+            }
+            let Some(time_runner) = std::iter::once(entity)
+                .chain(q_parent.iter_ancestors(entity))
+                .find(|e| q_time_runner.contains(*e))
+            else {
+                return;
+            };
+            let root = q_parent
+                .get(time_runner)
+                .map(|parent| parent.get())
+                .unwrap_or(time_runner);
+            let targets = q_joint
+                .iter()
+                .filter(|(joint, animation_target)| {
+                    animation_target.id == target_joint.0
+                        && q_parent.iter_ancestors(*joint).any(|e| e == root)
+                })
+                .map(|(joint, _)| joint)
+                .collect::<Vec<_>>();
+            if resolved.0 != targets {
+                resolved.0 = targets;
+            }
+        });
+}
@@ -1,9 +1,20 @@
 use super::*;
 use bevy::{
-    ecs::{query::QueryEntityError, schedule::SystemConfigs},
+    ecs::{
+        component::Tick, query::QueryEntityError, schedule::SystemConfigs,
+        system::SystemChangeTick,
+    },
     utils::{HashMap, HashSet},
 };
-use std::any::type_name;
+use std::{
+    any::{type_name, TypeId},
+    marker::PhantomData,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+/// Minimum number of targets for [`apply_component_tween_system`] to apply
+/// tweens in parallel
+pub const PARALLEL_APPLY_MIN_TARGETS: usize = 1024;
 
 /// Alias for [`apply_component_tween_system`] and may contains more systems
 /// in the future.
@@ -39,6 +50,18 @@ impl From<&QueryEntityError<'_>> for QueryEntityErrorWithoutWorld {
     }
 }
 
+impl QueryEntityErrorWithoutWorld {
+    /// Returns true if the entity doesn't exist or no longer matches the
+    /// query.
+    pub fn is_target_lost(&self) -> bool {
+        matches!(
+            self,
+            QueryEntityErrorWithoutWorld::QueryDoesNotMatch(_)
+                | QueryEntityErrorWithoutWorld::NoSuchEntity(_)
+        )
+    }
+}
+
 impl core::error::Error for QueryEntityErrorWithoutWorld {}
 
 impl core::fmt::Display for QueryEntityErrorWithoutWorld {
@@ -65,6 +88,23 @@ impl core::fmt::Display for QueryEntityErrorWithoutWorld {
 /// make your interpolators work. The trait is only necessary to be used with
 /// this built-in system.
 ///
+/// Tweens that are blended, see [`TweenPlayers::blend_weight`], are left to
+/// [`apply_blend_component_tween_system`] if it's registered for `I`, see
+/// [`BlendTweenRegistered`], and applied here otherwise. Once any
+/// [`ConflictPolicy`] exists, tweens are applied in order of the start of
+/// their span.
+///
+/// A target only triggers change detection when one of its tweens has
+/// changed, either its [`TweenInterpolationValue`], interpolator, or
+/// targets, so paused or completed animations don't trigger it every frame.
+/// Every target is still written every run so when tweens of different
+/// interpolators write the same field, the system that runs last wins.
+///
+/// All tweens of a target are applied together. When at least
+/// [`PARALLEL_APPLY_MIN_TARGETS`] targets are tweened and they're at least
+/// half of the entities with the component, the components are iterated in
+/// parallel instead of accessed one by one.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -106,123 +146,300 @@ impl core::fmt::Display for QueryEntityErrorWithoutWorld {
 /// }
 /// ```
 #[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
 pub fn apply_component_tween_system<I>(
+    mut commands: Commands,
     q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
     q_tween: Query<
-        (Entity, &Tween<TargetComponent, I>, &TweenInterpolationValue),
+        (
+            Entity,
+            Ref<Tween<TargetComponent, I>>,
+            Ref<TweenInterpolationValue>,
+        ),
         Without<SkipTween>,
     >,
-    mut q_component: Query<&mut I::Item>,
-    mut last_entity_error: Local<HashMap<Entity, QueryEntityErrorWithoutWorld>>,
-    mut last_search_error: Local<HashSet<Entity>>,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    q_changed_targets: Query<(), Changed<ResolvedTargets>>,
+    mut removed_values: RemovedComponents<TweenInterpolationValue>,
+    mut q_component: Query<(Entity, &mut I::Item)>,
+    q_target_lost_policy: Query<&TargetLostPolicy>,
+    players: TweenPlayers,
+    blend_registered: Option<Res<BlendTweenRegistered<I>>>,
+    field_writes: Option<Res<TweenFieldWrites>>,
+    additive_writes: Option<Res<AdditiveTweenWrites>>,
+    mut target_lost_writer: EventWriter<TweenTargetLost>,
+    mut last_entity_error: Local<
+        HashMap<(Entity, Entity), QueryEntityErrorWithoutWorld>,
+    >,
+    mut search_errors: Local<TweenTargetErrors>,
+    mut last_conflict: Local<HashSet<Entity>>,
 ) where
     I: Interpolator + Send + Sync + 'static,
     I::Item: Component,
 {
     let mut entity_error = HashMap::new();
-    let mut search_error = HashSet::new();
-    q_tween
-        .iter()
-        .for_each(|(entity, tween, ease_value)| match &tween.target {
-            TargetComponent::Entities(e) => {
-                e.iter().for_each(|target| {
-                    let mut target_component =
-                        match q_component.get_mut(*target) {
-                            Ok(target_component) => target_component,
-                            Err(e) => {
-                                let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
-                                if last_entity_error
-                                    .get(target)
-                                    .map(|old_e| old_e != &e_no_world)
-                                    .unwrap_or(true)
-                                    && entity_error
-                                        .get(target)
-                                        .map(|old_e| old_e != &e_no_world)
-                                        .unwrap_or(true)
-                                {
-                                    error!(
-                                        "{} attempted to tween {} component but got query error: {e}",
-                                        type_name::<I>(),
-                                        type_name::<I::Item>()
-                                    );
-                                }
-                                entity_error.insert(*target, e_no_world);
-                                return;
-                            }
-                        };
-                    tween
-                        .interpolator
-                        .interpolate(&mut target_component, ease_value.0);
-                });
-            }
-            _ => {
-                let target = match &tween.target {
-                    TargetComponent::Marker => {
-                        let mut curr = entity;
-                        let found = 'l: loop {
-                            match q_animation_target.get(curr) {
-                                Ok((parent, has_marker)) => {
-                                    if has_marker {
-                                        break 'l Some(curr);
-                                    } else {
-                                        match parent {
-                                            Some(parent) => curr = parent.get(),
-                                            None => break 'l None,
-                                        }
-                                    }
-                                }
-                                _ => break 'l None,
-                            }
-                        };
-                        match found {
-                            Some(found) => found,
-                            None => {
-                                if !last_search_error.contains(&entity) && !search_error.contains(&entity) {
-                                    error!(
-                                        "Tween {:?} {} cannot find AnimationTarget marker",
-                                        entity,
-                                        type_name::<I>(),
-                                    );
-                                }
-                                search_error.insert(entity);
-                                return;
-                            },
-                        }
-                    }
-                    TargetComponent::Entity(e) => *e,
-                    _ => unreachable!(),
-                };
+    let mut target_lost = Vec::new();
+    let mut conflicts = TweenConflicts::default();
+    let mut field_writes = field_writes
+        .as_deref()
+        .filter(|_| !players.is_trivial())
+        .map(TweenFieldWrites::lock);
+    let mut tweens = q_tween.iter().collect::<Vec<_>>();
+    if !players.is_trivial() {
+        if blend_registered.is_some() {
+            tweens.retain(|(entity, _, _)| {
+                players.blend_weight(*entity).is_none()
+            });
+        }
+        tweens.sort_by_key(|(entity, _, _)| {
+            (players.span_start(*entity), *entity)
+        });
+    }
 
-                let mut target_component = match q_component.get_mut(target) {
-                    Ok(target_component) => target_component,
-                    Err(e) => {
-                        let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
-                        if last_entity_error
-                            .get(&target)
-                            .map(|old_e| old_e != &e_no_world)
-                            .unwrap_or(true)
-                            && entity_error
-                                .get(&target)
-                                .map(|old_e| old_e != &e_no_world)
-                                .unwrap_or(true)
-                        {
-                            error!(
-                                "{} attempted to tween {} component but got query error: {e}",
-                                type_name::<I>(),
-                                type_name::<I::Item>()
-                            );
-                        }
-                        entity_error.insert(target, e_no_world);
-                        return;
+    // Resolve and validate every target first then apply to each target
+    // all of its tweens at once, in parallel if there are enough of them.
+    let mut applications = Vec::new();
+    let mut changed = Vec::with_capacity(tweens.len());
+    for (index, (entity, tween, ease_value)) in tweens.iter().enumerate() {
+        changed.push(
+            tween.is_changed()
+                || ease_value.is_changed()
+                || q_changed_targets.contains(*entity),
+        );
+        let Some(targets) = resolve_tween_targets::<I>(
+            *entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            Some(&mut search_errors),
+        ) else {
+            continue;
+        };
+        for target in targets.iter() {
+            if let Err(e) = q_component.get(*target) {
+                let e_no_world = QueryEntityErrorWithoutWorld::from(&e);
+                // Per tween so every tween losing the same target gets its
+                // own `TweenTargetLost`
+                let key = (*entity, *target);
+                if last_entity_error
+                    .get(&key)
+                    .map(|old_e| old_e != &e_no_world)
+                    .unwrap_or(true)
+                    && entity_error
+                        .get(&key)
+                        .map(|old_e| old_e != &e_no_world)
+                        .unwrap_or(true)
+                {
+                    error!(
+                        "{} attempted to tween {} component but got query error: {e}",
+                        type_name::<I>(),
+                        type_name::<I::Item>()
+                    );
+                    if e_no_world.is_target_lost() {
+                        target_lost.push(TweenTargetLost {
+                            tween: *entity,
+                            target: *target,
+                        });
                     }
-                };
-                tween
-                    .interpolator
-                    .interpolate(&mut target_component, ease_value.0);
+                }
+                entity_error.insert(key, e_no_world);
+                continue;
+            }
+            conflicts.check::<I>(*entity, *target, &players, &last_conflict);
+            if let Some(field_writes) = field_writes.as_mut() {
+                field_writes.check::<I>(
+                    *entity,
+                    *target,
+                    tween.interpolator.fields(),
+                    &players,
+                );
+            }
+            applications.push((*target, index));
+        }
+    }
+    // Stable so the tweens of each target stay in order
+    applications.sort_by_key(|(target, _)| *target);
+    let apply = |component: &mut I::Item, group: &[(Entity, usize)]| {
+        for (_, index) in group {
+            let (_, tween, ease_value) = &tweens[*index];
+            tween.interpolator.interpolate(component, ease_value.0);
+        }
+    };
+    // Targets whose tweens would write the same values as last run are
+    // still written, so the system that runs last wins as before, but
+    // without triggering change detection. A tween that stopped may have
+    // been overwriting others so every target is changed again then.
+    // Additive tweens still have to know their offset was written over.
+    let any_removed = removed_values.read().count() > 0;
+    let additive_writes = additive_writes.as_deref();
+    let apply_group = |component: Mut<I::Item>, group: &[(Entity, usize)]| {
+        if any_removed || group.iter().any(|(_, i)| changed[*i]) {
+            apply(component.into_inner(), group);
+        } else {
+            let mut component = component;
+            apply(component.bypass_change_detection(), group);
+            if let Some(additive_writes) = additive_writes {
+                additive_writes.bypassed::<I::Item>(group[0].0);
+            }
+        }
+    };
+    let groups = applications.chunk_by(|(a, _), (b, _)| a == b);
+    let target_count = groups.clone().count();
+    if target_count >= PARALLEL_APPLY_MIN_TARGETS
+        && target_count * 2 >= q_component.iter().len()
+    {
+        let groups = groups
+            .map(|group| (group[0].0, group))
+            .collect::<HashMap<_, _>>();
+        q_component.par_iter_mut().for_each(|(entity, component)| {
+            if let Some(group) = groups.get(&entity) {
+                apply_group(component, group);
             }
         });
+    } else {
+        for group in groups {
+            if let Ok((_, component)) = q_component.get_mut(group[0].0) {
+                apply_group(component, group);
+            }
+        }
+    }
+    drop(field_writes);
     *last_entity_error = entity_error;
-    *last_search_error = search_error;
+    search_errors.end_run();
+    *last_conflict = conflicts.reported;
+
+    for lost in target_lost {
+        match q_target_lost_policy.get(lost.tween) {
+            Ok(TargetLostPolicy::Skip) => {
+                if let Some(mut tween) = commands.get_entity(lost.tween) {
+                    tween.insert(SkipTween);
+                }
+            }
+            Ok(TargetLostPolicy::Despawn) => {
+                if let Some(tween) = commands.get_entity(lost.tween) {
+                    tween.despawn_recursive();
+                }
+            }
+            Ok(TargetLostPolicy::Ignore) | Err(_) => {}
+        }
+        commands.trigger_targets(lost, lost.tween);
+        target_lost_writer.send(lost);
+    }
+}
+
+/// Targets applied to by [`apply_component_tween_system`] in this run to
+/// detect tweens applying to the same target.
+#[derive(Default)]
+struct TweenConflicts {
+    applied: HashMap<Entity, Entity>,
+    reported: HashSet<Entity>,
+}
+
+impl TweenConflicts {
+    fn check<I>(
+        &mut self,
+        tween: Entity,
+        target: Entity,
+        players: &TweenPlayers,
+        last_reported: &HashSet<Entity>,
+    ) {
+        let Some(previous) = self.applied.insert(target, tween) else {
+            return;
+        };
+        if previous == tween
+            || (players.conflict_policy(tween) != ConflictPolicy::Error
+                && players.conflict_policy(previous) != ConflictPolicy::Error)
+        {
+            return;
+        }
+        if !last_reported.contains(&target) && !self.reported.contains(&target)
+        {
+            error!(
+                "Tweens {previous} and {tween} of {} both apply to {target} at the same time",
+                type_name::<I>(),
+            );
+        }
+        self.reported.insert(target);
+    }
+}
+
+/// Fields written by every [`apply_component_tween_system`] in this run to
+/// detect tweens of different interpolators writing the same field of the
+/// same target, see [`ConflictPolicy`] and [`Interpolator::fields`].
+///
+/// Behind a [`Mutex`] so the systems of different interpolators can still
+/// run in parallel, and only locked once any [`BlendWeight`] or
+/// [`ConflictPolicy`] exists.
+#[derive(Debug, Default, Resource)]
+pub struct TweenFieldWrites(Mutex<FieldWrites>);
+
+impl TweenFieldWrites {
+    fn lock(&self) -> MutexGuard<'_, FieldWrites> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// (target, item type, field)
+type FieldKey = (Entity, TypeId, &'static str);
+
+#[derive(Debug, Default)]
+struct FieldWrites {
+    /// Tween and its interpolator type that last wrote each field
+    applied: HashMap<FieldKey, (Entity, TypeId, &'static str)>,
+    reported: HashSet<FieldKey>,
+    last_reported: HashSet<FieldKey>,
+}
+
+impl FieldWrites {
+    fn check<I>(
+        &mut self,
+        tween: Entity,
+        target: Entity,
+        fields: &'static [&'static str],
+        players: &TweenPlayers,
+    ) where
+        I: Interpolator,
+    {
+        for field in fields {
+            let key = (target, TypeId::of::<I::Item>(), *field);
+            let Some((previous, previous_type, previous_name)) = self
+                .applied
+                .insert(key, (tween, TypeId::of::<I>(), type_name::<I>()))
+            else {
+                continue;
+            };
+            // Tweens of the same interpolator are checked by
+            // `TweenConflicts` instead
+            if previous_type == TypeId::of::<I>()
+                || (players.conflict_policy(tween) != ConflictPolicy::Error
+                    && players.conflict_policy(previous)
+                        != ConflictPolicy::Error)
+            {
+                continue;
+            }
+            if !self.last_reported.contains(&key)
+                && !self.reported.contains(&key)
+            {
+                error!(
+                    "Tweens {previous} of {previous_name} and {tween} of {} both write `{field}` of {target} at the same time",
+                    type_name::<I>(),
+                );
+            }
+            self.reported.insert(key);
+        }
+    }
+}
+
+/// Start a new run of [`TweenFieldWrites`]
+pub fn prepare_tween_field_writes_system(
+    mut field_writes: ResMut<TweenFieldWrites>,
+) {
+    let field_writes = field_writes
+        .0
+        .get_mut()
+        .unwrap_or_else(PoisonError::into_inner);
+    field_writes.applied.clear();
+    field_writes.last_reported = std::mem::take(&mut field_writes.reported);
 }
 
 /// System alias for [`component_tween_system`] that uses boxed dynamic [`Interpolator`]. (`Box<dyn Interpolator`)
@@ -237,6 +454,403 @@ where
         .into_configs()
 }
 
+/// Alias for [`apply_relative_component_tween_system`] and may contains more
+/// systems in the future.
+pub fn relative_component_tween_system<I>() -> SystemConfigs
+where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component,
+{
+    apply_relative_component_tween_system::<I>.into_configs()
+}
+
+/// Apply any [`Tween`] with the [`Interpolator`] that [`TargetComponent`]
+/// relative to the target's current value.
+///
+/// Instead of the [`TweenInterpolationValue`] itself, the interpolator
+/// receives how much the value has changed since the last time this tween was
+/// applied, starting from `0.` every time the tween becomes active again,
+/// such as when its time runner is restarted.
+/// Interpolators such as [`TranslationBy`] add a delta scaled by this change
+/// on top of the target so multiple relative tweens on the same target stack.
+///
+/// [`TranslationBy`]: crate::interpolate::TranslationBy
+#[allow(clippy::type_complexity)]
+pub fn apply_relative_component_tween_system<I>(
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_tween: Query<
+        (
+            Entity,
+            &Tween<TargetComponent, I>,
+            Ref<TweenInterpolationValue>,
+        ),
+        (Without<SkipTween>, Without<Additive>),
+    >,
+    q_tween_exists: Query<(), With<Tween<TargetComponent, I>>>,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    mut q_component: Query<&mut I::Item>,
+    mut applied_values: Local<HashMap<Entity, f32>>,
+    mut errors: Local<TweenTargetErrors>,
+) where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component,
+{
+    applied_values.retain(|tween, _| q_tween_exists.contains(*tween));
+    q_tween.iter().for_each(|(entity, tween, ease_value)| {
+        let applied = applied_values.entry(entity).or_insert(0.);
+        if ease_value.is_added() {
+            *applied = 0.;
+        }
+        let delta = ease_value.0 - *applied;
+        *applied = ease_value.0;
+        if delta == 0. {
+            return;
+        }
+        let Some(targets) = resolve_tween_targets::<I>(
+            entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            Some(&mut errors),
+        ) else {
+            return;
+        };
+        for &target in targets.iter() {
+            match q_component.get_mut(target) {
+                Ok(mut component) => {
+                    tween.interpolator.interpolate(&mut component, delta);
+                }
+                Err(e) => errors.query_error::<I, I::Item>(target, e),
+            }
+        }
+    });
+    errors.end_run();
+}
+
+/// Alias for [`apply_additive_component_tween_system`] and may contains more
+/// systems in the future.
+///
+/// Register it with
+/// [`add_additive_tween_systems`](crate::BevyTweenRegisterSystems::add_additive_tween_systems).
+pub fn additive_component_tween_system<I>() -> SystemConfigs
+where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component,
+{
+    apply_additive_component_tween_system::<I>.into_configs()
+}
+
+/// Apply any [`Additive`] [`Tween`] with the [`Interpolator`] that
+/// [`TargetComponent`] on top of the target's value of this frame.
+///
+/// If the target was written by something else since the last time this
+/// system applied to it, the interpolator receives the whole
+/// [`TweenInterpolationValue`] on top of that new value. Otherwise it
+/// receives the change since the last application so the offset isn't
+/// accumulated. Interpolators such as [`TranslationBy`] that add a delta
+/// scaled by the value work this way.
+///
+/// [`TranslationBy`]: crate::interpolate::TranslationBy
+#[allow(clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_additive_component_tween_system<I>(
+    system_ticks: SystemChangeTick,
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_tween: Query<
+        (Entity, &Tween<TargetComponent, I>, &TweenInterpolationValue),
+        (With<Additive>, Without<SkipTween>),
+    >,
+    q_tween_exists: Query<(), With<Tween<TargetComponent, I>>>,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    mut q_component: Query<&mut I::Item>,
+    mut writes: ResMut<AdditiveTweenWrites>,
+    mut applied_values: Local<HashMap<(Entity, Entity), f32>>,
+    mut errors: Local<TweenTargetErrors>,
+) where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component,
+{
+    applied_values.retain(|(tween, _), _| q_tween_exists.contains(*tween));
+    let AdditiveTweenWrites {
+        run,
+        targets: target_writes,
+        bypassed,
+    } = &mut *writes;
+    let run = *run;
+    let bypassed = bypassed.get_mut().unwrap_or_else(PoisonError::into_inner);
+    q_tween.iter().for_each(|(entity, tween, ease_value)| {
+        let Some(targets) = resolve_tween_targets::<I>(
+            entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            Some(&mut errors),
+        ) else {
+            return;
+        };
+        for &target in targets.iter() {
+            let mut component = match q_component.get_mut(target) {
+                Ok(component) => component,
+                Err(e) => {
+                    errors.query_error::<I, I::Item>(target, e);
+                    continue;
+                }
+            };
+            let key = (target, TypeId::of::<I::Item>());
+            let write =
+                target_writes.entry(key).or_insert(AdditiveTweenWrite {
+                    written: None,
+                    checked_run: run.wrapping_sub(1),
+                    overwritten: true,
+                });
+            // Checked once per run before any additive tween writes to it.
+            if write.checked_run != run {
+                write.checked_run = run;
+                write.overwritten = write.written
+                    != Some(component.last_changed())
+                    || bypassed.contains(&key);
+            }
+            let overwritten = write.overwritten;
+            let applied = applied_values.entry((entity, target)).or_insert(0.);
+            let delta = if overwritten {
+                ease_value.0
+            } else {
+                ease_value.0 - *applied
+            };
+            *applied = ease_value.0;
+            if delta == 0. {
+                continue;
+            }
+            tween.interpolator.interpolate(&mut component, delta);
+            write.written = Some(system_ticks.this_run());
+        }
+    });
+    errors.end_run();
+}
+
+/// Alias for [`apply_blend_component_tween_system`] and may contains more
+/// systems in the future.
+///
+/// Register it with
+/// [`add_blend_tween_systems`](crate::BevyTweenRegisterSystems::add_blend_tween_systems).
+pub fn blend_component_tween_system<I>() -> SystemConfigs
+where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component + Blend + Clone,
+{
+    apply_blend_component_tween_system::<I>.into_configs()
+}
+
+/// Apply any [`Tween`] with the [`Interpolator`] that [`TargetComponent`]
+/// and is played by a [`TimeRunner`] with [`BlendWeight`] or has
+/// [`ConflictPolicy::Blend`].
+///
+/// Every tween is sampled from the target's current value then the samples
+/// are combined by their [`BlendWeight`] with [`Blend`]. If the weights sum
+/// to less than `1.`, the rest of the weight is given to the current value.
+///
+/// Only tweens of the same interpolator type are blended together, tweens
+/// without [`BlendWeight`] are applied by [`apply_component_tween_system`]
+/// instead.
+#[allow(clippy::type_complexity)]
+pub fn apply_blend_component_tween_system<I>(
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    q_tween: Query<
+        (Entity, &Tween<TargetComponent, I>, &TweenInterpolationValue),
+        Without<SkipTween>,
+    >,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    players: TweenPlayers,
+    mut q_component: Query<&mut I::Item>,
+    mut errors: Local<TweenTargetErrors>,
+) where
+    I: Interpolator + Send + Sync + 'static,
+    I::Item: Component + Blend + Clone,
+{
+    // target -> (current value, blended samples, total weight)
+    let mut blended = HashMap::<Entity, (I::Item, I::Item, f32)>::new();
+    q_tween.iter().for_each(|(entity, tween, ease_value)| {
+        let Some(weight) = players.blend_weight(entity) else {
+            return;
+        };
+        let Some(targets) = resolve_tween_targets::<I>(
+            entity,
+            &tween.target,
+            &q_animation_target,
+            &q_resolved_targets,
+            Some(&mut errors),
+        ) else {
+            return;
+        };
+        for &target in targets.iter() {
+            let (current, samples, total) = match blended.entry(target) {
+                bevy::utils::Entry::Occupied(entry) => entry.into_mut(),
+                bevy::utils::Entry::Vacant(entry) => {
+                    match q_component.get(target) {
+                        Ok(component) => entry.insert((
+                            component.clone(),
+                            component.clone(),
+                            0.,
+                        )),
+                        Err(e) => {
+                            errors.query_error::<I, I::Item>(target, e);
+                            continue;
+                        }
+                    }
+                }
+            };
+            let weight = weight.max(0.);
+            *total += weight;
+            if *total <= 0. {
+                continue;
+            }
+            let mut sample = current.clone();
+            tween.interpolator.interpolate(&mut sample, ease_value.0);
+            samples.blend(&sample, weight / *total);
+        }
+    });
+    for (target, (mut current, samples, total)) in blended {
+        if total <= 0. {
+            continue;
+        }
+        let Ok(mut component) = q_component.get_mut(target) else {
+            continue;
+        };
+        current.blend(&samples, total.min(1.));
+        *component = current;
+    }
+    errors.end_run();
+}
+
+/// Marker resource that [`apply_blend_component_tween_system`] of `I` is
+/// registered, so [`apply_component_tween_system`] of `I` leaves blended
+/// tweens to it instead of applying them itself.
+///
+/// Inserted by
+/// [`add_blend_tween_systems`](crate::BevyTweenRegisterSystems::add_blend_tween_systems).
+/// Insert it yourself if you add a blend system of `I` in another way.
+#[derive(Resource)]
+pub struct BlendTweenRegistered<I>(PhantomData<fn() -> I>);
+
+impl<I> Default for BlendTweenRegistered<I> {
+    fn default() -> Self {
+        BlendTweenRegistered(PhantomData)
+    }
+}
+
+/// Bookkeeping of the writes by [`apply_additive_component_tween_system`] to
+/// tell if a target was written by something else since.
+///
+/// Also records the targets that [`apply_component_tween_system`] wrote
+/// without triggering change detection in this run, behind a [`Mutex`] so
+/// those systems can still run in parallel.
+#[derive(Debug, Default, Resource)]
+pub struct AdditiveTweenWrites {
+    run: u32,
+    targets: HashMap<(Entity, TypeId), AdditiveTweenWrite>,
+    bypassed: Mutex<HashSet<(Entity, TypeId)>>,
+}
+
+impl AdditiveTweenWrites {
+    /// Record that `target` was written over without change detection if an
+    /// additive tween applies to it.
+    fn bypassed<T: 'static>(&self, target: Entity) {
+        let key = (target, TypeId::of::<T>());
+        if self.targets.contains_key(&key) {
+            self.bypassed
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(key);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AdditiveTweenWrite {
+    written: Option<Tick>,
+    checked_run: u32,
+    overwritten: bool,
+}
+
+/// Start a new run of the additive tween systems and forget targets that
+/// weren't applied to by them in the previous run.
+pub fn prepare_additive_tween_writes_system(
+    mut writes: ResMut<AdditiveTweenWrites>,
+) {
+    let run = writes.run;
+    writes.targets.retain(|_, write| write.checked_run == run);
+    writes.run = run.wrapping_add(1);
+    writes
+        .bypassed
+        .get_mut()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+/// Alias for [`capture_start_component_tween_system`] chained before
+/// [`apply_component_tween_system`] for [`FromCurrent<I>`].
+pub fn component_tween_from_current_system<I>() -> SystemConfigs
+where
+    I: CaptureStart + Send + Sync + 'static,
+    I::Item: Component,
+{
+    (
+        capture_start_component_tween_system::<I>,
+        apply_component_tween_system::<FromCurrent<I>>,
+    )
+        .chain()
+}
+
+/// Capture the start of any active [`Tween`] with [`FromCurrent<I>`] from the
+/// current value of its [`TargetComponent`] every time it becomes active,
+/// which is when [`TweenInterpolationValue`] is added to it.
+///
+/// If the tween targets multiple entities, the first one is used.
+#[allow(clippy::type_complexity)]
+pub fn capture_start_component_tween_system<I>(
+    q_animation_target: Query<(Option<&Parent>, Has<AnimationTarget>)>,
+    mut q_tween: Query<
+        (
+            Entity,
+            &mut Tween<TargetComponent, FromCurrent<I>>,
+            Ref<TweenInterpolationValue>,
+        ),
+        Without<SkipTween>,
+    >,
+    q_resolved_targets: Query<&ResolvedTargets>,
+    q_component: Query<&I::Item>,
+) where
+    I: CaptureStart + Send + Sync + 'static,
+    I::Item: Component,
+{
+    q_tween
+        .iter_mut()
+        .filter(|(_, tween, value)| {
+            value.is_added() || !tween.interpolator.captured
+        })
+        .for_each(|(entity, mut tween, _)| {
+            // Errors are reported when the tween is applied.
+            let target = resolve_tween_targets::<I>(
+                entity,
+                &tween.target,
+                &q_animation_target,
+                &q_resolved_targets,
+                None,
+            )
+            .and_then(|targets| targets.first().copied());
+            let Some(item) = target.and_then(|t| q_component.get(t).ok())
+            else {
+                // Retried on the next run until the target is found
+                if tween.interpolator.captured {
+                    tween.interpolator.captured = false;
+                }
+                return;
+            };
+            tween.interpolator.interpolator.capture_start(item);
+            tween.interpolator.captured = true;
+        });
+}
+
 /// Alias for [`apply_resource_tween_system`] and may contains more systems
 /// in the future.
 pub fn resource_tween_system<I>() -> SystemConfigs
@@ -0,0 +1,218 @@
+use bevy_tween::interpolation::{
+    EaseBack, EaseChain, EaseCubicBezier, EaseElastic, EaseHermite, EaseKind,
+    EaseLut, EaseMode, EaseSteps, Interpolation, JumpTerm,
+};
+
+fn assert_approx(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-3,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn half_sine() {
+    for ease in [
+        EaseKind::HalfSineIn,
+        EaseKind::HalfSineOut,
+        EaseKind::HalfSineInOut,
+    ] {
+        assert_approx(ease.sample(0.), 0.);
+        assert_approx(ease.sample(1.), 1.);
+    }
+    assert_approx(EaseKind::HalfSineInOut.sample(0.5), 0.5);
+    // Between linear and the full quarter wave
+    let half_in = EaseKind::HalfSineIn.sample(0.5);
+    assert_approx(half_in, 1. - EaseKind::HalfSineOut.sample(0.5));
+    assert!(EaseKind::SineIn.sample(0.5) < half_in && half_in < 0.5);
+    let half_out = EaseKind::HalfSineOut.sample(0.5);
+    assert!(0.5 < half_out && half_out < EaseKind::SineOut.sample(0.5));
+}
+
+#[test]
+fn cubic_bezier_css_reference() {
+    // Sampled from the CSS definitions at 0.25, 0.5 and 0.75
+    for (ease, expected) in [
+        (EaseCubicBezier::EASE, [0.40851, 0.8024, 0.96046]),
+        (EaseCubicBezier::EASE_IN, [0.09346, 0.31536, 0.62186]),
+        (EaseCubicBezier::EASE_OUT, [0.37814, 0.68464, 0.90654]),
+        (EaseCubicBezier::EASE_IN_OUT, [0.12916, 0.5, 0.87084]),
+    ] {
+        assert_approx(ease.sample(0.), 0.);
+        assert_approx(ease.sample(1.), 1.);
+        for (t, expected) in [0.25, 0.5, 0.75].into_iter().zip(expected) {
+            assert_approx(ease.sample(t), expected);
+        }
+    }
+    // `y` may overshoot while `x` is clamped
+    let overshoot = EaseCubicBezier(0.5, 1.5, 0.5, 1.5);
+    assert!(overshoot.sample(0.5) > 1.);
+    assert_eq!(
+        EaseCubicBezier(-1., 0., 2., 1.).sample(0.3),
+        EaseCubicBezier(0., 0., 1., 1.).sample(0.3),
+    );
+}
+
+#[test]
+fn steps_css_reference() {
+    // `steps(4, <jump-term>)` at 0, 0.3, 0.5 and 1
+    for (jump_term, expected) in [
+        (JumpTerm::End, [0., 0.25, 0.5, 1.]),
+        (JumpTerm::Start, [0.25, 0.5, 0.75, 1.]),
+        (JumpTerm::None, [0., 1. / 3., 2. / 3., 1.]),
+        (JumpTerm::Both, [0.2, 0.4, 0.6, 1.]),
+    ] {
+        let steps = EaseSteps::new(4, jump_term);
+        for (t, expected) in [0., 0.3, 0.5, 1.].into_iter().zip(expected) {
+            assert_approx(steps.sample(t), expected);
+        }
+    }
+    // Each step is held for a whole interval
+    let steps = EaseSteps::new(4, JumpTerm::End);
+    assert_eq!(steps.sample(0.26), steps.sample(0.49));
+    // No steps is treated as one
+    assert_eq!(
+        EaseSteps::new(0, JumpTerm::End).sample(0.5),
+        EaseSteps::new(1, JumpTerm::End).sample(0.5),
+    );
+}
+
+/// Samples at the ends, the midpoint and in between
+const SAMPLES: [f32; 7] = [0., 0.1, 0.25, 0.5, 0.75, 0.9, 1.];
+
+#[test]
+fn elastic_matches_ease_kind() {
+    for (mode, kind) in [
+        (EaseMode::In, EaseKind::ElasticIn),
+        (EaseMode::Out, EaseKind::ElasticOut),
+        (EaseMode::InOut, EaseKind::ElasticInOut),
+    ] {
+        let elastic = EaseElastic {
+            mode,
+            ..Default::default()
+        };
+        for t in SAMPLES {
+            assert_approx(elastic.sample(t), kind.sample(t));
+        }
+    }
+    assert_approx(EaseElastic::default().sample(0.5), 1.015625);
+    // A higher amplitude overshoots further
+    let peak = |amplitude| {
+        let elastic = EaseElastic::new(amplitude, 0.3, EaseMode::Out);
+        (0..=100)
+            .map(|i| elastic.sample(i as f32 / 100.))
+            .fold(f32::MIN, f32::max)
+    };
+    assert!(peak(2.) > peak(1.));
+}
+
+#[test]
+fn back_matches_ease_kind() {
+    for (mode, kind) in [
+        (EaseMode::In, EaseKind::BackIn),
+        (EaseMode::Out, EaseKind::BackOut),
+        (EaseMode::InOut, EaseKind::BackInOut),
+    ] {
+        let back = EaseBack {
+            mode,
+            ..Default::default()
+        };
+        for t in SAMPLES {
+            assert_approx(back.sample(t), kind.sample(t));
+        }
+    }
+    // The default overshoot pulls back by 10%
+    let back_in = EaseBack::new(1.70158, EaseMode::In);
+    let low = (0..=1000)
+        .map(|i| back_in.sample(i as f32 / 1000.))
+        .fold(f32::MAX, f32::min);
+    assert_approx(low, -0.1);
+    assert_approx(EaseBack::default().sample(0.5), 1.0876975);
+    // No overshoot is a cubic
+    let cubic = EaseBack::new(0., EaseMode::In);
+    for t in SAMPLES {
+        assert_approx(cubic.sample(t), EaseKind::CubicIn.sample(t));
+    }
+}
+
+#[test]
+fn hermite_tangents() {
+    // Zero tangents, the default, is a smoothstep
+    let hermite = EaseHermite::default();
+    for t in SAMPLES {
+        assert_approx(hermite.sample(t), EaseKind::SmoothStep.sample(t));
+    }
+    let linear = EaseHermite::new(1., 1.);
+    for t in SAMPLES {
+        assert_approx(linear.sample(t), t);
+    }
+    // `h(0.5) = 0.5 + (out_tangent - in_tangent) / 8`
+    let hermite = EaseHermite::new(2., 0.);
+    assert_approx(hermite.sample(0.), 0.);
+    assert_approx(hermite.sample(0.5), 0.75);
+    assert_approx(hermite.sample(1.), 1.);
+    // Tangents in value per second are normalized by the keyframes
+    let hermite = EaseHermite::from_keyframes((1., 10.), 10., (3., 30.), 5.);
+    assert_approx(hermite.out_tangent, 1.);
+    assert_approx(hermite.in_tangent, 0.5);
+    assert_eq!(
+        EaseHermite::from_keyframes((0., 1.), 3., (1., 1.), 3.),
+        EaseHermite::default(),
+    );
+}
+
+#[test]
+fn chain_segments() {
+    let chain = EaseChain::new()
+        .then(0.2, -0.1, EaseKind::QuadraticOut)
+        .then(0.8, 1.1, EaseKind::CubicInOut)
+        .then(1.0, 1.0, EaseKind::QuadraticInOut);
+    for (t, expected) in [
+        (0., 0.),
+        (0.1, -0.075),
+        (0.2, -0.1),
+        (0.5, 0.5),
+        (0.8, 1.1),
+        (1., 1.),
+    ] {
+        assert_approx(chain.sample(t), expected);
+    }
+
+    // The last value is held past the last segment
+    let short = EaseChain::new().then(0.5, 1., EaseKind::Linear);
+    assert_approx(short.sample(0.25), 0.5);
+    assert_approx(short.sample(0.75), 1.);
+    // A segment without length jumps to its value
+    let jump = EaseChain::new()
+        .then(0.5, 0., EaseKind::Linear)
+        .then(0.5, 1., EaseKind::Linear)
+        .then(1., 1., EaseKind::Linear);
+    assert_approx(jump.sample(0.5), 0.);
+    assert_approx(jump.sample(0.51), 1.);
+    assert_approx(EaseChain::<EaseKind>::new().sample(0.5), 0.);
+}
+
+#[test]
+fn lut_bake() {
+    // Linear is exact between samples
+    let linear = EaseLut::bake(&EaseKind::Linear, 5);
+    assert_eq!(linear.samples(), &[0., 0.25, 0.5, 0.75, 1.]);
+    assert_approx(linear.sample(0.6), 0.6);
+    // Out of range is clamped to the ends
+    assert_approx(linear.sample(-1.), 0.);
+    assert_approx(linear.sample(2.), 1.);
+
+    let smooth = EaseLut::bake(&EaseKind::SmoothStep, 256);
+    for v in SAMPLES {
+        assert_approx(smooth.sample(v), EaseKind::SmoothStep.sample(v));
+    }
+
+    // At least 2 samples
+    let lut = EaseLut::bake(&EaseKind::QuadraticIn, 0);
+    assert_eq!(lut.samples(), &[0., 1.]);
+    assert_approx(lut.sample(0.5), 0.5);
+
+    // Clones share the table
+    let clone = smooth.clone();
+    assert!(std::ptr::eq(clone.samples(), smooth.samples()));
+}
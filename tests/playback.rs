@@ -0,0 +1,296 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_time_runner::{
+    Repeat, RepeatStyle, TimeDirection, TimeRunner, TimeRunnerEnded, TimeSpan,
+};
+use bevy_tween::{
+    playback::{
+        CompletionPolicy, ManualTick, TimeRunnerLooped, TimeRunnerStarted,
+    },
+    TweenCorePlugin,
+};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TweenCorePlugin::default(),
+        bevy_tween::playback::PlaybackPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(
+        Duration::from_millis(250),
+    ));
+    app
+}
+
+fn drain<E: Event>(app: &mut App) -> Vec<E> {
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .drain()
+        .collect()
+}
+
+/// Update `times` times and collect every `E` sent meanwhile
+fn update_collect<E: Event>(app: &mut App, times: usize) -> Vec<E> {
+    let mut events = Vec::new();
+    for _ in 0..times {
+        app.update();
+        events.extend(drain::<E>(app));
+    }
+    events
+}
+
+#[test]
+fn started_once() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn(TimeRunner::new(Duration::from_secs(1)))
+        .id();
+
+    app.update();
+    app.update();
+
+    let started = drain::<TimeRunnerStarted>(&mut app);
+    assert_eq!(started, [TimeRunnerStarted { time_runner }]);
+}
+
+#[test]
+fn despawn_spans_keeps_children() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn((
+            TimeRunner::new(Duration::from_millis(500)),
+            CompletionPolicy::DespawnSpans,
+        ))
+        .id();
+    let span = app
+        .world_mut()
+        .spawn(
+            TimeSpan::try_from(Duration::ZERO..Duration::from_millis(500))
+                .unwrap(),
+        )
+        .set_parent(time_runner)
+        .id();
+    let mesh = app.world_mut().spawn_empty().set_parent(span).id();
+
+    for _ in 0..4 {
+        app.update();
+    }
+
+    assert!(app.world().get_entity(span).is_err());
+    assert_eq!(
+        app.world().get::<Parent>(mesh).map(Parent::get),
+        Some(time_runner)
+    );
+}
+
+#[test]
+fn strip_spans_leaves_time_runner() {
+    let mut app = app();
+    let length = Duration::from_millis(500);
+    let time_runner = app
+        .world_mut()
+        .spawn((
+            TimeRunner::new(length),
+            TimeSpan::try_from(Duration::ZERO..length).unwrap(),
+            CompletionPolicy::StripSpans,
+        ))
+        .id();
+    let span = app
+        .world_mut()
+        .spawn(TimeSpan::try_from(Duration::ZERO..length).unwrap())
+        .set_parent(time_runner)
+        .id();
+
+    for _ in 0..4 {
+        app.update();
+    }
+
+    assert!(app.world().get::<TimeSpan>(span).is_none());
+    assert!(app.world().get::<TimeSpan>(time_runner).is_some());
+    assert!(app.world().get::<TimeRunner>(time_runner).is_some());
+}
+
+#[test]
+fn negative_time_scale_repeats() {
+    let mut app = app();
+    let mut time_runner = TimeRunner::new(Duration::from_secs(1));
+    time_runner.set_repeat(Some((Repeat::times(2), RepeatStyle::WrapAround)));
+    time_runner.set_time_scale(-1.);
+    let time_runner = app.world_mut().spawn(time_runner).id();
+
+    app.update();
+    app.update();
+
+    let ended = drain::<TimeRunnerEnded>(&mut app);
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended[0].time_runner, time_runner);
+    assert_eq!(ended[0].with_repeat, Some(Repeat::times(2)));
+}
+
+fn repeating(repeat: Repeat, repeat_style: RepeatStyle) -> TimeRunner {
+    let mut time_runner = TimeRunner::new(Duration::from_secs(1));
+    time_runner.set_repeat(Some((repeat, repeat_style)));
+    time_runner
+}
+
+#[test]
+fn time_runner_plugin_ticks_once() {
+    use bevy::ecs::schedule::ScheduleLabel;
+
+    let elasped = |mut app: App| {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_millis(250),
+        ));
+        let time_runner = app
+            .world_mut()
+            .spawn(TimeRunner::new(Duration::from_secs(10)))
+            .id();
+        for _ in 0..4 {
+            app.update();
+        }
+        app.world()
+            .get::<TimeRunner>(time_runner)
+            .unwrap()
+            .elasped()
+            .now()
+    };
+
+    let mut with_time_runner_plugin = App::new();
+    with_time_runner_plugin.add_plugins((
+        MinimalPlugins,
+        bevy_time_runner::TimeRunnerPlugin {
+            schedule: PostUpdate.intern(),
+        },
+        TweenCorePlugin::default(),
+    ));
+    let mut without = App::new();
+    without.add_plugins((MinimalPlugins, TweenCorePlugin::default()));
+
+    assert_eq!(elasped(with_time_runner_plugin), elasped(without));
+}
+
+#[test]
+fn manual_tick_completes() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn((
+            TimeRunner::new(Duration::from_secs(1)),
+            ManualTick::default(),
+        ))
+        .id();
+
+    for _ in 0..8 {
+        app.update();
+    }
+    assert_eq!(
+        app.world()
+            .get::<TimeRunner>(time_runner)
+            .unwrap()
+            .elasped()
+            .now(),
+        0.
+    );
+    assert!(drain::<TimeRunnerEnded>(&mut app).is_empty());
+
+    app.world_mut()
+        .get_mut::<ManualTick>(time_runner)
+        .unwrap()
+        .tick(1.);
+    app.update();
+
+    let ended = drain::<TimeRunnerEnded>(&mut app);
+    assert_eq!(ended.len(), 1);
+    assert!(ended[0].is_completed());
+    assert!(app
+        .world()
+        .get::<TimeRunner>(time_runner)
+        .unwrap()
+        .is_completed());
+}
+
+#[test]
+fn repeat_event_counts() {
+    let mut app = app();
+    app.world_mut()
+        .spawn(repeating(Repeat::times(2), RepeatStyle::WrapAround));
+
+    let ended = update_collect::<TimeRunnerEnded>(&mut app, 20);
+    assert_eq!(ended.len(), 3);
+    assert_eq!(
+        ended.iter().map(|e| e.is_completed()).collect::<Vec<_>>(),
+        [false, false, true]
+    );
+}
+
+#[test]
+fn ping_pong_event_counts() {
+    let mut app = app();
+    app.world_mut()
+        .spawn(repeating(Repeat::times(2), RepeatStyle::PingPong));
+
+    let ended = update_collect::<TimeRunnerEnded>(&mut app, 20);
+    assert_eq!(
+        ended
+            .iter()
+            .map(|e| (e.current_direction, e.is_completed()))
+            .collect::<Vec<_>>(),
+        [
+            (TimeDirection::Backward, false),
+            (TimeDirection::Forward, false),
+            (TimeDirection::Forward, true),
+        ]
+    );
+}
+
+#[test]
+fn one_tick_crosses_many_repeats() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn((
+            repeating(Repeat::times(3), RepeatStyle::WrapAround),
+            ManualTick::default(),
+        ))
+        .id();
+    app.world_mut()
+        .get_mut::<ManualTick>(time_runner)
+        .unwrap()
+        .tick(2.5);
+
+    app.update();
+
+    let ended = drain::<TimeRunnerEnded>(&mut app);
+    assert_eq!(ended.len(), 2);
+    assert!(ended.iter().all(|e| !e.is_completed()));
+}
+
+#[test]
+fn started_and_looped() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn(repeating(Repeat::times(1), RepeatStyle::WrapAround))
+        .id();
+
+    let mut started = Vec::new();
+    let mut looped = Vec::new();
+    for _ in 0..12 {
+        app.update();
+        started.extend(drain::<TimeRunnerStarted>(&mut app));
+        looped.extend(drain::<TimeRunnerLooped>(&mut app));
+    }
+
+    assert_eq!(started, [TimeRunnerStarted { time_runner }]);
+    assert_eq!(
+        looped,
+        [TimeRunnerLooped {
+            time_runner,
+            repeat: Some(Repeat::times(1)),
+        }]
+    );
+}
@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_time_runner::{TimeRunner, TimeSpan, TimeSpanProgress};
+use bevy_tween::{timeline::TimeSpanIndex, TweenCorePlugin};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TweenCorePlugin::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(
+            Duration::from_millis(250),
+        ));
+    app
+}
+
+fn secs(start: f32, end: f32) -> TimeSpan {
+    TimeSpan::try_from(
+        Duration::from_secs_f32(start)..Duration::from_secs_f32(end),
+    )
+    .unwrap()
+}
+
+#[test]
+fn overlapping() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn(TimeRunner::new(Duration::from_secs(3)))
+        .id();
+    let [first, second, third, long] =
+        [secs(0., 1.), secs(1., 2.), secs(2., 3.), secs(0., 3.)].map(|span| {
+            app.world_mut().spawn(span).set_parent(time_runner).id()
+        });
+
+    app.update();
+
+    let index = app.world().get::<TimeSpanIndex>(time_runner).unwrap();
+    assert_eq!(index.len(), 4);
+    let mut found = index.overlapping(1.2, 1.8).collect::<Vec<_>>();
+    found.sort();
+    let mut expected = vec![second, long];
+    expected.sort();
+    assert_eq!(found, expected);
+    assert_eq!(index.overlapping(3.5, 4.).count(), 0);
+    assert!(index.overlapping(1., 1.).any(|span| span == first));
+    assert!(index.overlapping(2., 2.5).any(|span| span == third));
+}
+
+#[test]
+fn progress_only_overlapping_spans() {
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn(TimeRunner::new(Duration::from_secs(3)))
+        .id();
+    let [first, second, long] = [secs(0., 1.), secs(1., 2.), secs(0., 3.)]
+        .map(|span| app.world_mut().spawn(span).set_parent(time_runner).id());
+
+    app.update();
+    app.update();
+
+    let world = app.world();
+    assert!(world.get::<TimeSpanProgress>(first).is_some());
+    assert!(world.get::<TimeSpanProgress>(second).is_none());
+    assert!(world.get::<TimeSpanProgress>(long).is_some());
+}
+
+#[test]
+fn spring_settle_fits_span() {
+    use bevy_tween::interpolation::{
+        EaseSpring, EaseSpringPlugin, SpringSettle,
+    };
+
+    let mut app = app();
+    app.add_plugins(EaseSpringPlugin);
+    let time_runner = app
+        .world_mut()
+        .spawn(TimeRunner::new(Duration::from_secs(1)))
+        .id();
+    let spring = EaseSpring::default();
+    let span = app
+        .world_mut()
+        .spawn((secs(0.5, 1.), spring, SpringSettle))
+        .set_parent(time_runner)
+        .id();
+
+    app.update();
+
+    let end = Duration::from_secs_f32(0.5) + spring.duration();
+    let span = app.world().get::<TimeSpan>(span).unwrap();
+    assert_eq!(span.max().duration(), end);
+    let time_runner = app.world().get::<TimeRunner>(time_runner).unwrap();
+    assert_eq!(time_runner.length(), end);
+}
+
+#[test]
+fn bake_in_one_pass() {
+    use bevy_tween::{
+        interpolate::{translation, Translation},
+        interpolation::{EaseKind, EaseKindPlugin},
+        timeline::{
+            BakeTimeline, BakedTimeline, TimelineBakePlugin, TimelineBaked,
+        },
+        tween::{ComponentTween, RegisterComponentTween},
+    };
+
+    #[derive(Resource, Default)]
+    struct Baked(Option<BakedTimeline<Transform>>);
+
+    let mut app = app();
+    app.add_plugins((
+        EaseKindPlugin,
+        TimelineBakePlugin::<Transform>::default(),
+    ))
+    .register_component_tween::<Translation>()
+    .init_resource::<Baked>()
+    .add_observer(
+        |trigger: Trigger<TimelineBaked<Transform>>,
+         mut baked: ResMut<Baked>| {
+            baked.0 = Some(trigger.baked.clone());
+        },
+    );
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let time_runner = app
+        .world_mut()
+        .spawn((
+            TimeRunner::new(Duration::from_secs(1)),
+            BakeTimeline::<Transform>::new(4.).with_target(target),
+        ))
+        .id();
+    app.world_mut()
+        .spawn((
+            secs(0., 1.),
+            EaseKind::Linear,
+            ComponentTween::new_target(
+                target,
+                translation(Vec3::ZERO, Vec3::X),
+            ),
+        ))
+        .set_parent(time_runner);
+
+    app.update();
+
+    let baked = app.world_mut().resource_mut::<Baked>().0.take().unwrap();
+    let track = baked.track(0).unwrap();
+    let xs = track.iter().map(|t| t.translation.x).collect::<Vec<_>>();
+    assert_eq!(xs.len(), 5, "{xs:?}");
+    for (x, expected) in xs.iter().zip([0., 0.25, 0.5, 0.75, 1.]) {
+        assert!((x - expected).abs() < 1e-4, "{xs:?}");
+    }
+    assert!(app
+        .world()
+        .get::<BakeTimeline<Transform>>(time_runner)
+        .is_none());
+}
+
+#[test]
+fn shift_span_rejects_invalid_offset() {
+    use bevy::ecs::world::Command;
+    use bevy_tween::timeline::shift_span;
+
+    let mut app = app();
+    let time_runner = app
+        .world_mut()
+        .spawn(TimeRunner::new(Duration::from_secs(3)))
+        .id();
+    let span = app
+        .world_mut()
+        .spawn(secs(1., 2.))
+        .set_parent(time_runner)
+        .id();
+    app.update();
+
+    for offset in [f32::NAN, f32::INFINITY, f32::MAX] {
+        shift_span(span, offset).apply(app.world_mut());
+        assert_eq!(app.world().get::<TimeSpan>(span), Some(&secs(1., 2.)));
+    }
+    shift_span(span, 0.5).apply(app.world_mut());
+    assert_eq!(app.world().get::<TimeSpan>(span), Some(&secs(1.5, 2.5)));
+}
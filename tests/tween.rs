@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_time_runner::{TimeRunner, TimeSpan};
+use bevy_tween::{
+    interpolate::{
+        from_current, look_at, look_at_entity_system, relative_translation,
+        translation, LookAt, LookAtEntity, Translation, TranslationBy,
+    },
+    interpolation::{EaseKind, EaseKindPlugin},
+    playback::TimeRunnerExt,
+    prelude::*,
+    tween::{
+        component_tween_from_current_system, relative_component_tween_system,
+    },
+    BevyTweenRegisterSystems, TweenCorePlugin, TweenSystemSet,
+};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TweenCorePlugin::default(),
+        EaseKindPlugin,
+    ))
+    .add_tween_systems((
+        relative_component_tween_system::<TranslationBy>(),
+        component_tween_from_current_system::<Translation>(),
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(
+        Duration::from_millis(250),
+    ));
+    app
+}
+
+#[test]
+fn relative_tween_restart() {
+    let mut app = app();
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let length = Duration::from_secs(1);
+    let time_runner = app.world_mut().spawn(TimeRunner::new(length)).id();
+    app.world_mut()
+        .spawn((
+            TimeSpan::try_from(Duration::ZERO..length).unwrap(),
+            EaseKind::Linear,
+            ComponentTween::new_target(target, relative_translation(Vec3::X)),
+        ))
+        .set_parent(time_runner);
+
+    for _ in 0..8 {
+        app.update();
+    }
+    let translation = app.world().get::<Transform>(target).unwrap().translation;
+    assert!((translation.x - 1.).abs() < 1e-4, "{translation}");
+
+    app.world_mut()
+        .get_mut::<TimeRunner>(time_runner)
+        .unwrap()
+        .restart();
+    for _ in 0..8 {
+        app.update();
+    }
+    let translation = app.world().get::<Transform>(target).unwrap().translation;
+    assert!((translation.x - 2.).abs() < 1e-4, "{translation}");
+}
+
+#[test]
+fn from_current_restart() {
+    let mut app = app();
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let length = Duration::from_secs(1);
+    let time_runner = app.world_mut().spawn(TimeRunner::new(length)).id();
+    app.world_mut()
+        .spawn((
+            TimeSpan::try_from(Duration::ZERO..length).unwrap(),
+            EaseKind::Linear,
+            ComponentTween::new_target(
+                target,
+                from_current(translation(Vec3::ZERO, Vec3::X)),
+            ),
+        ))
+        .set_parent(time_runner);
+
+    for _ in 0..8 {
+        app.update();
+    }
+    app.world_mut()
+        .get_mut::<Transform>(target)
+        .unwrap()
+        .translation = Vec3::new(5., 0., 0.);
+    app.world_mut()
+        .get_mut::<TimeRunner>(time_runner)
+        .unwrap()
+        .restart();
+    app.update();
+    app.update();
+
+    let translation = app.world().get::<Transform>(target).unwrap().translation;
+    assert!((translation.x - 3.).abs() < 1e-4, "{translation}");
+}
+
+#[test]
+fn look_at_entity_in_parent_space() {
+    let mut app = app();
+    app.add_plugins(TransformPlugin).add_systems(
+        PostUpdate,
+        look_at_entity_system.in_set(TweenSystemSet::ResolveTarget),
+    );
+    let parent = app.world_mut().spawn(Transform::from_xyz(10., 0., 0.)).id();
+    let target = app
+        .world_mut()
+        .spawn(Transform::default())
+        .set_parent(parent)
+        .id();
+    let looked_at = app
+        .world_mut()
+        .spawn(Transform::from_xyz(10., 0., -5.))
+        .id();
+    let length = Duration::from_secs(1);
+    let time_runner = app.world_mut().spawn(TimeRunner::new(length)).id();
+    let span = app
+        .world_mut()
+        .spawn((
+            TimeSpan::try_from(Duration::ZERO..length).unwrap(),
+            EaseKind::Linear,
+            ComponentTween::new_target(
+                target,
+                look_at(Quat::IDENTITY, Vec3::ZERO, Dir3::Y),
+            ),
+            LookAtEntity(looked_at),
+        ))
+        .set_parent(time_runner)
+        .id();
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let tween = app.world().get::<ComponentTween<LookAt>>(span).unwrap();
+    assert!(
+        tween
+            .interpolator
+            .point
+            .abs_diff_eq(Vec3::new(0., 0., -5.), 1e-4),
+        "{}",
+        tween.interpolator.point
+    );
+}
+
+#[test]
+fn additive_on_paused_base() {
+    use bevy_tween::tween::{
+        additive_component_tween_system, component_tween_system, Additive,
+    };
+
+    let mut app = app();
+    app.add_tween_systems(component_tween_system::<Translation>())
+        .add_additive_tween_systems(additive_component_tween_system::<
+            TranslationBy,
+        >());
+    let target = app.world_mut().spawn(Transform::default()).id();
+    let length = Duration::from_secs(1);
+    let base = app.world_mut().spawn(TimeRunner::new(length)).id();
+    app.world_mut()
+        .spawn((
+            TimeSpan::try_from(Duration::ZERO..length).unwrap(),
+            EaseKind::Linear,
+            ComponentTween::new_target(
+                target,
+                translation(Vec3::ZERO, Vec3::X),
+            ),
+        ))
+        .set_parent(base);
+    let layer = app.world_mut().spawn(TimeRunner::new(length)).id();
+    app.world_mut()
+        .spawn((
+            TimeSpan::try_from(Duration::ZERO..length).unwrap(),
+            EaseKind::Linear,
+            ComponentTween::new_target(target, relative_translation(Vec3::Y)),
+            Additive,
+        ))
+        .set_parent(layer);
+
+    for _ in 0..3 {
+        app.update();
+    }
+    for time_runner in [base, layer] {
+        app.world_mut()
+            .get_mut::<TimeRunner>(time_runner)
+            .unwrap()
+            .set_paused(true);
+    }
+    let paused = app.world().get::<Transform>(target).unwrap().translation;
+    assert!(paused.y > 0., "{paused}");
+    for _ in 0..4 {
+        app.update();
+        let translation =
+            app.world().get::<Transform>(target).unwrap().translation;
+        assert!((translation - paused).length() < 1e-4, "{translation}");
+    }
+}